@@ -28,12 +28,13 @@ fn main() {
 
     // 2. Instruir o `rustc` sobre como lincar a biblioteca.
     // --------------------------------------------------------------------------
-    // O método `.build()` retorna o diretório onde a biblioteca foi compilada.
+    // O método `.build()` retorna o prefixo de instalação, e o nosso
+    // `CMakeLists.txt` instala a biblioteca no subdiretório `lib/`.
     // Nós instruímos o Cargo a adicionar este diretório ao caminho de busca de
     // bibliotecas do linker.
     // A sintaxe `cargo:rustc-link-search=native={}` é uma instrução especial
     // que o Cargo entende.
-    println!("cargo:rustc-link-search=native={}", dst.display());
+    println!("cargo:rustc-link-search=native={}", dst.join("lib").display());
 
     // Agora, instruímos o Cargo a lincar nossa biblioteca `git_optim`.
     // O linker irá procurar por `libgit_optim.a` (em Linux/macOS) ou
@@ -43,6 +44,15 @@ fn main() {
     // único binário sem dependências de .dll ou .so.
     println!("cargo:rustc-link-lib=static=git_optim");
 
+    // Como a biblioteca é C++, ela depende da biblioteca padrão do C++, que
+    // precisa ser lincada explicitamente (o MSVC faz isso automaticamente).
+    let target = std::env::var("TARGET").unwrap_or_default();
+    if target.contains("apple") {
+        println!("cargo:rustc-link-lib=dylib=c++");
+    } else if !target.contains("msvc") {
+        println!("cargo:rustc-link-lib=dylib=stdc++");
+    }
+
     // 3. Garantir a Recompilação quando o Código C++ Mudar.
     // --------------------------------------------------------------------------
     // Esta é uma instrução crucial para o desenvolvimento. Ela diz ao Cargo
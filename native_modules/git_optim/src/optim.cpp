@@ -13,7 +13,7 @@
 #include "optim.h" // Incluímos nosso próprio cabeçalho para validação pelo compilador.
 
 #include <iostream> // Para `std::cout` e `std::endl`.
-#include <cstring>  // Para `strlen`.
+#include <cstring>  // Para `strlen` e `memchr`.
 
 void hello_from_cpp() {
     // `std::endl` não apenas adiciona uma nova linha, mas também "flusha" o buffer
@@ -39,4 +39,112 @@ int32_t get_string_length_from_cpp(const char* text) {
     // Retornamos o comprimento como um `int32_t` para corresponder à nossa API
     // e ao `i32` do Rust, fazendo um cast explícito para deixar a conversão clara.
     return static_cast<int32_t>(length);
+}
+
+namespace {
+
+// Verifica se a linha [begin, end) começa com o prefixo informado.
+bool starts_with(const char* begin, const char* end, const char* prefix) {
+    size_t prefix_length = strlen(prefix);
+    return static_cast<size_t>(end - begin) >= prefix_length &&
+           memcmp(begin, prefix, prefix_length) == 0;
+}
+
+// Lê uma coluna numérica do `--numstat` a partir de `cursor`, até o próximo
+// '\t'. Retorna `false` se a coluna não for um número nem o '-' dos binários.
+bool read_numstat_column(const char*& cursor, const char* end, uint64_t& value) {
+    value = 0;
+    if (cursor < end && *cursor == '-') {
+        cursor++;
+    } else {
+        const char* digits_begin = cursor;
+        while (cursor < end && *cursor >= '0' && *cursor <= '9') {
+            value = value * 10 + static_cast<uint64_t>(*cursor - '0');
+            cursor++;
+        }
+        if (cursor == digits_begin) {
+            return false;
+        }
+    }
+    if (cursor >= end || *cursor != '\t') {
+        return false;
+    }
+    cursor++; // Pula o '\t'.
+    return true;
+}
+
+} // namespace
+
+int32_t compute_diff_stats(const char* data, size_t length, DiffStatsResult* out) {
+    if (out == nullptr || (data == nullptr && length > 0)) {
+        return -1;
+    }
+
+    DiffStatsResult result{0, 0, 0};
+    bool in_hunk = false;
+
+    const char* cursor = data;
+    const char* const buffer_end = data + length;
+
+    // Percorremos o buffer linha a linha. `memchr` é a forma mais rápida de
+    // encontrar o próximo '\n', pois geralmente é vetorizada pela libc.
+    while (cursor < buffer_end) {
+        const void* newline = memchr(cursor, '\n', static_cast<size_t>(buffer_end - cursor));
+        const char* line_end = newline ? static_cast<const char*>(newline) : buffer_end;
+
+        if (starts_with(cursor, line_end, "diff ")) {
+            // Início de um novo arquivo: os cabeçalhos seguintes não são hunks.
+            result.files_changed++;
+            in_hunk = false;
+        } else if (starts_with(cursor, line_end, "@@")) {
+            in_hunk = true;
+        } else if (in_hunk && cursor < line_end) {
+            if (*cursor == '+') {
+                result.lines_added++;
+            } else if (*cursor == '-') {
+                result.lines_removed++;
+            }
+        }
+
+        if (newline == nullptr) {
+            break; // Última linha, sem '\n' final.
+        }
+        cursor = line_end + 1;
+    }
+
+    *out = result;
+    return 0;
+}
+
+int32_t compute_numstat_totals(const char* data, size_t length, DiffStatsResult* out) {
+    if (out == nullptr || (data == nullptr && length > 0)) {
+        return -1;
+    }
+
+    DiffStatsResult result{0, 0, 0};
+
+    const char* cursor = data;
+    const char* const buffer_end = data + length;
+
+    while (cursor < buffer_end) {
+        const void* newline = memchr(cursor, '\n', static_cast<size_t>(buffer_end - cursor));
+        const char* line_end = newline ? static_cast<const char*>(newline) : buffer_end;
+
+        const char* column = cursor;
+        uint64_t added = 0;
+        uint64_t removed = 0;
+        if (read_numstat_column(column, line_end, added) && read_numstat_column(column, line_end, removed)) {
+            result.files_changed++;
+            result.lines_added += added;
+            result.lines_removed += removed;
+        }
+
+        if (newline == nullptr) {
+            break; // Última linha, sem '\n' final.
+        }
+        cursor = line_end + 1;
+    }
+
+    *out = result;
+    return 0;
 }
\ No newline at end of file
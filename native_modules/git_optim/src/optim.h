@@ -14,6 +14,7 @@
 
 #pragma once
 
+#include <cstddef> // Para `size_t`.
 #include <cstdint> // Para tipos de inteiros de tamanho fixo como `int32_t`.
 
 // O bloco `extern "C"` é a pedra angular da interoperabilidade (FFI).
@@ -22,6 +23,18 @@
 // chamada C, que é estável e compreendida por outras linguagens como o Rust.
 extern "C" {
 
+/**
+ * @brief Estatísticas agregadas de um diff no formato unificado.
+ *
+ * O layout desta struct é espelhado em Rust com `#[repr(C)]` no arquivo
+ * `src/native_bindings.rs`. Qualquer alteração aqui DEVE ser replicada lá.
+ */
+struct DiffStatsResult {
+    uint64_t files_changed;
+    uint64_t lines_added;
+    uint64_t lines_removed;
+};
+
 /**
  * @brief Imprime uma mensagem de diagnóstico para verificar a ligação FFI.
  *
@@ -54,4 +67,45 @@ int32_t perform_complex_calculation(int32_t input);
  */
 int32_t get_string_length_from_cpp(const char* text);
 
+/**
+ * @brief Conta arquivos, linhas adicionadas e linhas removidas de um diff.
+ *
+ * Analisa a saída de `git diff` (formato unificado) em uma única passagem.
+ * Apenas linhas dentro de um hunk (após um cabeçalho `@@`) são contadas, de
+ * modo que os cabeçalhos `+++ b/arquivo` e `--- a/arquivo` não são confundidos
+ * com alterações. Cada linha `diff --git` conta como um arquivo alterado.
+ *
+ * @param data   Ponteiro para o primeiro byte do diff. NÃO precisa ser
+ *               terminado em nulo: o tamanho é dado por `length`.
+ * @param length O número de bytes válidos em `data`.
+ * @param out    Ponteiro para a struct que receberá o resultado.
+ *
+ * @return 0 em caso de sucesso, ou -1 se `data` (com `length > 0`) ou `out`
+ *         forem ponteiros nulos.
+ *
+ * @safety Como o buffer é tratado como bytes com tamanho explícito, não há
+ *         risco de cortar um caractere UTF-8 ao meio: os marcadores que
+ *         procuramos ('+', '-', '@', '\n') são ASCII e nunca aparecem dentro
+ *         de uma sequência multi-byte UTF-8.
+ */
+int32_t compute_diff_stats(const char* data, size_t length, DiffStatsResult* out);
+
+/**
+ * @brief Soma as colunas de uma saída `--numstat` (ex: `git log --numstat`).
+ *
+ * Cada linha no formato `<adicionadas>\t<removidas>\t<caminho>` conta como um
+ * arquivo alterado e tem suas colunas somadas. Arquivos binários (`-\t-\t`)
+ * contam como arquivos, mas não como linhas. Linhas em outro formato (ex: as
+ * linhas em branco entre commits) são ignoradas.
+ *
+ * @param data   Ponteiro para o primeiro byte da saída. NÃO precisa ser
+ *               terminado em nulo: o tamanho é dado por `length`.
+ * @param length O número de bytes válidos em `data`.
+ * @param out    Ponteiro para a struct que receberá o resultado.
+ *
+ * @return 0 em caso de sucesso, ou -1 se `data` (com `length > 0`) ou `out`
+ *         forem ponteiros nulos.
+ */
+int32_t compute_numstat_totals(const char* data, size_t length, DiffStatsResult* out);
+
 } // Fim do bloco extern "C"
\ No newline at end of file
@@ -10,18 +10,35 @@
 // Este módulo contém toda a lógica para interagir com a API REST do GitHub.
 // Ele lida com a construção de requisições, autenticação via token e
 // o tratamento de respostas de sucesso e de erro.
+//
+// Todas as funções públicas são `async` e rodam sobre o runtime Tokio criado
+// em `main.rs`. A camada de UI as executa com `api_client::block_on`, o que
+// mantém o fluxo dos menus síncrono sem bloquear o runtime de rede.
 // ==============================================================================
 
 use crate::config;
 use anyhow::{anyhow, Context, Result};
-use reqwest::blocking::Client;
-use reqwest::header::{ACCEPT, AUTHORIZATION, USER_AGENT};
+use reqwest::{Client, RequestBuilder, Response};
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, LINK, RETRY_AFTER, USER_AGENT};
+use reqwest::StatusCode;
+use serde::de::IgnoredAny;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 // Constantes para a API do GitHub.
 const GITHUB_API_BASE_URL: &str = "https://api.github.com";
 const APP_USER_AGENT: &str = "gitph-cli/0.1.0";
 
+/// Espera máxima (em segundos) que aceitamos fazer automaticamente quando o
+/// GitHub indica um limite de requisições. Se a janela de espera for maior,
+/// desistimos imediatamente e informamos o usuário, em vez de "congelar" a CLI.
+const MAX_RATE_LIMIT_WAIT_SECS: u64 = 60;
+
+/// O cabeçalho em que o GitHub informa os escopos de um token clássico.
+const OAUTH_SCOPES_HEADER: &str = "x-oauth-scopes";
+
 /// Define a estrutura do corpo (payload) JSON para a requisição de criação de Release.
 /// A diretiva `#[derive(Serialize)]` instrui o `serde` a converter esta struct
 /// em uma string JSON, que será o corpo da nossa requisição POST.
@@ -42,6 +59,424 @@ struct GitHubApiError {
     message: String,
 }
 
+/// Define o corpo JSON da requisição de criação de Pull Request.
+#[derive(Serialize)]
+struct CreatePullRequestPayload<'a> {
+    title: &'a str,
+    head: &'a str,
+    base: &'a str,
+    body: &'a str,
+}
+
+/// Representa um Pull Request criado, com os campos relevantes para a UI.
+#[derive(Deserialize, Debug, Clone)]
+pub struct PullRequestInfo {
+    pub number: u64,
+    pub html_url: String,
+}
+
+/// Representa uma Release existente, conforme retornada pela API do GitHub.
+///
+/// Apenas os campos que a aplicação usa são desserializados; o `serde`
+/// ignora silenciosamente todos os outros campos do JSON.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReleaseInfo {
+    pub id: u64,
+    pub tag_name: String,
+    /// O título da release. Pode ser nulo se a release foi criada sem nome.
+    pub name: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub html_url: String,
+}
+
+/// Define o corpo JSON da requisição de criação de Issue.
+#[derive(Serialize)]
+struct CreateIssuePayload<'a> {
+    title: &'a str,
+    body: &'a str,
+    labels: &'a [String],
+}
+
+/// O filtro de estado usado ao listar Issues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueState {
+    Open,
+    Closed,
+    All,
+}
+
+impl IssueState {
+    /// O valor do parâmetro `state` esperado pela API.
+    fn as_query(self) -> &'static str {
+        match self {
+            IssueState::Open => "open",
+            IssueState::Closed => "closed",
+            IssueState::All => "all",
+        }
+    }
+}
+
+/// Uma label associada a uma Issue.
+#[derive(Deserialize, Debug, Clone)]
+pub struct LabelInfo {
+    pub name: String,
+}
+
+/// Representa uma Issue, com os campos relevantes para a UI.
+#[derive(Deserialize, Debug, Clone)]
+pub struct IssueInfo {
+    pub number: u64,
+    pub title: String,
+    /// "open" ou "closed".
+    pub state: String,
+    pub html_url: String,
+    #[serde(default)]
+    pub labels: Vec<LabelInfo>,
+    /// Presente apenas quando o item é um Pull Request: a API de Issues do
+    /// GitHub também retorna PRs, que filtramos por este campo.
+    #[serde(default)]
+    pull_request: Option<IgnoredAny>,
+}
+
+/// Define o corpo JSON da requisição de criação de repositório.
+#[derive(Serialize)]
+struct CreateRepoPayload<'a> {
+    name: &'a str,
+    private: bool,
+    description: &'a str,
+}
+
+/// Representa um repositório do GitHub, com os campos relevantes para a UI.
+#[derive(Deserialize, Debug, Clone)]
+pub struct RepoInfo {
+    /// O nome completo, no formato "dono/repositório".
+    pub full_name: String,
+    pub private: bool,
+    pub html_url: String,
+    /// A URL HTTPS para clonar (ex: "https://github.com/dono/repo.git").
+    pub clone_url: String,
+    /// A URL SSH para clonar (ex: "git@github.com:dono/repo.git").
+    pub ssh_url: String,
+    /// A branch padrão do repositório (ex: "main").
+    pub default_branch: String,
+}
+
+/// O usuário dono do token, conforme retornado por `GET /user`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct GitHubUser {
+    /// O nome de usuário (ex: "octocat").
+    pub login: String,
+    /// O nome de exibição, se o usuário o tiver definido no perfil.
+    pub name: Option<String>,
+}
+
+/// Cache das branches padrão já consultadas nesta sessão, por "dono/repo".
+///
+/// A branch padrão de um repositório praticamente nunca muda, então não há
+/// motivo para repetir a chamada de rede a cada fluxo.
+static DEFAULT_BRANCH_CACHE: OnceLock<Mutex<HashMap<String, String>>> = OnceLock::new();
+
+/// Carrega o token do GitHub a partir da configuração.
+///
+/// # Returns
+/// `Ok(String)` com o token, ou um `Err` com instruções claras de como
+/// configurá-lo caso ele não esteja definido.
+fn load_token() -> Result<String> {
+    let config = config::load()?;
+    match config.github_token {
+        Some(t) => Ok(t),
+        None => {
+            // Este é um erro crítico de configuração. Fornecemos uma mensagem
+            // clara e acionável para o usuário.
+            Err(anyhow!(
+                "Token da API do GitHub não encontrado.\n\
+                 Por favor, adicione seu token ao arquivo de configuração: {}\n\
+                 Exemplo: github_token = \"seu_token_aqui\"",
+                config::get_config_path()?.display()
+            ))
+        }
+    }
+}
+
+/// O cliente HTTP compartilhado por todas as chamadas à API do GitHub.
+///
+/// Criado na primeira chamada (veja `shared_client`) e reutilizado durante
+/// toda a sessão, para que as conexões abertas (e o handshake TLS) sejam
+/// aproveitadas entre chamadas consecutivas, como criar uma release e enviar
+/// seus arquivos.
+static SHARED_CLIENT: OnceLock<Client> = OnceLock::new();
+
+/// Um cliente da API do GitHub autenticado com o token da configuração.
+///
+/// Envolve o cliente HTTP compartilhado e adiciona o cabeçalho de
+/// autenticação a cada requisição. O token não fica no cliente compartilhado
+/// porque pode ser configurado no meio da sessão (ex: por `ensure_api_token`).
+struct GitHubClient {
+    http: &'static Client,
+    auth: HeaderValue,
+}
+
+impl GitHubClient {
+    fn get(&self, url: &str) -> RequestBuilder {
+        self.http.get(url).header(AUTHORIZATION, self.auth.clone())
+    }
+
+    fn post(&self, url: &str) -> RequestBuilder {
+        self.http.post(url).header(AUTHORIZATION, self.auth.clone())
+    }
+
+    fn delete(&self, url: &str) -> RequestBuilder {
+        self.http.delete(url).header(AUTHORIZATION, self.auth.clone())
+    }
+}
+
+/// Retorna o cliente HTTP compartilhado, criando-o na primeira chamada.
+///
+/// Os cabeçalhos comuns (versão da API e User-Agent) e o tempo limite das
+/// requisições (`api_timeout_secs` na configuração) são definidos uma única
+/// vez, para que cada endpoint precise se preocupar apenas com a URL e o corpo.
+fn shared_client() -> Result<&'static Client> {
+    if let Some(client) = SHARED_CLIENT.get() {
+        return Ok(client);
+    }
+
+    let mut headers = HeaderMap::new();
+    headers.insert(ACCEPT, HeaderValue::from_static("application/vnd.github+json")); // Versão recomendada da API.
+    headers.insert(USER_AGENT, HeaderValue::from_static(APP_USER_AGENT)); // Muitas APIs exigem um User-Agent.
+
+    let client = Client::builder()
+        .default_headers(headers)
+        .timeout(Duration::from_secs(config::load()?.api_timeout_secs.max(1)))
+        .build()
+        .context("Falha ao construir o cliente HTTP.")?;
+    // Se outra thread tiver criado o cliente enquanto isso, usamos o dela.
+    Ok(SHARED_CLIENT.get_or_init(|| client))
+}
+
+/// Prepara o cliente autenticado usado por todas as chamadas à API do GitHub.
+///
+/// # Returns
+/// `Ok(GitHubClient)`, ou `Err` se o token não estiver configurado ou for inválido.
+fn build_client() -> Result<GitHubClient> {
+    let token = load_token()?;
+
+    let mut auth = HeaderValue::from_str(&format!("Bearer {}", token))
+        .context("O token do GitHub contém caracteres inválidos.")?;
+    // Marcamos o cabeçalho como sensível para que ele nunca apareça em logs de debug.
+    auth.set_sensitive(true);
+
+    Ok(GitHubClient {
+        http: shared_client()?,
+        auth,
+    })
+}
+
+/// Envia uma requisição, repetindo-a em caso de falhas transitórias.
+///
+/// São consideradas transitórias: erros de rede, respostas 5xx e respostas
+/// de limite de requisições (429, ou 403 com `X-RateLimit-Remaining: 0`).
+/// Para limites de requisições, respeitamos os cabeçalhos `Retry-After` e
+/// `X-RateLimit-Reset` do GitHub; para os demais casos, usamos um backoff
+/// exponencial (1s, 2s, 4s, ...). O número de tentativas vem de
+/// `api_max_retries` na configuração.
+///
+/// Requisições não idempotentes (ex: `POST`) só são repetidas quando é certo
+/// que o GitHub não as processou: falhas de conexão e limites de requisições.
+/// Após um timeout ou uma resposta 5xx, a release, o pull request ou a issue
+/// pode ter sido criado, e repetir o envio criaria uma duplicata.
+///
+/// # Returns
+/// A última `Response` recebida (que pode ser de erro, para o chamador
+/// analisar), ou um `Err` se a rede falhar ou o limite de requisições não
+/// se normalizar dentro das tentativas permitidas.
+async fn send_with_retry(request: RequestBuilder) -> Result<Response> {
+    config::ensure_online("a API do GitHub")?;
+    let max_attempts = config::load()?.api_max_retries.max(1);
+    let (client, request) = request.build_split();
+    let request = request.context("Falha ao montar a requisição para a API do GitHub.")?;
+    let idempotent = request.method().is_idempotent();
+    let mut attempt = 1;
+
+    loop {
+        // A `Request` é consumida pelo `execute`, então clonamos a requisição
+        // a cada tentativa. Corpos JSON (nosso único caso) sempre são clonáveis.
+        let current = request
+            .try_clone()
+            .ok_or_else(|| anyhow!("A requisição não pode ser repetida (corpo não clonável)."))?;
+        let backoff = Duration::from_secs(1 << (attempt - 1).min(5));
+
+        match client.execute(current).await {
+            Ok(response) => {
+                if let Some(wait) = rate_limit_wait(&response) {
+                    if attempt >= max_attempts || wait > Duration::from_secs(MAX_RATE_LIMIT_WAIT_SECS) {
+                        return Err(anyhow!(
+                            "Limite de requisições da API do GitHub atingido. Tente novamente em {} segundos.",
+                            wait.as_secs().max(1)
+                        ));
+                    }
+                    tokio::time::sleep(wait).await;
+                } else if response.status().is_server_error() && idempotent && attempt < max_attempts {
+                    tokio::time::sleep(backoff).await;
+                } else {
+                    return Ok(response);
+                }
+            }
+            Err(e) => {
+                // Timeouts e falhas de conexão podem ser passageiros; outros erros
+                // (ex: URL inválida) não vão se resolver sozinhos. Um timeout pode
+                // ocorrer depois de o GitHub processar a requisição.
+                let transient = e.is_connect() || (e.is_timeout() && idempotent);
+                if !transient || attempt >= max_attempts {
+                    return Err(anyhow!(e).context("Falha ao enviar a requisição para a API do GitHub."));
+                }
+                tokio::time::sleep(backoff).await;
+            }
+        }
+
+        attempt += 1;
+    }
+}
+
+/// Verifica se a resposta indica um limite de requisições e calcula a espera.
+///
+/// # Returns
+/// `Some(Duration)` com o tempo a aguardar antes de tentar novamente, ou
+/// `None` se a resposta não for de limite de requisições.
+fn rate_limit_wait(response: &Response) -> Option<Duration> {
+    let status = response.status();
+    let headers = response.headers();
+    let header_u64 = |name: &str| -> Option<u64> { headers.get(name)?.to_str().ok()?.trim().parse().ok() };
+
+    let exhausted = header_u64("x-ratelimit-remaining") == Some(0);
+    let limited = status == StatusCode::TOO_MANY_REQUESTS
+        || (status == StatusCode::FORBIDDEN && (exhausted || headers.contains_key(RETRY_AFTER)));
+    if !limited {
+        return None;
+    }
+
+    // `Retry-After` (limites secundários) tem prioridade: ele diz exatamente
+    // quantos segundos aguardar.
+    if let Some(seconds) = header_u64(RETRY_AFTER.as_str()) {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    // `X-RateLimit-Reset` (limite primário) é um timestamp Unix do momento em
+    // que a cota será renovada.
+    if let Some(reset_at) = header_u64("x-ratelimit-reset") {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+        return Some(Duration::from_secs(reset_at.saturating_sub(now).max(1)));
+    }
+
+    // Sem indicação do servidor, aguardamos um minuto, como recomenda o GitHub.
+    Some(Duration::from_secs(60))
+}
+
+/// Extrai a mensagem de erro de uma resposta malsucedida da API.
+async fn read_api_error(response: Response) -> String {
+    let status = response.status();
+    let error_body: Result<GitHubApiError, _> = response.json().await;
+    match error_body {
+        Ok(api_error) => api_error.message,
+        Err(_) => format!("A API retornou um erro {} mas não foi possível analisar a mensagem.", status),
+    }
+}
+
+/// Procura a URL da próxima página no cabeçalho `Link` de uma resposta paginada.
+///
+/// O GitHub pagina listas longas e indica as páginas seguintes com um cabeçalho
+/// no formato: `<https://api.github.com/...&page=2>; rel="next", <...>; rel="last"`.
+fn next_page_url(headers: &HeaderMap) -> Option<String> {
+    let link = headers.get(LINK)?.to_str().ok()?;
+    link.split(',').find_map(|part| {
+        let (url_part, rel_part) = part.split_once(';')?;
+        if rel_part.trim() == "rel=\"next\"" {
+            Some(
+                url_part
+                    .trim()
+                    .trim_start_matches('<')
+                    .trim_end_matches('>')
+                    .to_string(),
+            )
+        } else {
+            None
+        }
+    })
+}
+
+/// Lista todas as Releases de um repositório, incluindo rascunhos.
+///
+/// Percorre todas as páginas retornadas pela API (seguindo o cabeçalho `Link`),
+/// para que repositórios com muitas releases não sejam truncados em 30 itens.
+///
+/// # Arguments
+/// * `owner` - O nome do dono do repositório (usuário ou organização).
+/// * `repo` - O nome do repositório.
+///
+/// # Returns
+/// `Ok(Vec<ReleaseInfo>)` com as releases, da mais recente para a mais antiga,
+/// ou um `Err` detalhado em caso de falha.
+pub async fn list_releases(owner: &str, repo: &str) -> Result<Vec<ReleaseInfo>> {
+    let client = build_client()?;
+    let mut releases = Vec::new();
+    let mut next_url = Some(format!(
+        "{}/repos/{}/{}/releases?per_page=100",
+        GITHUB_API_BASE_URL, owner, repo
+    ));
+
+    while let Some(url) = next_url {
+        let response = send_with_retry(client.get(&url)).await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            return Err(anyhow!(
+                "Falha ao listar as releases no GitHub (Status: {}):\n{}",
+                status,
+                read_api_error(response).await
+            ));
+        }
+
+        next_url = next_page_url(response.headers());
+        let page: Vec<ReleaseInfo> = response
+            .json()
+            .await
+            .context("Falha ao analisar a lista de releases retornada pelo GitHub.")?;
+        releases.extend(page);
+    }
+
+    Ok(releases)
+}
+
+/// Exclui uma Release do GitHub.
+///
+/// A tag Git associada NÃO é removida; apenas o objeto Release no GitHub.
+///
+/// # Arguments
+/// * `owner` - O nome do dono do repositório.
+/// * `repo` - O nome do repositório.
+/// * `id` - O identificador numérico da release (campo `id` de `ReleaseInfo`).
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou um `Err` detalhado em caso de falha.
+pub async fn delete_release(owner: &str, repo: &str, id: u64) -> Result<()> {
+    let client = build_client()?;
+    let url = format!("{}/repos/{}/{}/releases/{}", GITHUB_API_BASE_URL, owner, repo, id);
+
+    let response = send_with_retry(client.delete(&url)).await?;
+
+    // Um status 204 No Content indica que a release foi excluída.
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        let status = response.status();
+        Err(anyhow!(
+            "Falha ao excluir a release no GitHub (Status: {}):\n{}",
+            status,
+            read_api_error(response).await
+        ))
+    }
+}
+
 /// Cria uma nova Release no GitHub associada a uma tag existente.
 ///
 /// # Arguments
@@ -53,30 +488,17 @@ struct GitHubApiError {
 ///
 /// # Returns
 /// `Ok(())` em caso de sucesso, ou um `Err` detalhado em caso de falha.
-pub fn create_release(
+pub async fn create_release(
     owner: &str,
     repo: &str,
     tag_name: &str,
     release_name: &str,
     release_notes: &str,
 ) -> Result<()> {
-    // --- PASSO 1: Obter o Token de Autenticação ---
-    // Carregamos a configuração e verificamos se o token do GitHub está definido.
-    // Sem um token, a API não nos permitirá criar uma release.
-    let config = config::load()?;
-    let token = match config.github_token {
-        Some(t) => t,
-        None => {
-            // Este é um erro crítico de configuração. Fornecemos uma mensagem
-            // clara e acionável para o usuário.
-            return Err(anyhow!(
-                "Token da API do GitHub não encontrado.\n\
-                 Por favor, adicione seu token ao arquivo de configuração: {}\n\
-                 Exemplo: github_token = \"seu_token_aqui\"",
-                config::get_config_path()?.display()
-            ));
-        }
-    };
+    // --- PASSO 1: Construir o Cliente Autenticado ---
+    // O cliente carrega o token do GitHub da configuração. Sem um token, a API
+    // não nos permitirá criar uma release, e o erro explica como configurá-lo.
+    let client = build_client()?;
 
     // --- PASSO 2: Construir o Payload da Requisição ---
     let payload = CreateReleasePayload {
@@ -88,27 +510,13 @@ pub fn create_release(
     };
 
     // --- PASSO 3: Construir e Enviar a Requisição HTTP ---
-    let client = Client::new();
-    let url = format!("{}/repos/{}/{}/releases", GITHUB_API_BASE_URL, owner, repo);
-
-    let response = client
+    // Os cabeçalhos comuns já foram definidos pelo `build_client`, e o envio
+    // repete a requisição automaticamente em caso de falhas transitórias.
+    let url = releases_url(owner, repo);
+    let request = client
         .post(&url)
-        // Definimos os cabeçalhos HTTP necessários.
-        .header(
-            AUTHORIZATION,
-            format!("Bearer {}", token), // Autenticação via Bearer Token.
-        )
-        .header(
-            ACCEPT,
-            "application/vnd.github+json", // Versão recomendada da API.
-        )
-        .header(
-            USER_AGENT,
-            APP_USER_AGENT, // Muitas APIs exigem um User-Agent.
-        )
-        .json(&payload) // Serializa nosso `payload` para JSON e define o Content-Type.
-        .send()
-        .context("Falha ao enviar a requisição para a API do GitHub.")?;
+        .json(&payload); // Serializa nosso `payload` para JSON e define o Content-Type.
+    let response = send_with_retry(request).await?;
 
     // --- PASSO 4: Processar a Resposta ---
     if response.status().is_success() {
@@ -118,17 +526,403 @@ pub fn create_release(
         // Se a API retornou um erro, tentamos analisar a mensagem de erro
         // que o GitHub nos enviou no corpo da resposta.
         let status = response.status();
-        let error_body: Result<GitHubApiError, _> = response.json();
-
-        let error_message = match error_body {
-            Ok(api_error) => api_error.message,
-            Err(_) => format!("A API retornou um erro {} mas não foi possível analisar a mensagem.", status),
+        // Os escopos precisam ser lidos antes de consumir o corpo da resposta.
+        let scopes = response
+            .headers()
+            .get(OAUTH_SCOPES_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let message = read_api_error(response).await;
+        let hint = if status == StatusCode::FORBIDDEN {
+            token_scope_hint(scopes.as_deref(), &message)
+        } else {
+            None
         };
+        Err(anyhow!(
+            "Falha ao criar a release no GitHub (Status: {}):\n{}{}",
+            status,
+            message,
+            hint.map(|h| format!("\n\n{}", h)).unwrap_or_default()
+        ))
+    }
+}
+
+/// A URL do endpoint de releases de um repositório (usada por `create_release`).
+pub fn releases_url(owner: &str, repo: &str) -> String {
+    format!("{}/repos/{}/{}/releases", GITHUB_API_BASE_URL, owner, repo)
+}
+
+/// A URL do endpoint de Pull Requests de um repositório (usada por
+/// `create_pull_request`).
+pub fn pulls_url(owner: &str, repo: &str) -> String {
+    format!("{}/repos/{}/{}/pulls", GITHUB_API_BASE_URL, owner, repo)
+}
+
+/// Explica um 403 causado pela falta de permissões do token.
+///
+/// Tokens clássicos informam seus escopos no cabeçalho `X-OAuth-Scopes`; os
+/// tokens "fine-grained" não o enviam, mas o GitHub os identifica na mensagem
+/// de erro.
+///
+/// # Arguments
+/// * `scopes` - O valor do cabeçalho `X-OAuth-Scopes`, se presente.
+/// * `message` - A mensagem de erro retornada pela API.
+///
+/// # Returns
+/// `Some` com uma orientação para o usuário, ou `None` se o 403 não parecer
+/// ser um problema de permissões do token.
+fn token_scope_hint(scopes: Option<&str>, message: &str) -> Option<String> {
+    if message.contains("personal access token") {
+        return Some(
+            "O seu token \"fine-grained\" não tem acesso a este repositório. Inclua o repositório \
+             no token e conceda a permissão 'Contents: Read and write'."
+                .to_string(),
+        );
+    }
+
+    let scopes = scopes?;
+    let granted: Vec<&str> = scopes.split(',').map(str::trim).filter(|s| !s.is_empty()).collect();
+    if granted.contains(&"repo") {
+        return None;
+    }
+    let granted_list = if granted.is_empty() { "nenhum".to_string() } else { granted.join(", ") };
+    if granted.contains(&"public_repo") {
+        Some(format!(
+            "O seu token só tem o escopo 'public_repo' (escopos: {}); repositórios privados exigem o \
+             escopo 'repo'. Gere um novo token com o escopo 'repo'.",
+            granted_list
+        ))
+    } else {
+        Some(format!(
+            "O seu token não tem o escopo 'repo' (escopos: {}). Gere um novo token com o escopo 'repo'.",
+            granted_list
+        ))
+    }
+}
+
+/// Abre um Pull Request no GitHub.
+///
+/// # Arguments
+/// * `owner` - O nome do dono do repositório.
+/// * `repo` - O nome do repositório.
+/// * `head` - A branch com as alterações (já enviada ao remoto).
+/// * `base` - A branch que receberá as alterações (ex: "main").
+/// * `title` - O título do Pull Request.
+/// * `body` - A descrição do Pull Request, em Markdown.
+///
+/// # Returns
+/// `Ok(PullRequestInfo)` com o número e a URL do PR criado, ou um `Err`
+/// detalhado em caso de falha.
+pub async fn create_pull_request(
+    owner: &str,
+    repo: &str,
+    head: &str,
+    base: &str,
+    title: &str,
+    body: &str,
+) -> Result<PullRequestInfo> {
+    let client = build_client()?;
+    let payload = CreatePullRequestPayload { title, head, base, body };
+    let url = pulls_url(owner, repo);
+
+    let response = send_with_retry(client.post(&url).json(&payload)).await?;
+
+    if response.status().is_success() {
+        // Um status 201 Created indica que o PR foi aberto.
+        response
+            .json()
+            .await
+            .context("Falha ao analisar a resposta do GitHub sobre o Pull Request criado.")
+    } else {
+        let status = response.status();
+        let message = read_api_error(response).await;
+
+        // O 422 é usado pelo GitHub para erros de validação. O mais comum aqui
+        // é tentar abrir um PR sem diferenças entre as branches.
+        if status == StatusCode::UNPROCESSABLE_ENTITY {
+            return Err(anyhow!(
+                "O GitHub recusou o Pull Request: a branch '{}' não tem commits que não estejam em '{}', \
+                 ou já existe um PR aberto para ela.\n(Resposta da API: {})",
+                head,
+                base,
+                message
+            ));
+        }
+
+        Err(anyhow!(
+            "Falha ao criar o Pull Request no GitHub (Status: {}):\n{}",
+            status,
+            message
+        ))
+    }
+}
+
+/// Lista as Issues de um repositório.
+///
+/// Percorre todas as páginas retornadas pela API (seguindo o cabeçalho `Link`).
+/// Pull Requests, que o GitHub também retorna neste endpoint, são descartados.
+///
+/// # Arguments
+/// * `owner` - O nome do dono do repositório.
+/// * `repo` - O nome do repositório.
+/// * `state` - Quais Issues listar (abertas, fechadas ou todas).
+///
+/// # Returns
+/// `Ok(Vec<IssueInfo>)` da mais recente para a mais antiga, ou um `Err`
+/// detalhado em caso de falha.
+pub async fn list_issues(owner: &str, repo: &str, state: IssueState) -> Result<Vec<IssueInfo>> {
+    let client = build_client()?;
+    let mut issues = Vec::new();
+    let mut next_url = Some(format!(
+        "{}/repos/{}/{}/issues?state={}&per_page=100",
+        GITHUB_API_BASE_URL,
+        owner,
+        repo,
+        state.as_query()
+    ));
+
+    while let Some(url) = next_url {
+        let response = send_with_retry(client.get(&url)).await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            return Err(anyhow!(
+                "Falha ao listar as issues no GitHub (Status: {}):\n{}",
+                status,
+                read_api_error(response).await
+            ));
+        }
+
+        next_url = next_page_url(response.headers());
+        let page: Vec<IssueInfo> = response
+            .json()
+            .await
+            .context("Falha ao analisar a lista de issues retornada pelo GitHub.")?;
+        issues.extend(page.into_iter().filter(|issue| issue.pull_request.is_none()));
+    }
+
+    Ok(issues)
+}
+
+/// Abre uma nova Issue no GitHub.
+///
+/// # Arguments
+/// * `owner` - O nome do dono do repositório.
+/// * `repo` - O nome do repositório.
+/// * `title` - O título da Issue.
+/// * `body` - A descrição, em Markdown.
+/// * `labels` - As labels a aplicar. Labels inexistentes são criadas pelo
+///   GitHub se o usuário tiver permissão de escrita; caso contrário, a API as ignora.
+///
+/// # Returns
+/// `Ok(IssueInfo)` com o número e a URL da Issue criada, ou um `Err`
+/// detalhado em caso de falha.
+pub async fn create_issue(owner: &str, repo: &str, title: &str, body: &str, labels: &[String]) -> Result<IssueInfo> {
+    let client = build_client()?;
+    let payload = CreateIssuePayload { title, body, labels };
+    let url = format!("{}/repos/{}/{}/issues", GITHUB_API_BASE_URL, owner, repo);
+
+    let response = send_with_retry(client.post(&url).json(&payload)).await?;
+
+    if response.status().is_success() {
+        // Um status 201 Created indica que a Issue foi aberta.
+        response
+            .json()
+            .await
+            .context("Falha ao analisar a resposta do GitHub sobre a Issue criada.")
+    } else {
+        let status = response.status();
+        Err(anyhow!(
+            "Falha ao criar a issue no GitHub (Status: {}):\n{}",
+            status,
+            read_api_error(response).await
+        ))
+    }
+}
+
+/// Cria um novo repositório (vazio) na conta do usuário autenticado.
+///
+/// # Arguments
+/// * `name` - O nome do repositório.
+/// * `private` - Se `true`, o repositório é privado.
+/// * `description` - Uma descrição curta (pode ser vazia).
+///
+/// # Returns
+/// `Ok(RepoInfo)` com as URLs do repositório criado, ou um `Err` detalhado em
+/// caso de falha (incluindo um nome já em uso).
+pub async fn create_repo(name: &str, private: bool, description: &str) -> Result<RepoInfo> {
+    let client = build_client()?;
+    let payload = CreateRepoPayload { name, private, description };
+    let url = format!("{}/user/repos", GITHUB_API_BASE_URL);
+
+    let response = send_with_retry(client.post(&url).json(&payload)).await?;
+
+    if response.status().is_success() {
+        // Um status 201 Created indica que o repositório foi criado.
+        response
+            .json()
+            .await
+            .context("Falha ao analisar a resposta do GitHub sobre o repositório criado.")
+    } else {
+        let status = response.status();
+        let message = read_api_error(response).await;
+
+        // O 422 é usado pelo GitHub para erros de validação; o caso comum aqui
+        // é um repositório com o mesmo nome já existir na conta.
+        if status == StatusCode::UNPROCESSABLE_ENTITY {
+            return Err(anyhow!(
+                "O GitHub recusou a criação: já existe um repositório chamado '{}' na sua conta, \
+                 ou o nome é inválido.\n(Resposta da API: {})",
+                name,
+                message
+            ));
+        }
+
+        Err(anyhow!(
+            "Falha ao criar o repositório no GitHub (Status: {}):\n{}",
+            status,
+            message
+        ))
+    }
+}
+
+/// Obtém as informações de um repositório.
+///
+/// # Arguments
+/// * `owner` - O nome do dono do repositório.
+/// * `repo` - O nome do repositório.
+///
+/// # Returns
+/// `Ok(RepoInfo)`, ou um `Err` detalhado em caso de falha (ex: repositório
+/// inexistente ou sem permissão de acesso).
+pub async fn get_repo(owner: &str, repo: &str) -> Result<RepoInfo> {
+    let client = build_client()?;
+    let url = format!("{}/repos/{}/{}", GITHUB_API_BASE_URL, owner, repo);
+
+    let response = send_with_retry(client.get(&url)).await?;
+
+    if response.status().is_success() {
+        response
+            .json()
+            .await
+            .context("Falha ao analisar as informações do repositório retornadas pelo GitHub.")
+    } else {
+        let status = response.status();
+        Err(anyhow!(
+            "Falha ao obter o repositório no GitHub (Status: {}):\n{}",
+            status,
+            read_api_error(response).await
+        ))
+    }
+}
+
+/// A parte usada da resposta de `GET /repos/{owner}/{repo}/branches/{branch}`.
+#[derive(Deserialize)]
+struct BranchProtectionSummary {
+    protected: bool,
+}
+
+/// Verifica se uma branch é protegida no GitHub.
+///
+/// Consulta `GET /repos/{owner}/{repo}/branches/{branch}/protection`. Esse
+/// endpoint exige permissão de administrador; sem ela (403, ou 404 sem a
+/// mensagem "Branch not protected"), a consulta recorre ao campo `protected`
+/// de `GET /repos/{owner}/{repo}/branches/{branch}`, visível a quem pode ler
+/// o repositório.
+///
+/// # Arguments
+/// * `owner` - O nome do dono do repositório.
+/// * `repo` - O nome do repositório.
+/// * `branch` - O nome da branch.
+///
+/// # Returns
+/// `Ok(true)` se a branch for protegida, `Ok(false)` se não for (ou se ela
+/// não existir no GitHub), ou `Err` se não for possível determinar.
+pub async fn is_branch_protected(owner: &str, repo: &str, branch: &str) -> Result<bool> {
+    let client = build_client()?;
+    let branch_url = format!("{}/repos/{}/{}/branches/{}", GITHUB_API_BASE_URL, owner, repo, branch);
+
+    let response = send_with_retry(client.get(&format!("{}/protection", branch_url))).await?;
+    let status = response.status();
+    if status.is_success() {
+        return Ok(true);
+    }
+    if status == StatusCode::NOT_FOUND && read_api_error(response).await.contains("Branch not protected") {
+        return Ok(false);
+    }
+
+    let response = send_with_retry(client.get(&branch_url)).await?;
+    let status = response.status();
+    if status.is_success() {
+        let summary: BranchProtectionSummary = response
+            .json()
+            .await
+            .context("Falha ao analisar a branch retornada pelo GitHub.")?;
+        Ok(summary.protected)
+    } else if status == StatusCode::NOT_FOUND {
+        // A branch ainda não existe no GitHub; não há o que proteger.
+        Ok(false)
+    } else {
+        Err(anyhow!(
+            "Falha ao verificar a proteção da branch '{}' no GitHub (Status: {}):\n{}",
+            branch,
+            status,
+            read_api_error(response).await
+        ))
+    }
+}
+
+/// Obtém a branch padrão de um repositório, consultando a API uma única vez
+/// por sessão.
+///
+/// # Arguments
+/// * `owner` - O nome do dono do repositório.
+/// * `repo` - O nome do repositório.
+///
+/// # Returns
+/// `Ok(String)` com o nome da branch (ex: "main"), ou um `Err` se a consulta falhar.
+pub async fn default_remote_branch(owner: &str, repo: &str) -> Result<String> {
+    let key = format!("{}/{}", owner, repo);
+    let cache = DEFAULT_BRANCH_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Some(branch) = cache.lock().unwrap().get(&key) {
+        return Ok(branch.clone());
+    }
 
+    let branch = get_repo(owner, repo).await?.default_branch;
+    cache.lock().unwrap().insert(key, branch.clone());
+    Ok(branch)
+}
+
+/// Obtém o usuário autenticado pelo token configurado.
+///
+/// É a forma mais rápida de confirmar que o token funciona antes de tentar
+/// uma operação maior, como criar uma release.
+///
+/// # Returns
+/// `Ok(GitHubUser)`, ou um `Err` com uma mensagem clara se o token for
+/// inválido ou tiver expirado (401) ou se a requisição falhar.
+pub async fn get_authenticated_user() -> Result<GitHubUser> {
+    let client = build_client()?;
+    let url = format!("{}/user", GITHUB_API_BASE_URL);
+
+    let response = send_with_retry(client.get(&url)).await?;
+
+    let status = response.status();
+    if status.is_success() {
+        response
+            .json()
+            .await
+            .context("Falha ao analisar o usuário retornado pelo GitHub.")
+    } else if status == StatusCode::UNAUTHORIZED {
+        Err(anyhow!(
+            "O token do GitHub é inválido ou expirou. Gere um novo token e atualize-o em: {}",
+            config::get_config_path()?.display()
+        ))
+    } else {
         Err(anyhow!(
-            "Falha ao criar a release no GitHub (Status: {}):\n{}",
+            "Falha ao obter o usuário autenticado no GitHub (Status: {}):\n{}",
             status,
-            error_message
+            read_api_error(response).await
         ))
     }
 }
\ No newline at end of file
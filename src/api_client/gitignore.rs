@@ -0,0 +1,76 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Cliente da API do gitignore.io
+//
+// Obtém templates de `.gitignore` do serviço gitignore.io (mantido pela
+// Toptal). A API é pública e não exige autenticação: basta informar as
+// tecnologias desejadas, separadas por vírgula, no caminho da URL.
+// ==============================================================================
+
+use crate::config;
+use anyhow::{anyhow, Context, Result};
+use reqwest::header::USER_AGENT;
+use reqwest::Client;
+use std::time::Duration;
+
+const GITIGNORE_API_BASE_URL: &str = "https://www.toptal.com/developers/gitignore/api";
+const APP_USER_AGENT: &str = "gitph-cli/0.1.0";
+
+/// Tempo máximo de espera pela API. Como existe um fallback offline, é melhor
+/// desistir rapidamente do que deixar o usuário esperando.
+const REQUEST_TIMEOUT_SECS: u64 = 10;
+
+/// Marcador que a API insere no texto quando uma tecnologia é desconhecida.
+const UNKNOWN_TEMPLATE_MARKER: &str = "#!! ERROR:";
+
+/// Baixa o template de `.gitignore` combinado para as tecnologias informadas.
+///
+/// # Arguments
+/// * `langs` - As tecnologias (ex: `["rust", "node"]`), em qualquer capitalização.
+///
+/// # Returns
+/// `Ok(String)` com o conteúdo do template, ou `Err` se a requisição falhar
+/// ou alguma das tecnologias não for reconhecida pelo serviço.
+pub async fn fetch_template(langs: &[String]) -> Result<String> {
+    if langs.is_empty() {
+        return Err(anyhow!("Informe ao menos uma linguagem ou tecnologia."));
+    }
+    config::ensure_online("o gitignore.io")?;
+
+    let list = langs
+        .iter()
+        .map(|l| l.trim().to_lowercase())
+        .collect::<Vec<_>>()
+        .join(",");
+    let url = format!("{}/{}", GITIGNORE_API_BASE_URL, list);
+
+    let response = Client::new()
+        .get(&url)
+        .header(USER_AGENT, APP_USER_AGENT)
+        .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .send()
+        .await
+        .context("Falha ao conectar ao gitignore.io.")?;
+
+    let status = response.status();
+    let body = response
+        .text()
+        .await
+        .context("Falha ao ler a resposta do gitignore.io.")?;
+
+    // Para tecnologias desconhecidas, a API responde com 404 e uma linha de
+    // erro dentro do próprio texto do template.
+    if let Some(line) = body.lines().find(|l| l.starts_with(UNKNOWN_TEMPLATE_MARKER)) {
+        return Err(anyhow!("O gitignore.io não reconheceu a entrada: {}", line));
+    }
+    if !status.is_success() {
+        return Err(anyhow!("O gitignore.io respondeu com o status {}.", status));
+    }
+
+    Ok(body)
+}
\ No newline at end of file
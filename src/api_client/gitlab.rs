@@ -0,0 +1,115 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Cliente da API do GitLab
+//
+// Este módulo contém a lógica para interagir com a API REST (v4) do GitLab,
+// espelhando o fluxo de criação de releases do cliente do GitHub.
+//
+// Diferente do GitHub, o GitLab pode ser auto-hospedado. Por isso, a URL base
+// da API é derivada do host do remoto (ex: `https://gitlab.empresa.com/api/v4`),
+// e os projetos são identificados pelo caminho completo codificado na URL
+// (ex: `grupo%2Fsubgrupo%2Fprojeto`).
+// ==============================================================================
+
+use crate::config;
+use anyhow::{anyhow, Context, Result};
+use reqwest::Client;
+use reqwest::header::USER_AGENT;
+use serde::Serialize;
+
+const APP_USER_AGENT: &str = "gitph-cli/0.1.0";
+
+/// Define o corpo JSON da requisição de criação de Release no GitLab.
+#[derive(Serialize)]
+struct CreateReleasePayload<'a> {
+    tag_name: &'a str,
+    name: &'a str,
+    description: &'a str,
+}
+
+/// Cria uma nova Release no GitLab associada a uma tag existente.
+///
+/// # Arguments
+/// * `host` - O host da instância do GitLab (ex: "gitlab.com").
+/// * `project` - O caminho completo do projeto (ex: "grupo/projeto").
+/// * `tag` - A tag que esta release irá marcar. A tag já deve existir no remoto.
+/// * `name` - O título da release.
+/// * `description` - As notas da release, em formato Markdown.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou um `Err` detalhado em caso de falha.
+pub async fn create_release(host: &str, project: &str, tag: &str, name: &str, description: &str) -> Result<()> {
+    config::ensure_online("a API do GitLab")?;
+
+    // --- PASSO 1: Obter o Token de Autenticação ---
+    let config = config::load()?;
+    let token = match config.gitlab_token {
+        Some(t) => t,
+        None => {
+            return Err(anyhow!(
+                "Token da API do GitLab não encontrado.\n\
+                 Por favor, adicione seu token ao arquivo de configuração: {}\n\
+                 Exemplo: gitlab_token = \"seu_token_aqui\"",
+                config::get_config_path()?.display()
+            ));
+        }
+    };
+
+    // --- PASSO 2: Construir o Payload da Requisição ---
+    let payload = CreateReleasePayload {
+        tag_name: tag,
+        name,
+        description,
+    };
+
+    // --- PASSO 3: Construir e Enviar a Requisição HTTP ---
+    let url = releases_url(host, project);
+
+    let response = Client::new()
+        .post(&url)
+        // O GitLab autentica tokens de acesso pessoal pelo cabeçalho `PRIVATE-TOKEN`.
+        .header("PRIVATE-TOKEN", token)
+        .header(USER_AGENT, APP_USER_AGENT)
+        .json(&payload)
+        .send()
+        .await
+        .context("Falha ao enviar a requisição para a API do GitLab.")?;
+
+    // --- PASSO 4: Processar a Resposta ---
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        // O formato dos erros do GitLab varia (`message` pode ser texto ou um
+        // objeto com erros por campo), então exibimos o corpo da resposta.
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        Err(anyhow!(
+            "Falha ao criar a release no GitLab (Status: {}):\n{}",
+            status,
+            body.trim()
+        ))
+    }
+}
+
+/// A URL do endpoint de releases de um projeto (usada por `create_release`).
+pub fn releases_url(host: &str, project: &str) -> String {
+    format!("https://{}/api/v4/projects/{}/releases", host, encode_project_path(project))
+}
+
+/// Codifica o caminho de um projeto para uso como identificador na URL da API.
+///
+/// Caracteres fora do conjunto "não reservado" da RFC 3986 (incluindo a `/`
+/// que separa grupos e projeto) são convertidos para o formato `%XX`.
+fn encode_project_path(path: &str) -> String {
+    path.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
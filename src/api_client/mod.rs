@@ -15,8 +15,52 @@
 // apenas este módulo (e seus sub-módulos) precisará ser atualizado.
 // ==============================================================================
 
+use std::future::Future;
+
 /// Módulo para interações com a API REST do GitHub.
 pub mod github;
 
-// No futuro, poderíamos adicionar outros clientes aqui, mantendo a organização:
-// pub mod gitlab;
\ No newline at end of file
+/// Módulo para interações com a API REST do GitLab.
+pub mod gitlab;
+
+/// Módulo para obter templates de `.gitignore` do gitignore.io.
+pub mod gitignore;
+
+/// As plataformas de hospedagem (forges) suportadas pelos clientes de API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Forge {
+    GitHub,
+    GitLab,
+}
+
+/// Identifica a plataforma de hospedagem a partir do host de um remoto.
+///
+/// O GitHub é reconhecido apenas em `github.com`. Qualquer host que contenha
+/// "gitlab" (ex: `gitlab.com`, `gitlab.empresa.com`) é tratado como GitLab,
+/// cobrindo também instâncias auto-hospedadas.
+///
+/// # Returns
+/// `Some(Forge)` para hosts reconhecidos, ou `None` caso contrário.
+pub fn forge_for_host(host: &str) -> Option<Forge> {
+    let host = host.to_lowercase();
+    if host == "github.com" {
+        Some(Forge::GitHub)
+    } else if host.contains("gitlab") {
+        Some(Forge::GitLab)
+    } else {
+        None
+    }
+}
+
+/// Executa uma operação assíncrona da API até o fim, bloqueando a thread atual.
+///
+/// Esta é a fronteira entre o código síncrono da UI e os clientes assíncronos.
+/// O runtime Tokio é criado (e "entrado") em `main.rs`; aqui apenas obtemos
+/// um handle para ele. Enquanto a thread da UI aguarda, as threads do runtime
+/// continuam livres para processar a rede.
+///
+/// # Panics
+/// Se chamada fora do contexto do runtime criado em `main.rs`.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    tokio::runtime::Handle::current().block_on(future)
+}
\ No newline at end of file
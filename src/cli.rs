@@ -12,10 +12,16 @@
 // ações diretamente, como `gitph cnb nova-feature`, sem entrar no menu.
 // ==============================================================================
 
-use crate::git_wrapper::{branch, clone};
+use crate::api_client;
+use crate::config;
+use crate::git_wrapper::{branch, clone, fetch, hooks, init, log, pull, push, stats, tag};
+use crate::gitignore::{self, TemplateSource, WriteMode};
+use crate::native_bindings;
+use crate::ui::{menus, output, plan};
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 use console::style;
+use std::path::PathBuf;
 
 /// A estrutura principal que define a CLI.
 /// `clap` usará esta struct e seus atributos para gerar o parser de argumentos,
@@ -28,6 +34,32 @@ use console::style;
     long_about = "Uma ferramenta de linha de comando que simplifica operações comuns do Git através de um painel interativo ou comandos diretos."
 )]
 pub struct Cli {
+    /// Exibe informações de diagnóstico, como os comandos Git executados.
+    /// Repita a flag (`-vv`) para um nível de detalhe ainda maior.
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Desativa as cores na saída (útil ao redirecionar para arquivos ou logs).
+    /// A variável de ambiente `NO_COLOR` tem o mesmo efeito.
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// Executa como se o `gitph` tivesse sido iniciado em `<CAMINHO>`, como o
+    /// `git -C` (ex: `gitph -C ../outro-repo stats`).
+    #[arg(short = 'C', global = true, value_name = "CAMINHO")]
+    pub directory: Option<PathBuf>,
+
+    /// Desativa todas as operações de rede (push, pull, fetch, clone e as APIs
+    /// do GitHub/GitLab), que passam a falhar com uma mensagem clara. O mesmo
+    /// que `offline = true` na configuração.
+    #[arg(long, global = true)]
+    pub offline: bool,
+
+    /// Exibe apenas os erros e a saída essencial dos comandos, sem
+    /// cabeçalhos, mensagens de sucesso ou spinners (útil em scripts).
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
     /// O subcomando a ser executado.
     /// Se nenhum subcomando for fornecido, a aplicação iniciará o menu interativo.
     #[command(subcommand)]
@@ -38,11 +70,35 @@ pub struct Cli {
 /// Cada variante corresponde a uma ação que o usuário pode executar.
 #[derive(Subcommand, Debug)]
 pub enum Commands {
+    /// [snd] Adiciona, commita e envia as alterações (fluxo guiado).
+    Snd {
+        /// Apenas exibe os comandos Git e chamadas de API que seriam executados.
+        #[arg(long)]
+        plan: bool,
+    },
+    /// [rls] Executa o SND e cria uma tag e uma release (fluxo guiado).
+    Rls {
+        /// Apenas exibe os comandos Git e chamadas de API que seriam executados.
+        #[arg(long)]
+        plan: bool,
+    },
     /// [cnb] Cria uma nova branch local.
     Cnb {
         /// O nome da nova branch a ser criada.
         name: String,
     },
+    /// [rename] Renomeia uma branch local.
+    ///
+    /// Com um único nome, renomeia a branch atual para ele (como `git branch -m`).
+    Rename {
+        /// O novo nome da branch atual ou, se NOVO_NOME for informado, a
+        /// branch a ser renomeada.
+        #[arg(value_name = "NOME")]
+        name: String,
+        /// O novo nome da branch informada em NOME.
+        #[arg(value_name = "NOVO_NOME")]
+        new_name: Option<String>,
+    },
     /// [cb] Muda para uma branch existente.
     Cb {
         /// O nome da branch para a qual mudar.
@@ -53,12 +109,111 @@ pub enum Commands {
         /// A URL (HTTPS ou SSH) do repositório a ser clonado.
         url: String,
     },
+    /// [fetch] Busca atualizações de um remoto sem integrá-las à branch local.
+    Fetch {
+        /// O remoto de onde buscar as atualizações.
+        #[arg(default_value = "origin", conflicts_with = "all")]
+        remote: String,
+        /// Remove referências de branches que foram apagadas no remoto.
+        #[arg(long)]
+        prune: bool,
+        /// Busca de todos os remotos configurados.
+        #[arg(long)]
+        all: bool,
+    },
+    /// [push] Envia os commits da branch atual para o remoto.
+    Push {
+        /// Apenas simula o envio, mostrando as referências que seriam atualizadas.
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// [push-tag] Envia uma tag para o remoto 'origin'.
+    PushTag {
+        /// O nome da tag a ser enviada.
+        name: String,
+        /// Apenas simula o envio, sem alterar o remoto.
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// [pull] Puxa as alterações do upstream para a branch atual.
+    ///
+    /// A integração usa merge ou rebase conforme `pull_mode` na configuração.
+    Pull,
+    /// [sync] Busca o remoto, integra o upstream e envia os commits locais.
+    ///
+    /// A integração usa merge ou rebase conforme `pull_mode` na configuração.
+    Sync,
+    /// [init] Cria um novo repositório Git vazio.
+    Init {
+        /// O diretório do novo repositório (o atual, se omitido).
+        #[arg(default_value = ".")]
+        path: PathBuf,
+        /// O nome da branch inicial.
+        #[arg(short = 'b', long, default_value = init::DEFAULT_INITIAL_BRANCH)]
+        initial_branch: String,
+    },
+    /// [gitignore] Gera um arquivo `.gitignore` para as tecnologias informadas.
+    Gitignore {
+        /// As linguagens ou tecnologias (ex: rust node python).
+        #[arg(required = true)]
+        langs: Vec<String>,
+        /// Acrescenta ao `.gitignore` existente em vez de recusar.
+        #[arg(long, conflicts_with = "force")]
+        append: bool,
+        /// Substitui o `.gitignore` existente.
+        #[arg(long)]
+        force: bool,
+    },
+    /// [graph] Exibe o grafo do histórico recente.
+    Graph {
+        /// O número máximo de commits exibidos.
+        #[arg(short = 'n', long, default_value_t = 30)]
+        limit: usize,
+    },
+    /// [stats] Exibe os commits por autor e o total de linhas alteradas.
+    Stats {
+        /// O número máximo de commits recentes analisados.
+        #[arg(short = 'n', long, default_value_t = stats::DEFAULT_COMMIT_LIMIT)]
+        limit: usize,
+    },
+    /// [show] Exibe os detalhes de um commit.
+    Show {
+        /// O hash ou a referência do commit (ex: HEAD~1, v1.0).
+        #[arg(default_value = "HEAD")]
+        rev: String,
+    },
+    /// [install-hooks] Instala um hook `commit-msg` que exige mensagens no
+    /// padrão Conventional Commits.
+    ///
+    /// Um hook `commit-msg` existente é preservado como `commit-msg.bak`.
+    InstallHooks,
+    /// [whoami] Mostra o usuário do GitHub autenticado pelo token configurado.
+    Whoami,
+    /// [config] Inspeciona o arquivo de configuração do gitph.
+    Config {
+        #[command(subcommand)]
+        action: ConfigCommands,
+    },
+    /// Verifica a integração com o módulo nativo C++ (ferramenta de desenvolvimento).
+    ///
+    /// Oculto da ajuda, pois não tem utilidade para o usuário final.
+    #[command(hide = true)]
+    Selftest,
     // NOTA: Os comandos `snd` e `rls` são intencionalmente omitidos da CLI direta
     // por enquanto, pois seus fluxos de trabalho são inerentemente interativos
     // (exigem prompts para mensagens de commit, notas de release, etc.).
     // Eles permanecem como as principais funcionalidades do modo de painel.
 }
 
+/// Os subcomandos de `gitph config`.
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommands {
+    /// Mostra onde fica o arquivo de configuração e se ele já existe.
+    Path,
+    /// Verifica os arquivos de configuração (global e do repositório).
+    Validate,
+}
+
 /// Lida com a execução de um subcomando que foi analisado pela `clap`.
 ///
 /// Esta função atua como um despachante, chamando a lógica apropriada do
@@ -71,27 +226,195 @@ pub enum Commands {
 /// `Ok(())` se a ação foi bem-sucedida, ou `Err` se ocorreu um erro.
 pub fn handle_cli_command(command: Commands) -> Result<()> {
     match command {
+        Commands::Snd { plan: true } => match plan::snd_plan() {
+            Ok(steps) => plan::display_plan(&steps),
+            Err(e) => output::error(e),
+        },
+        Commands::Rls { plan: true } => match plan::rls_plan() {
+            Ok(steps) => plan::display_plan(&steps),
+            Err(e) => output::error(e),
+        },
+        Commands::Snd { plan: false } => menus::handle_snd_action()?,
+        Commands::Rls { plan: false } => menus::handle_rls_action()?,
         Commands::Cnb { name } => {
-            println!("Criando nova branch '{}'...", style(&name).cyan());
+            output::info(format!("Criando nova branch '{}'...", style(&name).cyan()));
             match branch::create_branch(&name) {
-                Ok(()) => println!("{}", style("✔ Branch criada com sucesso.").green()),
-                Err(e) => eprintln!("{} {}", style("Erro:").red().bold(), style(e).red()),
+                Ok(()) => output::success("✔ Branch criada com sucesso."),
+                Err(e) => output::error(e),
+            }
+        }
+        Commands::Rename { name, new_name } => {
+            let result = match &new_name {
+                Some(new_name) => branch::rename_branch(&name, new_name),
+                None => branch::rename_current_branch(&name),
+            };
+            match result {
+                Ok(()) => output::success(format!(
+                    "✔ Branch renomeada para '{}'.",
+                    new_name.as_deref().unwrap_or(&name).trim()
+                )),
+                Err(e) => output::error(e),
             }
         }
         Commands::Cb { name } => {
-            println!("Mudando para a branch '{}'...", style(&name).cyan());
+            output::info(format!("Mudando para a branch '{}'...", style(&name).cyan()));
             match branch::switch_branch(&name) {
-                Ok(()) => println!("{}", style("✔ Mudou para a branch com sucesso.").green()),
-                Err(e) => eprintln!("{} {}", style("Erro:").red().bold(), style(e).red()),
+                Ok(()) => output::success("✔ Mudou para a branch com sucesso."),
+                Err(e) => output::error(e),
             }
         }
         Commands::Clone { url } => {
             // A função `clone_repository` já imprime seu próprio feedback em tempo real,
             // então não precisamos de mensagens de sucesso/erro adicionais aqui.
             if let Err(e) = clone::clone_repository(&url) {
-                eprintln!("\n{} {}", style("Erro:").red().bold(), style(e).red());
+                output::blank_line();
+                output::error(e);
+            }
+        }
+        Commands::Fetch { remote, prune, all } => {
+            let result = if all {
+                fetch::fetch_all(prune)
+            } else {
+                fetch::fetch(&remote, prune)
+            };
+            match result {
+                Ok(summary) => menus::display_fetch_summary(&summary),
+                Err(e) => output::error(e),
+            }
+        }
+        Commands::Push { dry_run } => report_push(push::push(dry_run), dry_run),
+        Commands::PushTag { name, dry_run } => report_push(tag::push_tag(&name, dry_run), dry_run),
+        Commands::Pull => menus::handle_pull_action()?,
+        Commands::Sync => {
+            let mode = config::load()?.pull_mode;
+            match pull::sync(mode) {
+                Ok(report) => menus::display_sync_report(&report),
+                Err(e) => {
+                    output::blank_line();
+                    output::error(e);
+                }
+            }
+        }
+        Commands::Init { path, initial_branch } => {
+            match init::init(&path, Some(&initial_branch)) {
+                Ok(()) => output::success(format!(
+                    "✔ Repositório criado em '{}' com a branch '{}'.",
+                    path.display(),
+                    initial_branch
+                )),
+                Err(e) => output::error(e),
+            }
+        }
+        Commands::Gitignore { langs, append, force } => {
+            // Sem um prompt para perguntar, nunca sobrescrevemos um arquivo
+            // existente sem uma flag explícita.
+            let mode = if append { WriteMode::Append } else { WriteMode::Overwrite };
+            if gitignore::exists() && !append && !force {
+                output::error("Já existe um .gitignore. Use --append para acrescentar ou --force para substituí-lo.");
+                return Ok(());
+            }
+            match gitignore::generate(&langs).and_then(|(content, source)| {
+                gitignore::write(&content, mode).map(|()| source)
+            }) {
+                Ok(source) => {
+                    if source == TemplateSource::Bundled {
+                        output::warning("gitignore.io indisponível; usando os templates embutidos.");
+                    }
+                    output::success("✔ .gitignore gerado com sucesso.");
+                }
+                Err(e) => output::error(e),
             }
         }
+        Commands::Graph { limit } => match log::commit_graph(limit) {
+            Ok(lines) if lines.is_empty() => output::warning("A branch atual ainda não tem commits."),
+            Ok(lines) => menus::display_commit_graph(&lines),
+            Err(e) => output::error(e),
+        },
+        Commands::Stats { limit } => match stats::repo_stats(limit) {
+            Ok(stats) => menus::display_repo_stats(&stats),
+            Err(e) => output::error(e),
+        },
+        Commands::Show { rev } => match log::show_commit(&rev) {
+            Ok(details) => menus::display_commit_details(&details),
+            Err(e) => output::error(e),
+        },
+        Commands::InstallHooks => match hooks::install_commit_msg_hook() {
+            Ok(install) => {
+                if let Some(backup) = &install.backup {
+                    output::warning(format!("O hook existente foi guardado em '{}'.", backup.display()));
+                }
+                output::success(format!("✔ Hook commit-msg instalado em '{}'.", install.path.display()));
+            }
+            Err(e) => output::error(e),
+        },
+        Commands::Whoami => match api_client::block_on(api_client::github::get_authenticated_user()) {
+            Ok(user) => menus::display_authenticated_user(&user),
+            Err(e) => output::error(e),
+        },
+        Commands::Config { action } => match action {
+            ConfigCommands::Path => {
+                let path = config::get_config_path()?;
+                output::data(path.display());
+                if path.exists() {
+                    output::success("O arquivo existe.");
+                } else {
+                    output::warning("O arquivo ainda não existe; os valores padrão estão em uso.");
+                }
+                if let Some(repo_config) = config::find_repo_config() {
+                    output::info(format!(
+                        "{} {}",
+                        style("Sobrescrito neste repositório por:").dim(),
+                        repo_config.display()
+                    ));
+                }
+            }
+            ConfigCommands::Validate => {
+                let mut files = vec![config::get_config_path()?];
+                files.extend(config::find_repo_config());
+                files.retain(|p| p.exists());
+                if files.is_empty() {
+                    output::warning("Nenhum arquivo de configuração encontrado; os valores padrão estão em uso.");
+                }
+                for path in files {
+                    output::data(style(path.display()).bold());
+                    // Assim como o autoteste, propagamos o erro para que o comando
+                    // encerre com um código de saída não-zero em scripts.
+                    let warnings = config::validate_file(&path)?;
+                    for warning in &warnings {
+                        output::data(format!("  {}", style(warning).yellow()));
+                    }
+                    if warnings.is_empty() {
+                        output::data(format!("  {}", style("OK").green()));
+                    }
+                }
+            }
+        },
+        Commands::Selftest => {
+            output::header("--- Verificação da Integração Nativa ---");
+            // Diferente dos outros comandos, propagamos o erro: o autoteste é
+            // usado em scripts e precisa encerrar com um código de saída não-zero.
+            native_bindings::run_self_test()?;
+            output::success("✔ Todas as chamadas FFI funcionaram.");
+        }
     }
     Ok(())
+}
+
+/// Exibe o resultado de um push (de commits ou de tag) feito pela CLI.
+///
+/// Em um dry-run, deixa explícito que nada foi enviado ao remoto.
+fn report_push(result: Result<String>, dry_run: bool) {
+    match result {
+        Ok(git_output) => {
+            if !git_output.is_empty() {
+                output::info(style(git_output).dim());
+            }
+            if dry_run {
+                output::warning("Isto foi uma simulação (dry-run); nada foi enviado.");
+            } else {
+                output::success("✔ Push realizado com sucesso.");
+            }
+        }
+        Err(e) => output::error(e),
+    }
 }
\ No newline at end of file
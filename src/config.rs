@@ -10,19 +10,140 @@
 // Este módulo gerencia todas as configurações persistentes do `gitph`.
 // Ele é responsável por carregar, salvar e fornecer acesso a configurações
 // como tokens de API, preferências do usuário, etc.
+//
+// A configuração é montada em camadas, da menor para a maior precedência:
+//   1. O arquivo global do usuário (veja `get_config_path`).
+//   2. Variáveis de ambiente (`GITPH_GITHUB_TOKEN`, `GITPH_GITLAB_TOKEN`).
+//   3. Um arquivo `.gitph.toml` do repositório atual (veja `find_repo_config`).
+// Assim, um projeto de cliente ou um monorepo pode usar um token diferente
+// do padrão do usuário sem alterar a configuração global.
 // ==============================================================================
 
+use crate::git_wrapper::{self, pull::PullMode};
 use anyhow::{anyhow, Context, Result};
 use directories::ProjectDirs;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::env;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// O nome do arquivo de configuração local de um repositório.
+pub const REPO_CONFIG_FILE: &str = ".gitph.toml";
 
 /// Define a estrutura dos dados de configuração da aplicação.
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct Config {
+    /// A versão do formato do arquivo. Arquivos sem este campo são da versão 1.
+    /// Veja `MIGRATIONS` para como arquivos antigos são atualizados.
+    #[serde(default = "default_version")]
+    pub version: u32,
+
     /// Token de Acesso Pessoal (PAT) para a API do GitHub.
     pub github_token: Option<String>,
+
+    /// Token de Acesso Pessoal (PAT) para a API do GitLab.
+    pub gitlab_token: Option<String>,
+
+    /// Se `true`, commits e tags criados pelo `gitph` são assinados com GPG.
+    /// Útil para repositórios que exigem commits verificados.
+    #[serde(default)]
+    pub sign_commits: bool,
+
+    /// Se `true`, commits recebem o trailer `Signed-off-by` (DCO) por padrão.
+    #[serde(default)]
+    pub signoff_by_default: bool,
+
+    /// Número máximo de tentativas para chamadas à API do GitHub que falham
+    /// por motivos transitórios (rede instável, erros 5xx, limite de requisições).
+    #[serde(default = "default_api_max_retries")]
+    pub api_max_retries: u32,
+
+    /// Tempo máximo, em segundos, de cada requisição à API do GitHub.
+    #[serde(default = "default_api_timeout_secs")]
+    pub api_timeout_secs: u64,
+
+    /// Se `true`, o fluxo SND pede confirmação antes de enviar o commit ao
+    /// remoto. Usuários experientes podem desativá-lo para um push direto.
+    #[serde(default = "default_confirm_before_push")]
+    pub confirm_before_push: bool,
+
+    /// Tempo máximo, em segundos, para operações de rede do Git (clone, fetch,
+    /// push). Ao ser excedido, o processo é encerrado. Ausente (o padrão)
+    /// significa sem limite.
+    #[serde(default)]
+    pub network_timeout_secs: Option<u64>,
+
+    /// Como o `sync` integra as alterações do upstream: `"merge"` (o padrão)
+    /// ou `"rebase"`.
+    #[serde(default)]
+    pub pull_mode: PullMode,
+
+    /// Se `true`, saídas longas (histórico, diff, blame) são exibidas pelo
+    /// paginador (`$PAGER` ou `less -R`).
+    #[serde(default = "default_use_pager")]
+    pub use_pager: bool,
+
+    /// Se `true`, a mensagem de commit do fluxo SND é montada por um prompt
+    /// guiado no padrão Conventional Commits (`tipo(escopo): assunto`).
+    #[serde(default)]
+    pub conventional_commits: bool,
+
+    /// Se `true`, todas as operações de rede (push, pull, fetch, clone e as
+    /// APIs) são recusadas, como com a flag `--offline`.
+    #[serde(default)]
+    pub offline: bool,
+}
+
+/// Valor padrão de `version` quando o campo não está no arquivo: arquivos
+/// anteriores ao versionamento são tratados como da versão 1.
+fn default_version() -> u32 {
+    1
+}
+
+/// Valor padrão de `api_max_retries` quando o campo não está no arquivo.
+fn default_api_max_retries() -> u32 {
+    3
+}
+
+/// Valor padrão de `api_timeout_secs` quando o campo não está no arquivo.
+fn default_api_timeout_secs() -> u64 {
+    30
+}
+
+/// Valor padrão de `confirm_before_push` quando o campo não está no arquivo.
+fn default_confirm_before_push() -> bool {
+    true
+}
+
+/// Valor padrão de `use_pager` quando o campo não está no arquivo.
+fn default_use_pager() -> bool {
+    true
+}
+
+// O `Default` é implementado manualmente (em vez de derivado) para que a
+// configuração usada quando o arquivo não existe tenha os mesmos valores
+// padrão aplicados pelo `serde` aos campos ausentes.
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            version: CURRENT_VERSION,
+            github_token: None,
+            gitlab_token: None,
+            sign_commits: false,
+            signoff_by_default: false,
+            api_max_retries: default_api_max_retries(),
+            api_timeout_secs: default_api_timeout_secs(),
+            confirm_before_push: default_confirm_before_push(),
+            network_timeout_secs: None,
+            pull_mode: PullMode::default(),
+            use_pager: default_use_pager(),
+            conventional_commits: false,
+            offline: false,
+        }
+    }
 }
 
 /// Retorna o caminho para o arquivo de configuração da aplicação.
@@ -33,38 +154,392 @@ pub struct Config {
 /// # Returns
 /// Um `Result<PathBuf>` contendo o caminho completo para o arquivo de configuração.
 /// Retorna `Err` se o diretório "home" do usuário não puder ser determinado.
-//
-// CORREÇÃO: Adicionamos `pub` para tornar esta função acessível a outros módulos.
+///
+/// É pública para que mensagens de erro (ex: token ausente) e o comando
+/// `gitph config path` possam mostrar ao usuário onde o arquivo fica.
 pub fn get_config_path() -> Result<PathBuf> {
     if let Some(proj_dirs) = ProjectDirs::from("com", "phkaiser13", "gitph") {
         let config_dir = proj_dirs.config_dir();
         Ok(config_dir.join("config.toml"))
     } else {
-        Err(anyhow::anyhow!("Não foi possível determinar o diretório de configuração do usuário."))
+        Err(anyhow!("Não foi possível determinar o diretório de configuração do usuário."))
+    }
+}
+
+/// Os campos que um `.gitph.toml` de repositório pode sobrescrever.
+///
+/// Todos são opcionais: campos ausentes mantêm o valor das camadas anteriores.
+#[derive(Deserialize, Debug, Default)]
+struct ConfigOverrides {
+    github_token: Option<String>,
+    gitlab_token: Option<String>,
+    sign_commits: Option<bool>,
+    signoff_by_default: Option<bool>,
+    api_max_retries: Option<u32>,
+    api_timeout_secs: Option<u64>,
+    confirm_before_push: Option<bool>,
+    network_timeout_secs: Option<u64>,
+    pull_mode: Option<PullMode>,
+    use_pager: Option<bool>,
+    conventional_commits: Option<bool>,
+    offline: Option<bool>,
+}
+
+impl ConfigOverrides {
+    /// Aplica sobre `config` os campos definidos nesta camada.
+    fn apply_to(self, config: &mut Config) {
+        if let Some(v) = self.github_token {
+            config.github_token = Some(v);
+        }
+        if let Some(v) = self.gitlab_token {
+            config.gitlab_token = Some(v);
+        }
+        if let Some(v) = self.sign_commits {
+            config.sign_commits = v;
+        }
+        if let Some(v) = self.signoff_by_default {
+            config.signoff_by_default = v;
+        }
+        if let Some(v) = self.api_max_retries {
+            config.api_max_retries = v;
+        }
+        if let Some(v) = self.api_timeout_secs {
+            config.api_timeout_secs = v;
+        }
+        if let Some(v) = self.confirm_before_push {
+            config.confirm_before_push = v;
+        }
+        if let Some(v) = self.network_timeout_secs {
+            config.network_timeout_secs = Some(v);
+        }
+        if let Some(v) = self.pull_mode {
+            config.pull_mode = v;
+        }
+        if let Some(v) = self.use_pager {
+            config.use_pager = v;
+        }
+        if let Some(v) = self.conventional_commits {
+            config.conventional_commits = v;
+        }
+        if let Some(v) = self.offline {
+            config.offline = v;
+        }
+    }
+}
+
+/// Procura o `.gitph.toml` do repositório atual.
+///
+/// Sobe a partir do diretório atual (ou do definido com `-C`) até a raiz do
+/// repositório Git (veja `git_wrapper::repo_root`), retornando o arquivo mais
+/// próximo. Em um monorepo, isso permite que um subprojeto tenha sua própria
+/// configuração.
+///
+/// # Returns
+/// `Some(PathBuf)` com o arquivo encontrado, ou `None` se não houver um (ou se
+/// o diretório atual não estiver dentro de um repositório).
+pub fn find_repo_config() -> Option<PathBuf> {
+    let cwd = match git_wrapper::working_dir() {
+        Some(dir) => dir.to_path_buf(),
+        None => env::current_dir().ok()?,
+    };
+    // O Git retorna a raiz sem links simbólicos; o diretório atual precisa
+    // estar na mesma forma para a comparação de prefixos.
+    let cwd = cwd.canonicalize().unwrap_or(cwd);
+    // Fora de um repositório, um `.gitph.toml` solto não é considerado.
+    let root = git_wrapper::repo_root().ok()?;
+    cwd.ancestors()
+        .take_while(|dir| dir.starts_with(&root))
+        .map(|dir| dir.join(REPO_CONFIG_FILE))
+        .find(|file| file.is_file())
+}
+
+/// Se o modo offline está ativo nesta execução (veja `set_offline`).
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// Ativa ou desativa o modo offline para o restante da execução.
+///
+/// Deve ser chamada na inicialização da aplicação, combinando a flag
+/// `--offline` com o campo `offline` da configuração.
+pub fn set_offline(offline: bool) {
+    OFFLINE.store(offline, Ordering::Relaxed);
+}
+
+/// Indica se o modo offline está ativo.
+pub fn is_offline() -> bool {
+    OFFLINE.load(Ordering::Relaxed)
+}
+
+/// Erro retornado por operações de rede quando o modo offline está ativo.
+///
+/// A UI pode identificá-lo via `anyhow::Error::downcast_ref`.
+#[derive(Debug)]
+pub struct OfflineError {
+    /// A operação recusada (ex: "o 'git push'", "a API do GitHub").
+    pub operation: String,
+}
+
+impl fmt::Display for OfflineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "O modo offline está ativo, então {} não pode acessar a rede. \
+             Execute sem '--offline' (e com 'offline = false' na configuração) para usá-lo.",
+            self.operation
+        )
+    }
+}
+
+impl std::error::Error for OfflineError {}
+
+/// Recusa uma operação de rede se o modo offline estiver ativo.
+///
+/// # Arguments
+/// * `operation` - A descrição da operação, usada na mensagem de erro (ex:
+///   "o 'git fetch'").
+///
+/// # Returns
+/// `Ok(())` se a rede puder ser usada, ou um `OfflineError`.
+pub fn ensure_online(operation: &str) -> Result<()> {
+    if is_offline() {
+        return Err(OfflineError {
+            operation: operation.to_string(),
+        }
+        .into());
     }
+    Ok(())
 }
 
-/// Carrega a configuração do arquivo no disco.
+/// Carrega a configuração efetiva, combinando todas as camadas.
 ///
-/// Se o arquivo de configuração não existir, uma configuração padrão (vazia)
-/// é retornada sem gerar erro.
+/// A precedência é: repositório (`.gitph.toml`) > ambiente > global. Camadas
+/// ausentes são simplesmente ignoradas.
 pub fn load() -> Result<Config> {
-    let path = get_config_path()?;
+    let mut config = load_global()?;
 
-    if path.exists() {
-        let content = fs::read_to_string(&path)
-            .with_context(|| format!("Falha ao ler o arquivo de configuração em {:?}", path))?;
+    env_overrides().apply_to(&mut config);
+
+    if let Some(path) = find_repo_config() {
+        ::log::debug!("Usando a configuração do repositório em {:?}", path);
+        let overrides: ConfigOverrides = read_file(&path)?;
+        overrides.apply_to(&mut config);
+    }
+
+    Ok(config)
+}
+
+/// Lê as sobrescritas definidas por variáveis de ambiente.
+///
+/// Apenas os tokens são suportados, o caso de uso comum em CI.
+fn env_overrides() -> ConfigOverrides {
+    let var = |name: &str| env::var(name).ok().filter(|v| !v.trim().is_empty());
+    ConfigOverrides {
+        github_token: var("GITPH_GITHUB_TOKEN"),
+        gitlab_token: var("GITPH_GITLAB_TOKEN"),
+        ..Default::default()
+    }
+}
 
-        let config: Config = toml::from_str(&content)
-            .with_context(|| format!("Falha ao analisar o arquivo de configuração TOML em {:?}", path))?;
+/// Carrega apenas o arquivo de configuração global, sem as outras camadas.
+///
+/// Use esta função quando a configuração for modificada e salva com `save`,
+/// para que valores do repositório ou do ambiente não vazem para o arquivo global.
+/// Se o arquivo não existir, uma configuração padrão (vazia) é retornada sem
+/// gerar erro.
+pub fn load_global() -> Result<Config> {
+    let path = get_config_path()?;
 
-        Ok(config)
+    if path.exists() {
+        migrate(&path)?;
+        read_file(&path)
     } else {
         Ok(Config::default())
     }
 }
 
-/// Salva a estrutura `Config` fornecida no arquivo de configuração no disco.
+/// Os passos de migração do formato do arquivo de configuração global.
+///
+/// O passo de índice `i` converte um arquivo da versão `i + 1` para a versão
+/// `i + 2`, operando diretamente sobre a tabela TOML. Ao introduzir uma
+/// mudança incompatível no formato (ex: renomear ou mover um campo), adicione
+/// um passo ao final desta lista; `CURRENT_VERSION` acompanha automaticamente.
+const MIGRATIONS: &[fn(&mut toml::Table)] = &[];
+
+/// A versão do formato gravada por esta versão do gitph.
+pub const CURRENT_VERSION: u32 = MIGRATIONS.len() as u32 + 1;
+
+/// Atualiza o arquivo de configuração global para o formato atual, se preciso.
+///
+/// Quando há passos a aplicar, o arquivo original é preservado como
+/// `config.toml.bak` e reescrito com `version = CURRENT_VERSION`. Um arquivo
+/// de uma versão mais nova do que a suportada gera um erro, em vez de ser
+/// interpretado (e possivelmente sobrescrito) de forma incorreta.
+///
+/// # Arguments
+/// * `path` - O caminho do arquivo de configuração global.
+fn migrate(path: &Path) -> Result<()> {
+    let mut table: toml::Table = read_file(path)?;
+    let version = match table.get("version") {
+        None => default_version(),
+        Some(value) => value
+            .as_integer()
+            .and_then(|v| u32::try_from(v).ok())
+            .filter(|v| *v >= 1)
+            .ok_or_else(|| {
+                anyhow!(
+                    "Configuração inválida em {:?}: 'version' deve ser um número inteiro positivo.",
+                    path
+                )
+            })?,
+    };
+
+    if version > CURRENT_VERSION {
+        return Err(anyhow!(
+            "O arquivo de configuração em {:?} usa o formato {}, mas esta versão do gitph \
+             suporta até o formato {}. Atualize o gitph.",
+            path,
+            version,
+            CURRENT_VERSION
+        ));
+    }
+    if version == CURRENT_VERSION {
+        return Ok(());
+    }
+
+    for step in &MIGRATIONS[(version - 1) as usize..] {
+        step(&mut table);
+    }
+    table.insert("version".to_string(), toml::Value::Integer(CURRENT_VERSION.into()));
+
+    let backup = path.with_extension("toml.bak");
+    fs::copy(path, &backup)
+        .with_context(|| format!("Falha ao criar a cópia de segurança da configuração em {:?}", backup))?;
+    let content = toml::to_string_pretty(&table)
+        .context("Falha ao serializar a configuração para o formato TOML.")?;
+    fs::write(path, content)
+        .with_context(|| format!("Falha ao escrever no arquivo de configuração em {:?}", path))?;
+
+    ::log::info!(
+        "Configuração atualizada do formato {} para o {} (original em {:?})",
+        version,
+        CURRENT_VERSION,
+        backup
+    );
+    Ok(())
+}
+
+/// As chaves reconhecidas nos arquivos de configuração, com uma descrição do
+/// valor esperado, usada nas mensagens de erro.
+const KNOWN_KEYS: &[(&str, &str)] = &[
+    ("version", "um número inteiro positivo (gerenciado pelo gitph)"),
+    ("github_token", "um texto entre aspas (ex: github_token = \"ghp_...\")"),
+    ("gitlab_token", "um texto entre aspas (ex: gitlab_token = \"glpat-...\")"),
+    ("sign_commits", "true ou false"),
+    ("signoff_by_default", "true ou false"),
+    ("api_max_retries", "um número inteiro não negativo (ex: api_max_retries = 3)"),
+    ("api_timeout_secs", "um número inteiro de segundos (ex: api_timeout_secs = 30)"),
+    ("confirm_before_push", "true ou false"),
+    ("network_timeout_secs", "um número inteiro de segundos (ex: network_timeout_secs = 120)"),
+    ("pull_mode", "\"merge\" ou \"rebase\""),
+    ("use_pager", "true ou false"),
+    ("conventional_commits", "true ou false"),
+    ("offline", "true ou false"),
+];
+
+/// Lê e desserializa um arquivo de configuração.
+///
+/// Erros de sintaxe ou de tipo são traduzidos por `describe_parse_error`,
+/// indicando a linha, a chave e o valor esperado.
+fn read_file<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Falha ao ler o arquivo de configuração em {:?}", path))?;
+    toml::from_str(&content).map_err(|e| {
+        anyhow!(
+            "Configuração inválida em {:?}: {}",
+            path,
+            describe_parse_error(&content, &e)
+        )
+    })
+}
+
+/// Transforma um erro do `toml` em uma mensagem que aponta a chave problemática.
+///
+/// O `toml` informa apenas a posição do erro; a chave é obtida da linha
+/// correspondente do arquivo. Para chaves conhecidas, acrescentamos uma dica
+/// com o tipo de valor esperado.
+fn describe_parse_error(content: &str, error: &toml::de::Error) -> String {
+    let message = error.message().trim();
+    let Some(span) = error.span() else {
+        return message.to_string();
+    };
+
+    let line_number = content[..span.start].matches('\n').count() + 1;
+    let line = content.lines().nth(line_number - 1).unwrap_or_default();
+    let key = line.split_once('=').map(|(k, _)| k.trim().trim_matches('"'));
+
+    match key.and_then(|k| KNOWN_KEYS.iter().find(|(name, _)| *name == k)) {
+        Some((name, expected)) => format!(
+            "linha {}, chave '{}': {}.\nDica: '{}' deve ser {}.",
+            line_number, name, message, name, expected
+        ),
+        None => match key {
+            Some(k) if !k.is_empty() => format!("linha {}, chave '{}': {}", line_number, k, message),
+            _ => format!("linha {}: {}", line_number, message),
+        },
+    }
+}
+
+/// Verifica um arquivo de configuração sem aplicá-lo.
+///
+/// Além dos erros que impediriam o carregamento, detecta chaves desconhecidas
+/// (normalmente um erro de digitação), que o carregamento normal ignora.
+///
+/// # Arguments
+/// * `path` - O arquivo a ser verificado (global ou `.gitph.toml`).
+///
+/// # Returns
+/// `Ok(Vec<String>)` com avisos sobre chaves desconhecidas (vazio se o arquivo
+/// estiver perfeito), ou `Err` com o diagnóstico se o arquivo for inválido.
+pub fn validate_file(path: &Path) -> Result<Vec<String>> {
+    let _: ConfigOverrides = read_file(path)?;
+    let table: toml::Table = read_file(path)?;
+
+    Ok(table
+        .keys()
+        .filter(|key| !KNOWN_KEYS.iter().any(|(name, _)| name == key))
+        .map(|key| match closest_known_key(key) {
+            Some(suggestion) => format!("Chave desconhecida '{}'. Você quis dizer '{}'?", key, suggestion),
+            None => format!("Chave desconhecida '{}' (será ignorada).", key),
+        })
+        .collect())
+}
+
+/// Sugere a chave conhecida mais parecida com `key`, se houver uma próxima o
+/// suficiente (distância de edição de no máximo 3).
+fn closest_known_key(key: &str) -> Option<&'static str> {
+    KNOWN_KEYS
+        .iter()
+        .map(|(name, _)| (*name, edit_distance(key, name)))
+        .filter(|(_, distance)| *distance <= 3)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(name, _)| name)
+}
+
+/// Calcula a distância de Levenshtein entre duas strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Salva a estrutura `Config` fornecida no arquivo de configuração global.
 ///
 /// Esta função irá criar o diretório de configuração se ele não existir.
 pub fn save(config: &Config) -> Result<()> {
@@ -0,0 +1,120 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo de Blame do Git
+//
+// Descobre quem alterou cada linha de um arquivo por último, através do
+// `git blame --porcelain`. O formato porcelain é estável e fácil de analisar:
+// cada linha do arquivo é precedida por um cabeçalho com o commit, e os dados
+// do commit (autor, data, etc.) aparecem apenas na primeira vez em que ele é
+// citado.
+// ==============================================================================
+
+use super::{resolve_path, run_git, GitCommandError};
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+
+/// Representa uma linha do arquivo e o commit que a alterou por último.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlameLine {
+    /// O hash completo do commit. Linhas ainda não commitadas têm um hash
+    /// formado apenas por zeros.
+    pub commit: String,
+    /// O nome do autor do commit, já canonicalizado pelo `.mailmap` (o
+    /// `git blame` sempre o aplica).
+    pub author: String,
+    /// O número da linha no arquivo atual, a partir de 1.
+    pub line_no: usize,
+    /// O conteúdo da linha, sem a quebra de linha.
+    pub content: String,
+}
+
+/// Obtém o autor da última alteração de cada linha de um arquivo.
+///
+/// Executa `git blame --porcelain <path>`.
+///
+/// # Arguments
+/// * `path` - O caminho do arquivo, relativo ao diretório atual.
+///
+/// # Returns
+/// `Ok(Vec<BlameLine>)` com uma entrada por linha, em ordem. Retorna `Err` com
+/// uma mensagem clara se o arquivo não existir, não estiver versionado ou
+/// for binário.
+pub fn blame(path: &str) -> Result<Vec<BlameLine>> {
+    if !resolve_path(path).exists() {
+        return Err(anyhow!("O arquivo '{}' não existe.", path));
+    }
+
+    let output = match run_git(&["blame", "--porcelain", "--", path]) {
+        Ok(output) => output,
+        Err(e) => {
+            if let Some(git_error) = e.downcast_ref::<GitCommandError>() {
+                if git_error.stderr.contains("no such path") {
+                    return Err(anyhow!(
+                        "O arquivo '{}' ainda não foi commitado, então não há histórico para exibir.",
+                        path
+                    ));
+                }
+            }
+            return Err(anyhow!("Falha ao executar o 'git blame': {}", e));
+        }
+    };
+
+    let lines = parse_porcelain(&output.stdout);
+    // O Git não recusa arquivos binários; detectamos o byte nulo, que não
+    // aparece em texto, para não despejar lixo no terminal.
+    if lines.iter().any(|l| l.content.contains('\0')) {
+        return Err(anyhow!("O arquivo '{}' é binário; o blame só é útil para arquivos de texto.", path));
+    }
+    Ok(lines)
+}
+
+/// Analisa a saída de `git blame --porcelain`.
+///
+/// Cada linha do arquivo é descrita por um cabeçalho
+/// `<hash> <linha original> <linha final> [<tamanho do grupo>]`, seguido (na
+/// primeira aparição do commit) pelos metadados, e pelo conteúdo prefixado
+/// por um TAB.
+fn parse_porcelain(output: &str) -> Vec<BlameLine> {
+    let mut authors: HashMap<String, String> = HashMap::new();
+    let mut lines = Vec::new();
+    let mut current: Option<(String, usize)> = None;
+
+    for line in output.lines() {
+        if let Some(content) = line.strip_prefix('\t') {
+            if let Some((commit, line_no)) = current.take() {
+                let author = authors.get(&commit).cloned().unwrap_or_default();
+                lines.push(BlameLine {
+                    commit,
+                    author,
+                    line_no,
+                    content: content.to_string(),
+                });
+            }
+        } else if let Some(author) = line.strip_prefix("author ") {
+            if let Some((commit, _)) = &current {
+                authors.insert(commit.clone(), author.to_string());
+            }
+        } else if let Some((commit, line_no)) = parse_header(line) {
+            current = Some((commit, line_no));
+        }
+    }
+
+    lines
+}
+
+/// Reconhece uma linha de cabeçalho, retornando o hash e o número da linha final.
+fn parse_header(line: &str) -> Option<(String, usize)> {
+    let mut parts = line.split(' ');
+    let commit = parts.next()?;
+    if commit.len() < 40 || !commit.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let _original_line = parts.next()?;
+    let final_line = parts.next()?.parse().ok()?;
+    Some((commit.to_string(), final_line))
+}
\ No newline at end of file
@@ -10,63 +10,140 @@
 // Este módulo implementa a funcionalidade para listar, criar e mudar de
 // branches em um repositório Git. A manipulação de branches é uma operação
 // central no fluxo de trabalho do Git.
+//
+// Para mudar de branch, preferimos o `git switch` (Git 2.23+), que faz apenas
+// isso, ao sobrecarregado `git checkout`, que também restaura arquivos. Em
+// versões antigas do Git, o `checkout` continua sendo usado.
 // ==============================================================================
 
-use anyhow::{anyhow, Context, Result};
-use std::process::Command;
+use super::{git_version_at_least, run_git, FIELD_SEPARATOR, GitCommandError, RESTORE_SWITCH_VERSION};
+use anyhow::{anyhow, Result};
+use std::fmt;
+
+/// Erro retornado quando alterações não commitadas impedem a mudança de branch.
+///
+/// É distinto para que a UI possa oferecer guardá-las no stash (veja
+/// `git_wrapper::stash`) e tentar novamente.
+#[derive(Debug)]
+pub struct UncommittedChangesError {
+    /// A branch para a qual o usuário tentou mudar.
+    pub branch: String,
+    /// Os arquivos cujas alterações seriam sobrescritas.
+    pub files: Vec<String>,
+}
+
+impl fmt::Display for UncommittedChangesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Suas alterações não commitadas seriam sobrescritas ao mudar para '{}'", self.branch)?;
+        if !self.files.is_empty() {
+            write!(f, " (em: {})", self.files.join(", "))?;
+        }
+        write!(
+            f,
+            ".\nGuarde-as no stash (git stash) ou faça um commit antes de mudar de branch."
+        )
+    }
+}
+
+impl std::error::Error for UncommittedChangesError {}
+
+/// Erro retornado quando `git branch -d` recusa excluir uma branch com
+/// commits que ainda não foram integrados.
+///
+/// É distinto para que a UI possa oferecer a exclusão forçada (`-D`).
+#[derive(Debug)]
+pub struct UnmergedBranchError {
+    /// A branch que o usuário tentou excluir.
+    pub branch: String,
+}
+
+impl fmt::Display for UnmergedBranchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "A branch '{}' tem commits que ainda não foram integrados (not fully merged).\n\
+             Se tiver certeza de que eles não são mais necessários, exclua-a com força (git branch -D).",
+            self.branch
+        )
+    }
+}
+
+impl std::error::Error for UnmergedBranchError {}
 
 /// Representa as informações sobre uma única branch.
 ///
 /// Em vez de retornar uma simples string, usamos uma struct para fornecer
 /// dados ricos e estruturados para a camada de UI, que pode então usar
-/// a flag `is_current` para destacar a branch ativa.
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// a flag `is_current` para destacar a branch ativa e as informações de
+/// rastreamento para mostrar a sincronia com o remoto.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct BranchInfo {
     pub name: String,
     pub is_current: bool,
+    /// A branch remota rastreada (ex: "origin/main"), se configurada.
+    pub upstream: Option<String>,
+    /// Commits locais ainda não enviados ao upstream.
+    pub ahead: u32,
+    /// Commits do upstream ainda não integrados localmente.
+    pub behind: u32,
+    /// `true` se o upstream configurado não existe mais no remoto (ex: a
+    /// branch foi apagada após o merge de um PR).
+    pub upstream_gone: bool,
 }
 
 /// Lista todas as branches locais no repositório.
 ///
-/// Executa `git branch` e analisa a saída para identificar a branch atual
-/// (marcada com um `*`) e os nomes de todas as outras branches.
+/// Obtém as mesmas informações de `git branch -vv` (branch atual, upstream e
+/// sincronia), mas com um `--format` explícito: a saída do `-vv` é feita para
+/// humanos e não distingue, por exemplo, um upstream de um assunto de commit
+/// que comece com colchetes.
+///
+/// Em detached HEAD, o Git inclui uma pseudo-entrada "(HEAD detached at ...)",
+/// que não é uma branch e é descartada.
 ///
 /// # Returns
 /// Um `Result` contendo um vetor de `BranchInfo`, ou um `Err` se o comando falhar.
 pub fn list_branches() -> Result<Vec<BranchInfo>> {
-    let output = Command::new("git")
-        .arg("branch")
-        .output()
-        .context("Falha ao executar o comando 'git branch'.")?;
+    let format = format!(
+        "--format=%(HEAD){sep}%(refname:short){sep}%(upstream:short){sep}%(upstream:track,nobracket)",
+        sep = FIELD_SEPARATOR
+    );
+    let output = run_git(&["branch", &format]).map_err(|e| anyhow!("Falha ao listar as branches: {}", e))?;
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(anyhow!("Falha ao listar as branches: {}", stderr.trim()));
-    }
+    Ok(output.stdout.lines().filter_map(parse_branch_line).collect())
+}
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let mut branches = Vec::new();
+/// Analisa uma linha da saída de `list_branches`.
+///
+/// # Returns
+/// `Some(BranchInfo)`, ou `None` para linhas vazias e para a pseudo-entrada
+/// de detached HEAD.
+fn parse_branch_line(line: &str) -> Option<BranchInfo> {
+    let mut fields = line.split(FIELD_SEPARATOR);
+    let head = fields.next()?;
+    let name = fields.next()?.trim();
+    if name.is_empty() || name.starts_with('(') {
+        return None;
+    }
+    let upstream = fields.next().map(str::trim).filter(|u| !u.is_empty());
+    // O rastreamento vem como "ahead 3, behind 1", "ahead 3", "gone" ou vazio.
+    let track = fields.next().unwrap_or_default().trim();
 
-    // Analisamos cada linha da saída do comando.
-    for line in stdout.lines() {
-        let trimmed_line = line.trim();
-        if trimmed_line.is_empty() {
-            continue;
+    let mut info = BranchInfo {
+        name: name.to_string(),
+        is_current: head == "*",
+        upstream: upstream.map(String::from),
+        upstream_gone: track == "gone",
+        ..Default::default()
+    };
+    for part in track.split(", ") {
+        if let Some(n) = part.strip_prefix("ahead ") {
+            info.ahead = n.parse().unwrap_or(0);
+        } else if let Some(n) = part.strip_prefix("behind ") {
+            info.behind = n.parse().unwrap_or(0);
         }
-
-        // O Git marca a branch atual com um asterisco.
-        let is_current = trimmed_line.starts_with('*');
-        // Removemos o prefixo `* ` para obter o nome limpo da branch.
-        let name = if is_current {
-            trimmed_line.strip_prefix("* ").unwrap_or(trimmed_line).to_string()
-        } else {
-            trimmed_line.to_string()
-        };
-
-        branches.push(BranchInfo { name, is_current });
     }
-
-    Ok(branches)
+    Some(info)
 }
 
 /// Cria uma nova branch local.
@@ -84,54 +161,279 @@ pub fn create_branch(name: &str) -> Result<()> {
         return Err(anyhow!("O nome da branch não pode ser vazio."));
     }
 
-    let output = Command::new("git")
-        .arg("branch")
-        .arg(trimmed_name)
-        .output()
-        .context("Falha ao executar o comando 'git branch' para criar a branch.")?;
+    run_git(&["branch", trimmed_name])
+        .map_err(|e| anyhow!("Falha ao criar a branch '{}': {}", trimmed_name, e))?;
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(anyhow!(
-            "Falha ao criar a branch '{}': {}",
-            trimmed_name,
-            stderr.trim()
-        ));
+    Ok(())
+}
+
+/// Cria uma nova branch local apontando para um commit específico.
+///
+/// Executa `git branch <name> <start_point>`. Útil para recuperar commits que
+/// só restam no reflog, sem mudar a branch atual.
+///
+/// # Arguments
+/// * `name` - O nome da nova branch a ser criada.
+/// * `start_point` - O commit (hash ou referência) onde a branch começa.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se a branch já existir, o nome for
+/// inválido ou o commit não existir.
+pub fn create_branch_from(name: &str, start_point: &str) -> Result<()> {
+    let trimmed_name = name.trim();
+    if trimmed_name.is_empty() {
+        return Err(anyhow!("O nome da branch não pode ser vazio."));
     }
 
+    run_git(&["branch", trimmed_name, start_point.trim()])
+        .map_err(|e| anyhow!("Falha ao criar a branch '{}': {}", trimmed_name, e))?;
+
     Ok(())
 }
 
 /// Muda para uma branch existente.
 ///
-/// Executa `git checkout <name>`.
+/// Executa `git switch <name>` (ou `git checkout <name>` em Git anterior à 2.23).
 ///
 /// # Arguments
 /// * `name` - O nome da branch para a qual mudar.
 ///
 /// # Returns
-/// `Ok(())` em caso de sucesso, ou `Err` se a branch não existir ou se houver
-/// alterações não commitadas que impediriam a mudança.
+/// `Ok(())` em caso de sucesso. Retorna um `UncommittedChangesError` se
+/// alterações não commitadas impedirem a mudança, ou outro `Err` se a branch
+/// não existir.
 pub fn switch_branch(name: &str) -> Result<()> {
     let trimmed_name = name.trim();
     if trimmed_name.is_empty() {
         return Err(anyhow!("O nome da branch não pode ser vazio."));
     }
 
-    let output = Command::new("git")
-        .arg("checkout")
-        .arg(trimmed_name)
-        .output()
-        .context("Falha ao executar o comando 'git checkout'.")?;
+    let command = if git_version_at_least(RESTORE_SWITCH_VERSION) { "switch" } else { "checkout" };
+    run_git(&[command, trimmed_name])
+        .map_err(|e| switch_error(e, trimmed_name))?;
+
+    Ok(())
+}
+
+/// Cria uma nova branch a partir de `HEAD` e muda para ela.
+///
+/// Executa `git switch -c <name>` (ou `git checkout -b <name>` em Git
+/// anterior à 2.23). Alterações não commitadas são levadas para a nova
+/// branch. É a forma de "salvar" commits feitos em detached HEAD.
+///
+/// # Arguments
+/// * `name` - O nome da nova branch.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se a branch já existir ou o nome for inválido.
+pub fn create_and_switch(name: &str) -> Result<()> {
+    let trimmed_name = name.trim();
+    if trimmed_name.is_empty() {
+        return Err(anyhow!("O nome da branch não pode ser vazio."));
+    }
+
+    let args = if git_version_at_least(RESTORE_SWITCH_VERSION) {
+        ["switch", "-c", trimmed_name]
+    } else {
+        ["checkout", "-b", trimmed_name]
+    };
+    run_git(&args).map_err(|e| anyhow!("Falha ao criar a branch '{}': {}", trimmed_name, e))?;
+
+    Ok(())
+}
+
+/// Converte a falha de um `switch`/`checkout` em um erro descritivo.
+///
+/// Quando o Git recusa a mudança por causa de alterações locais, ele lista os
+/// arquivos afetados, um por linha e indentados, entre o aviso e a sugestão
+/// "Please commit your changes or stash them".
+fn switch_error(error: anyhow::Error, branch: &str) -> anyhow::Error {
+    let Some(failure) = error.downcast_ref::<GitCommandError>() else {
+        return error;
+    };
+    if !failure.stderr.contains("would be overwritten by checkout") {
+        return anyhow!("Falha ao mudar para a branch '{}': {}", branch, failure);
+    }
+
+    let files = failure
+        .stderr
+        .lines()
+        .skip_while(|line| !line.contains("would be overwritten"))
+        .skip(1)
+        .take_while(|line| line.starts_with(char::is_whitespace))
+        .map(|line| line.trim().to_string())
+        .collect();
+    UncommittedChangesError {
+        branch: branch.to_string(),
+        files,
+    }
+    .into()
+}
+
+/// Obtém o nome da branch atual.
+///
+/// Executa `git symbolic-ref --short -q HEAD`. Quando `HEAD` aponta
+/// diretamente para um commit (detached HEAD, ex: após o checkout de uma tag),
+/// o comando termina com o código 1 sem mensagem.
+///
+/// # Returns
+/// `Ok(Some(String))` com o nome da branch, `Ok(None)` em detached HEAD, ou
+/// `Err` se o diretório não for um repositório Git.
+pub fn current_branch() -> Result<Option<String>> {
+    match run_git(&["symbolic-ref", "--short", "-q", "HEAD"]) {
+        Ok(output) => Ok(Some(output.stdout.trim().to_string())),
+        Err(e) => match e.downcast_ref::<GitCommandError>() {
+            Some(failure) if failure.code == Some(1) => Ok(None),
+            _ => Err(e),
+        },
+    }
+}
+
+/// Obtém o remoto para o qual uma branch envia seus commits.
+///
+/// Lê `branch.<name>.remote`, configurado ao definir o upstream; na falta
+/// dele, usa "origin", como o próprio Git.
+///
+/// # Arguments
+/// * `name` - O nome da branch local.
+pub fn tracking_remote(name: &str) -> String {
+    run_git(&["config", "--get", &format!("branch.{}.remote", name)])
+        .map(|o| o.stdout.trim().to_string())
+        .ok()
+        .filter(|remote| !remote.is_empty())
+        .unwrap_or_else(|| "origin".to_string())
+}
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
+/// Exclui uma branch local.
+///
+/// Executa `git branch -d <name>`, que recusa excluir branches com commits
+/// ainda não integrados, ou `git branch -D <name>` quando `force` é `true`.
+///
+/// # Arguments
+/// * `name` - O nome da branch a ser excluída.
+/// * `force` - Se `true`, exclui mesmo que a branch não tenha sido integrada.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso. Retorna um `UnmergedBranchError` se (sem
+/// `force`) a branch tiver commits não integrados, ou outro `Err` se ela não
+/// existir ou for a branch atual.
+pub fn delete_branch(name: &str, force: bool) -> Result<()> {
+    let trimmed_name = name.trim();
+    if trimmed_name.is_empty() {
+        return Err(anyhow!("O nome da branch não pode ser vazio."));
+    }
+    // Verificado antes de chamar o Git, cuja mensagem para este caso fala em
+    // "checked out" e não deixa claro o que fazer.
+    if current_branch()?.as_deref() == Some(trimmed_name) {
         return Err(anyhow!(
-            "Falha ao mudar para a branch '{}': {}",
-            trimmed_name,
-            stderr.trim()
+            "Não é possível excluir '{}': ela é a branch atual. Mude para outra branch antes de excluí-la.",
+            trimmed_name
         ));
     }
 
+    let flag = if force { "-D" } else { "-d" };
+    run_git(&["branch", flag, trimmed_name]).map_err(|e| {
+        let unmerged = e
+            .downcast_ref::<GitCommandError>()
+            .is_some_and(|git_error| git_error.stderr.contains("not fully merged"));
+        if unmerged {
+            UnmergedBranchError {
+                branch: trimmed_name.to_string(),
+            }
+            .into()
+        } else {
+            anyhow!("Falha ao excluir a branch '{}': {}", trimmed_name, e)
+        }
+    })?;
+
     Ok(())
+}
+
+/// Renomeia uma branch local.
+///
+/// Executa `git branch -m <old> <new>`. A configuração de rastreamento
+/// (upstream) acompanha a branch; a branch remota não é renomeada.
+///
+/// # Arguments
+/// * `old` - O nome atual da branch.
+/// * `new` - O novo nome.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se algum dos nomes for vazio, já
+/// existir uma branch com o novo nome ou o Git recusar a renomeação.
+pub fn rename_branch(old: &str, new: &str) -> Result<()> {
+    let old = old.trim();
+    if old.is_empty() {
+        return Err(anyhow!("O nome da branch a ser renomeada não pode ser vazio."));
+    }
+    let new = validate_new_name(new)?;
+
+    run_git(&["branch", "-m", old, new])
+        .map_err(|e| anyhow!("Falha ao renomear a branch '{}' para '{}': {}", old, new, e))?;
+
+    Ok(())
+}
+
+/// Renomeia a branch atual.
+///
+/// Executa `git branch -m <new>`. Funciona também em uma branch ainda sem
+/// commits.
+///
+/// # Arguments
+/// * `new` - O novo nome.
+///
+/// # Returns
+/// O mesmo que `rename_branch`.
+pub fn rename_current_branch(new: &str) -> Result<()> {
+    let new = validate_new_name(new)?;
+
+    run_git(&["branch", "-m", new])
+        .map_err(|e| anyhow!("Falha ao renomear a branch atual para '{}': {}", new, e))?;
+
+    Ok(())
+}
+
+/// Verifica o novo nome de uma renomeação antes de chamar o Git, para que o
+/// erro seja claro.
+fn validate_new_name(new: &str) -> Result<&str> {
+    let new = new.trim();
+    if new.is_empty() {
+        return Err(anyhow!("O novo nome da branch não pode ser vazio."));
+    }
+    if list_branches()?.iter().any(|b| b.name == new) {
+        return Err(anyhow!("Já existe uma branch chamada '{}'.", new));
+    }
+    Ok(new)
+}
+
+/// Lista as branches locais já integradas (mergeadas) em `base`.
+///
+/// Executa `git branch --merged <base>`. A branch atual e a própria base
+/// nunca são incluídas, pois não devem ser excluídas.
+///
+/// # Arguments
+/// * `base` - A branch (ou referência) de integração, tipicamente a branch
+///   padrão (ex: "main" ou "origin/main").
+///
+/// # Returns
+/// `Ok(Vec<String>)` com os nomes das branches, ou `Err` se a base não existir.
+pub fn merged_branches(base: &str) -> Result<Vec<String>> {
+    let output = run_git(&["branch", "--merged", base, "--format=%(refname:short)"])
+        .map_err(|e| anyhow!("Falha ao listar as branches integradas em '{}': {}", base, e))?;
+
+    let current = current_branch()?;
+    // A base pode ter sido informada como remota (ex: "origin/main"); a
+    // branch local de mesmo nome também é preservada.
+    let base_name = base.strip_prefix("origin/").unwrap_or(base);
+
+    Ok(output
+        .stdout
+        .lines()
+        .map(str::trim)
+        .filter(|name| !name.is_empty() && *name != base && *name != base_name)
+        .filter(|name| current.as_deref() != Some(*name))
+        // Em detached HEAD, o Git lista uma pseudo-entrada "(HEAD detached ...)".
+        .filter(|name| !name.starts_with('('))
+        .map(String::from)
+        .collect())
 }
\ No newline at end of file
@@ -0,0 +1,54 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo de Cherry-pick do Git
+//
+// Aplica as alterações de um commit específico (tipicamente de outra branch)
+// sobre a branch atual, criando um novo commit.
+// ==============================================================================
+
+use super::{conflicted_files, output_has_conflicts, run_git, ConflictError, GitCommandError};
+use anyhow::{anyhow, Result};
+
+/// Aplica um commit na branch atual.
+///
+/// Executa `git cherry-pick <commit>`. Em caso de conflito, o repositório é
+/// deixado no estado de cherry-pick em andamento, para que o usuário resolva
+/// os conflitos e continue (`git cherry-pick --continue`) ou aborte
+/// (`git cherry-pick --abort`).
+///
+/// # Arguments
+/// * `commit` - O hash (ou outra referência) do commit a aplicar.
+///
+/// # Returns
+/// `Ok(())` se o commit foi aplicado. Retorna um `ConflictError` se houver
+/// conflitos, ou outro `Err` se o cherry-pick falhar por outro motivo (ex:
+/// commit inexistente ou alterações locais que seriam sobrescritas).
+pub fn cherry_pick(commit: &str) -> Result<()> {
+    let commit = commit.trim();
+    if commit.is_empty() {
+        return Err(anyhow!("O commit a ser aplicado não pode ser vazio."));
+    }
+
+    let error = match run_git(&["cherry-pick", commit]) {
+        Ok(_) => return Ok(()),
+        Err(e) => e,
+    };
+
+    let is_conflict = error
+        .downcast_ref::<GitCommandError>()
+        .is_some_and(|failure| output_has_conflicts(&failure.combined_output()));
+    if is_conflict {
+        return Err(ConflictError {
+            operation: "cherry-pick",
+            files: conflicted_files().unwrap_or_default(),
+        }
+        .into());
+    }
+
+    Err(anyhow!("Falha ao aplicar o commit '{}': {}", commit, error))
+}
\ No newline at end of file
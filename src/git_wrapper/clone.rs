@@ -13,9 +13,12 @@
 // do processo `git clone` enquanto ele está em execução.
 // ==============================================================================
 
+use super::remote::{self, UrlScheme};
+use super::{git_command, stream_stderr_and_wait, LoggedCommand};
+use crate::config;
+use crate::ui::output;
 use anyhow::{anyhow, Context, Result};
-use std::io::{BufRead, BufReader};
-use std::process::{Command, Stdio};
+use std::process::Stdio;
 
 /// Clona um repositório a partir de uma URL.
 ///
@@ -36,52 +39,88 @@ pub fn clone_repository(url: &str) -> Result<()> {
     if trimmed_url.is_empty() {
         return Err(anyhow!("A URL do repositório não pode ser vazia."));
     }
+    config::ensure_online("o 'git clone'")?;
 
-    println!("Clonando de '{}'...", trimmed_url);
+    output::info(format!("Clonando de '{}'...", trimmed_url));
 
     // --- Configuração do Comando para Streaming ---
     // Em vez de usar `.output()`, que bloqueia até o fim, usamos `.spawn()`.
     // Para capturar a saída em tempo real, precisamos redirecionar o fluxo
     // de `stderr` para um "pipe", que podemos ler em nosso programa.
-    let mut child = Command::new("git")
+    let mut child = git_command()
         .arg("clone")
         .arg(trimmed_url)
         .stderr(Stdio::piped()) // Redireciona o stderr para que possamos lê-lo.
+        .logged()
         .spawn()
         .context("Falha ao iniciar o processo 'git clone'.")?;
 
     // --- Leitura em Tempo Real do Stderr ---
-    // `child.stderr.take()` nos dá um handle para o fluxo de erro do processo filho.
-    // Envolvemos este handle em um `BufReader` para ler a saída linha por linha
-    // de forma eficiente.
-    if let Some(stderr) = child.stderr.take() {
-        let reader = BufReader::new(stderr);
-        for line in reader.lines() {
-            match line {
-                // Imprimimos cada linha de progresso diretamente no console.
-                Ok(line_content) => println!("{}", line_content),
-                // Se houver um erro ao ler a linha (raro), o propagamos.
-                Err(e) => return Err(anyhow!(e).context("Falha ao ler a saída do git clone.")),
-            }
-        }
-    }
-
-    // --- Verificação do Status Final ---
-    // Após a leitura de toda a saída, esperamos o processo terminar para obter
-    // seu código de saída final.
-    let status = child
-        .wait()
-        .context("Falha ao aguardar o término do processo 'git clone'.")?;
+    // Cada linha de progresso é impressa diretamente no console assim que
+    // chega, e a saída é guardada para, em caso de falha, diagnosticar a causa.
+    // Se um tempo limite estiver configurado e for excedido, o processo é
+    // encerrado e um `TimeoutError` é retornado.
+    let (status, captured_output) = stream_stderr_and_wait(&mut child, "git clone")?;
 
     if !status.success() {
-        // Se o processo terminou com um código de erro, nós retornamos um erro.
-        // A mensagem de erro específica do Git já foi impressa na tela
-        // durante o loop de leitura, então um erro genérico aqui é suficiente.
+        // Falhas de autenticação são as mais confusas para novos usuários, então
+        // as reconhecemos e oferecemos orientação específica para o protocolo.
+        if let Some(hint) = auth_failure_hint(trimmed_url, &captured_output) {
+            return Err(anyhow!("O comando 'git clone' falhou por um erro de autenticação.\n\n{}", hint));
+        }
+
+        // Para os demais casos, a mensagem de erro específica do Git já foi
+        // impressa na tela durante o loop de leitura, então um erro genérico
+        // aqui é suficiente. No modo silencioso, nada foi impresso: a saída
+        // capturada acompanha o erro.
+        if output::is_quiet() {
+            return Err(anyhow!("O comando 'git clone' falhou:\n\n{}", captured_output.trim()));
+        }
         return Err(anyhow!(
             "O comando 'git clone' falhou. Verifique a saída acima para detalhes."
         ));
     }
 
-    println!("\nRepositório clonado com sucesso.");
+    output::blank_line();
+    output::info("Repositório clonado com sucesso.");
     Ok(())
+}
+
+/// Verifica se a saída de um clone indica uma falha de autenticação e, em caso
+/// afirmativo, retorna uma orientação adequada ao protocolo da URL.
+fn auth_failure_hint(url: &str, output: &str) -> Option<String> {
+    let lower = output.to_lowercase();
+    let is_auth_error = [
+        "authentication failed",
+        "could not read username",
+        "could not read password",
+        "terminal prompts disabled",
+        "invalid username or password",
+        "permission denied (publickey",
+        "host key verification failed",
+        "could not read from remote repository",
+    ]
+    .iter()
+    .any(|pattern| lower.contains(pattern));
+
+    if !is_auth_error {
+        return None;
+    }
+
+    let hint = match remote::url_scheme(url) {
+        UrlScheme::Https => {
+            "Para URLs HTTPS, o GitHub e o GitLab não aceitam mais a senha da conta.\n\
+             - Use um Token de Acesso Pessoal (PAT) no lugar da senha, de preferência\n\
+             \x20 armazenado por um credential helper (git config --global credential.helper).\n\
+             - Ou use a URL SSH do repositório (ex: git@github.com:owner/repo.git)."
+        }
+        UrlScheme::Ssh => {
+            "Para URLs SSH, verifique sua chave:\n\
+             - O ssh-agent está rodando e a chave foi adicionada? (ssh-add -l)\n\
+             - A chave pública está cadastrada na sua conta do servidor Git?\n\
+             - Teste a conexão com: ssh -T git@<host>"
+        }
+        UrlScheme::Other => "Verifique se você tem permissão de acesso ao repositório.",
+    };
+    Some(hint.to_string())
 }
\ No newline at end of file
@@ -11,8 +11,70 @@
 // commits no repositório local.
 // ==============================================================================
 
+use super::{git_command, git_version_at_least, resolve_path, run_git, run_verification, signing_failure_hint, GitCommandError, VerificationStatus, HOOK_RUN_VERSION};
+use super::LoggedCommand;
 use anyhow::{anyhow, Context, Result};
-use std::process::Command;
+use std::fmt;
+use std::fs;
+
+/// Opções que modificam o comportamento de `commit`.
+///
+/// Agrupar as flags em uma struct evita funções com vários parâmetros `bool`
+/// posicionais, que são fáceis de trocar por engano no ponto de chamada.
+/// O `Default` corresponde a um `git commit -m` simples.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CommitOptions {
+    /// Assina o commit com a chave GPG configurada no Git (`-S`).
+    pub sign: bool,
+    /// Ignora os hooks `pre-commit` e `commit-msg` (`--no-verify`).
+    pub no_verify: bool,
+    /// Adiciona o trailer `Signed-off-by` exigido pelo DCO (`-s`).
+    pub signoff: bool,
+    /// Permite criar um commit sem alterações (`--allow-empty`).
+    pub allow_empty: bool,
+}
+
+/// Erro retornado quando um hook do Git (`pre-commit` ou `commit-msg`) rejeita
+/// o commit.
+///
+/// Este tipo é distinto para que a camada de UI possa identificá-lo (via
+/// `anyhow::Error::downcast_ref`) e oferecer uma nova tentativa com `--no-verify`.
+#[derive(Debug)]
+pub struct HookRejectedError {
+    /// A saída produzida pelo hook e pelo Git.
+    pub details: String,
+}
+
+impl fmt::Display for HookRejectedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Um hook do Git rejeitou o commit: {}", self.details)
+    }
+}
+
+impl std::error::Error for HookRejectedError {}
+
+/// Erro retornado quando o commit falha porque a identidade do autor
+/// (`user.name`/`user.email`) não está configurada no Git.
+///
+/// Assim como `HookRejectedError`, é distinto para que a UI possa oferecer a
+/// configuração interativa (veja `git_wrapper::config::set_user_identity`).
+#[derive(Debug)]
+pub struct MissingIdentityError {
+    /// A saída produzida pelo Git.
+    pub details: String,
+}
+
+impl fmt::Display for MissingIdentityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "O Git não sabe quem você é: configure seu nome e email (user.name e user.email) antes de commitar.\n\n{}",
+            self.details
+        )
+    }
+}
+
+impl std::error::Error for MissingIdentityError {}
 
 /// Adiciona todas as alterações no diretório de trabalho ao stage do Git.
 ///
@@ -24,54 +86,124 @@ use std::process::Command;
 /// Um `Result<()>` que é `Ok` se o comando for bem-sucedido, ou `Err` se
 /// o comando `git add` falhar.
 pub fn add_all() -> Result<()> {
-    let output = Command::new("git")
-        .arg("add")
-        .arg(".") // O ponto representa "tudo no diretório atual e subdiretórios"
-        .output()
-        .context("Falha ao executar o comando 'git add'.")?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(anyhow!(
-            "O comando 'git add .' falhou: {}",
-            stderr.trim()
-        ));
+    // O ponto representa "tudo no diretório atual e subdiretórios".
+    run_git(&["add", "."]).map_err(|e| anyhow!("O comando 'git add .' falhou: {}", e))?;
+
+    Ok(())
+}
+
+/// Adiciona ao stage apenas as alterações em arquivos já rastreados.
+///
+/// Executa `git add -u`. Arquivos modificados e deletados são preparados,
+/// mas arquivos novos (não rastreados) são ignorados, o que preserva
+/// arquivos de rascunho que o usuário mantém fora do Git de propósito.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se o comando `git add -u` falhar.
+pub fn add_tracked() -> Result<()> {
+    run_git(&["add", "-u"]).map_err(|e| anyhow!("O comando 'git add -u' falhou: {}", e))?;
+
+    Ok(())
+}
+
+/// Adiciona apenas os caminhos fornecidos ao stage.
+///
+/// Executa `git add -- <caminhos>`. O `--` garante que caminhos que começam
+/// com `-` não sejam interpretados como opções.
+///
+/// # Arguments
+/// * `paths` - Os caminhos dos arquivos a serem adicionados.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se a lista estiver vazia ou o Git falhar.
+pub fn add_paths(paths: &[String]) -> Result<()> {
+    if paths.is_empty() {
+        return Err(anyhow!("Nenhum arquivo foi informado para adicionar ao stage."));
     }
 
+    let mut args = vec!["add", "--"];
+    args.extend(paths.iter().map(String::as_str));
+    run_git(&args).map_err(|e| anyhow!("O comando 'git add' falhou: {}", e))?;
+
+    Ok(())
+}
+
+/// Adiciona ao stage trechos (hunks) escolhidos interativamente.
+///
+/// Executa `git add -p` herdando o terminal, para que o usuário use o próprio
+/// seletor de trechos do Git (`y`, `n`, `s`, `e`, `q`, ...). A função só
+/// retorna quando o Git termina; sair com `q` não é um erro.
+///
+/// # Returns
+/// `Ok(())` quando o Git termina com sucesso, ou `Err` se ele não puder ser
+/// iniciado ou reportar uma falha.
+pub fn add_patch() -> Result<()> {
+    let status = git_command()
+        .args(["add", "-p"])
+        .logged()
+        .status()
+        .context("Falha ao executar o comando 'git add -p'. O Git está instalado e no PATH?")?;
+
+    if !status.success() {
+        return Err(anyhow!("O comando 'git add -p' terminou com falha ({}).", status));
+    }
     Ok(())
 }
 
 /// Cria um novo commit com a mensagem fornecida.
 ///
-/// Executa o comando `git commit -m "<mensagem>"`.
+/// Executa o comando `git commit -m "<mensagem>"`, adicionando `-S` quando o
+/// commit deve ser assinado com GPG, `-s` quando deve receber o trailer
+/// `Signed-off-by`, `--no-verify` quando os hooks devem ser ignorados e
+/// `--allow-empty` quando o commit pode não ter alterações.
 ///
 /// # Arguments
 /// * `message` - A mensagem de commit a ser usada.
+/// * `options` - As flags adicionais do commit (veja `CommitOptions`).
 ///
 /// # Returns
 /// Um `Result<()>` que é `Ok` se o commit for criado com sucesso. Retorna `Err`
 /// se o comando `git commit` falhar, por exemplo, se não houver nada no stage
-/// para commitar, ou se a configuração do Git (user.name, user.email)
-/// não estiver definida.
-pub fn commit(message: &str) -> Result<()> {
+/// para commitar, se a configuração do Git (user.name, user.email)
+/// não estiver definida, ou se a assinatura GPG falhar. Se a falha vier de um
+/// hook, o erro encapsula um `HookRejectedError`.
+pub fn commit(message: &str, options: CommitOptions) -> Result<()> {
     // Validação de entrada: uma mensagem de commit não pode ser vazia.
     if message.trim().is_empty() {
         return Err(anyhow!("A mensagem de commit não pode ser vazia."));
     }
 
-    let output = Command::new("git")
-        .arg("commit")
-        .arg("-m")
-        .arg(message)
-        .output()
-        .context("Falha ao executar o comando 'git commit'.")?;
-
-    if !output.status.success() {
-        // Captura tanto stdout quanto stderr, pois `git commit` pode escrever
+    if let Err(e) = run_git(&commit_args(message, options)) {
+        let failure = match e.downcast_ref::<GitCommandError>() {
+            Some(failure) => failure,
+            None => return Err(e),
+        };
+        // Usa tanto stdout quanto stderr, pois `git commit` pode escrever
         // mensagens informativas de erro em ambos os canais.
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        let error_message = format!("{}\n{}", stdout.trim(), stderr.trim()).trim().to_string();
+        let (stdout, stderr) = (&failure.stdout, &failure.stderr);
+        let error_message = failure.combined_output();
+
+        // Falhas de assinatura são comuns e a mensagem do Git é pouco clara,
+        // então acrescentamos uma dica específica quando as detectamos.
+        if let Some(hint) = signing_failure_hint(stderr) {
+            return Err(anyhow!(
+                "O comando 'git commit' falhou: {}\n\n{}",
+                error_message,
+                hint
+            ));
+        }
+
+        // "Please tell me who you are": a identidade do autor não está
+        // configurada (ou não pôde ser detectada automaticamente).
+        if stderr.contains("Please tell me who you are") || stderr.contains("unable to auto-detect email address") {
+            return Err(MissingIdentityError { details: error_message }.into());
+        }
+
+        // Se os hooks foram executados e o commit falhou por um motivo que não
+        // é "nada para commitar", confirma se foi mesmo um hook que o rejeitou.
+        if !options.no_verify && !stdout.contains("nothing to commit") && rejected_by_hook(message, stderr)? {
+            return Err(HookRejectedError { details: error_message }.into());
+        }
 
         return Err(anyhow!(
             "O comando 'git commit' falhou: {}",
@@ -80,4 +212,290 @@ pub fn commit(message: &str) -> Result<()> {
     }
 
     Ok(())
+}
+
+/// Monta os argumentos do `git commit` executado por `commit`.
+///
+/// Exposta para que o modo plano (veja `ui::plan`) mostre exatamente o
+/// comando que seria executado.
+pub fn commit_args(message: &str, options: CommitOptions) -> Vec<&str> {
+    let mut args = vec!["commit", "-m", message];
+    if options.sign {
+        args.push("-S");
+    }
+    if options.signoff {
+        args.push("-s");
+    }
+    if options.no_verify {
+        args.push("--no-verify");
+    }
+    if options.allow_empty {
+        args.push("--allow-empty");
+    }
+    args
+}
+
+/// Cria um commit de correção ("fixup") para um commit anterior.
+///
+/// Executa `git commit --fixup=<target_sha>` (com `-S` quando o commit deve
+/// ser assinado) com o que estiver no stage. O
+/// commit criado tem a mensagem `fixup! <assunto do alvo>` e NÃO altera o
+/// alvo imediatamente: a correção só é incorporada a ele no próximo rebase
+/// com `--autosquash` (veja `rebase::autosquash`).
+///
+/// # Arguments
+/// * `target_sha` - O hash (ou outra referência) do commit a ser corrigido.
+/// * `sign` - Se `true`, assina o commit de fixup com GPG.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se não houver nada no stage, se o
+/// alvo não existir ou se o Git falhar.
+pub fn commit_fixup(target_sha: &str, sign: bool) -> Result<()> {
+    let target_sha = target_sha.trim();
+    if target_sha.is_empty() {
+        return Err(anyhow!("O commit a ser corrigido não pode ser vazio."));
+    }
+
+    let fixup = format!("--fixup={}", target_sha);
+    let mut args = vec!["commit", fixup.as_str()];
+    if sign {
+        args.push("-S");
+    }
+    if let Err(e) = run_git(&args) {
+        if let Some(failure) = e.downcast_ref::<GitCommandError>() {
+            if failure.stdout.contains("nothing to commit") || failure.stdout.contains("no changes added") {
+                return Err(anyhow!(
+                    "Não há alterações no stage. Adicione as correções ao stage antes de criar o commit de fixup."
+                ));
+            }
+            if let Some(hint) = signing_failure_hint(&failure.stderr) {
+                return Err(anyhow!("O commit de fixup falhou: {}\n\n{}", failure.combined_output(), hint));
+            }
+        }
+        return Err(anyhow!("O commit de fixup falhou: {}", e));
+    }
+
+    Ok(())
+}
+
+/// Retorna o trailer `Signed-off-by` que o Git adicionaria com `git commit -s`.
+///
+/// O Git usa a identidade do *committer* para o trailer. Obtemo-la com
+/// `git var GIT_COMMITTER_IDENT`, cuja saída tem o formato
+/// `Nome <email> <timestamp> <fuso>`, e descartamos a data.
+///
+/// # Returns
+/// `Ok(String)` com a linha completa (ex: `Signed-off-by: Fulano <fulano@ex.com>`),
+/// ou `Err` se a identidade (user.name/user.email) não estiver configurada.
+pub fn signoff_trailer() -> Result<String> {
+    let output = run_git(&["var", "GIT_COMMITTER_IDENT"])
+        .map_err(|e| anyhow!("Não foi possível determinar sua identidade no Git: {}", e))?;
+
+    let ident = output.stdout.trim().to_string();
+    // A identidade termina no `>` do email; o restante é a data do commit.
+    let identity = match ident.rfind('>') {
+        Some(end) => &ident[..=end],
+        None => ident.as_str(),
+    };
+
+    Ok(format!("Signed-off-by: {}", identity))
+}
+
+/// Determina se a falha de um `git commit` foi causada por um hook.
+///
+/// O Git não emite uma mensagem padronizada quando um hook falha: ele apenas
+/// repassa a saída do próprio hook. A existência de um hook também não prova
+/// nada, já que o commit pode ter falhado por outro motivo depois de o hook
+/// aprová-lo. Por isso, executamos novamente os hooks `pre-commit` e
+/// `commit-msg` com `git hook run` e só consideramos que houve rejeição se um
+/// deles falhar. Em versões do Git sem `git hook run`, recorremos à saída do
+/// commit, que precisa mencionar um hook.
+///
+/// # Arguments
+/// * `message` - A mensagem do commit que falhou, repassada ao `commit-msg`.
+/// * `stderr` - A saída de erro do `git commit`.
+///
+/// # Returns
+/// `Ok(true)` se um hook rejeitou o commit, `Ok(false)` caso contrário, ou
+/// `Err` se o Git não puder ser executado.
+fn rejected_by_hook(message: &str, stderr: &str) -> Result<bool> {
+    if !git_version_at_least(HOOK_RUN_VERSION) {
+        return Ok(stderr.to_lowercase().contains("hook"));
+    }
+
+    if hook_fails(&["hook", "run", "--ignore-missing", "pre-commit"])? {
+        return Ok(true);
+    }
+
+    // Assim como o `git commit`, entrega a mensagem ao `commit-msg` por meio
+    // do arquivo `COMMIT_EDITMSG`.
+    let output = run_git(&["rev-parse", "--git-path", "COMMIT_EDITMSG"])?;
+    let message_path = resolve_path(output.stdout.trim());
+    fs::write(&message_path, format!("{}\n", message))
+        .with_context(|| format!("Falha ao escrever '{}'", message_path.display()))?;
+
+    let message_arg = message_path.to_string_lossy();
+    hook_fails(&["hook", "run", "--ignore-missing", "commit-msg", "--", &message_arg])
+}
+
+/// Executa um hook com `git hook run` e informa se ele terminou com erro.
+fn hook_fails(args: &[&str]) -> Result<bool> {
+    match run_git(args) {
+        Ok(_) => Ok(false),
+        Err(e) if e.is::<GitCommandError>() => Ok(true),
+        Err(e) => Err(e),
+    }
+}
+
+/// Corrige (reescreve) o último commit.
+///
+/// Executa `git commit --amend -m "<mensagem>"` quando uma nova mensagem é
+/// fornecida, ou `git commit --amend --no-edit` para manter a mensagem atual,
+/// adicionando `-S` quando o commit corrigido deve ser assinado. Em ambos os
+/// casos, qualquer alteração que esteja no stage é incorporada ao commit
+/// corrigido.
+///
+/// O amend cria um commit novo: sem o `-S`, corrigir um commit assinado
+/// produziria um commit sem assinatura.
+///
+/// ATENÇÃO: o amend cria um novo commit com um novo hash. Se o commit original
+/// já foi enviado ao remoto, o histórico local e o remoto vão divergir.
+///
+/// # Arguments
+/// * `new_message` - A nova mensagem, ou `None` para manter a mensagem atual.
+/// * `sign` - Se `true`, assina o commit corrigido com GPG (veja
+///   `Config::sign_commits`).
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se a mensagem for vazia, se não
+/// houver um commit anterior, ou se o comando `git commit` falhar.
+pub fn amend(new_message: Option<&str>, sign: bool) -> Result<()> {
+    let mut args = vec!["commit", "--amend"];
+    if sign {
+        args.push("-S");
+    }
+
+    match new_message {
+        Some(message) => {
+            if message.trim().is_empty() {
+                return Err(anyhow!("A mensagem de commit não pode ser vazia."));
+            }
+            args.extend(["-m", message]);
+        }
+        None => {
+            args.push("--no-edit");
+        }
+    }
+
+    if let Err(e) = run_git(&args) {
+        let failure = match e.downcast_ref::<GitCommandError>() {
+            Some(failure) => failure,
+            None => return Err(e),
+        };
+        let error_message = failure.combined_output();
+
+        if let Some(hint) = signing_failure_hint(&failure.stderr) {
+            return Err(anyhow!(
+                "O comando 'git commit --amend' falhou: {}\n\n{}",
+                error_message,
+                hint
+            ));
+        }
+
+        return Err(anyhow!(
+            "O comando 'git commit --amend' falhou: {}",
+            error_message
+        ));
+    }
+
+    Ok(())
+}
+
+/// Altera apenas a mensagem do último commit.
+///
+/// Executa `git commit --amend --only --allow-empty -m "<mensagem>"`, com
+/// `-S` quando o commit deve ser assinado. O `--only` sem caminhos faz o Git
+/// ignorar o stage: alterações que estejam nele continuam lá, fora do commit.
+/// O `--allow-empty` permite reformular um commit que já era vazio.
+///
+/// ATENÇÃO: assim como no `amend`, o commit recebe um novo hash.
+///
+/// # Arguments
+/// * `new_message` - A nova mensagem do commit.
+/// * `sign` - Se `true`, assina o commit reformulado com GPG.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se a mensagem for vazia, se não
+/// houver um commit anterior, ou se o comando `git commit` falhar.
+pub fn reword_last(new_message: &str, sign: bool) -> Result<()> {
+    if new_message.trim().is_empty() {
+        return Err(anyhow!("A mensagem de commit não pode ser vazia."));
+    }
+
+    let mut args = vec!["commit", "--amend", "--only", "--allow-empty", "-m", new_message];
+    if sign {
+        args.push("-S");
+    }
+    run_git(&args).map_err(|e| match e.downcast_ref::<GitCommandError>() {
+        Some(failure) => match signing_failure_hint(&failure.stderr) {
+            Some(hint) => anyhow!(
+                "O comando 'git commit --amend' falhou: {}\n\n{}",
+                failure.combined_output(),
+                hint
+            ),
+            None => anyhow!("O comando 'git commit --amend' falhou: {}", failure.combined_output()),
+        },
+        None => e,
+    })?;
+
+    Ok(())
+}
+
+/// Incorpora o que está no stage ao último commit, mantendo sua mensagem.
+///
+/// Atalho para `amend(None, sign)`, que executa `git commit --amend --no-edit`.
+/// É a correção típica para um arquivo esquecido no último commit.
+///
+/// # Arguments
+/// * `sign` - Se `true`, assina o commit corrigido com GPG.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se não houver um commit anterior ou
+/// o comando `git commit` falhar.
+pub fn amend_no_edit(sign: bool) -> Result<()> {
+    amend(None, sign)
+}
+
+/// Verifica se o último commit (`HEAD`) já está presente em alguma branch remota.
+///
+/// Executa `git branch -r --contains HEAD`. Se a saída listar ao menos uma
+/// branch remota, o commit já foi enviado e reescrevê-lo exigirá um push forçado.
+///
+/// # Returns
+/// `Ok(true)` se o commit já foi enviado, `Ok(false)` caso contrário (incluindo
+/// repositórios sem remoto), ou `Err` se o comando não puder ser executado.
+pub fn last_commit_is_pushed() -> Result<bool> {
+    let output = match run_git(&["branch", "-r", "--contains", "HEAD"]) {
+        Ok(output) => output,
+        // Sem commits (HEAD inválido) não há nada que possa ter sido enviado.
+        Err(e) if e.is::<GitCommandError>() => return Ok(false),
+        Err(e) => return Err(e),
+    };
+
+    Ok(output.stdout.lines().any(|line| !line.trim().is_empty()))
+}
+
+/// Verifica a assinatura de um commit.
+///
+/// Executa `git verify-commit --raw <commit>`. Um commit sem assinatura
+/// resulta em `VerificationStatus::Unsigned`, não em erro.
+///
+/// # Arguments
+/// * `commit` - O hash ou a referência do commit (ex: "HEAD").
+///
+/// # Returns
+/// `Ok(VerificationStatus)`, ou `Err` se o commit não existir.
+pub fn verify_commit(commit: &str) -> Result<VerificationStatus> {
+    run_verification(&["verify-commit", "--raw", commit.trim()])
+        .map_err(|e| anyhow!("Falha ao verificar o commit '{}': {}", commit.trim(), e))
 }
\ No newline at end of file
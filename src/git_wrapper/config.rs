@@ -0,0 +1,103 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo de Configuração do Git
+//
+// Lê e grava valores da configuração do próprio Git (`git config`), como a
+// identidade usada nos commits. Não confundir com `crate::config`, que
+// gerencia a configuração do `gitph`.
+//
+// Usuários de primeira viagem costumam esbarrar no "Please tell me who you
+// are" do `git commit`; este módulo permite que a UI os guie na configuração.
+// ==============================================================================
+
+use super::{resolve_path, run_git, GitCommandError};
+use anyhow::{anyhow, Result};
+use std::fs;
+
+/// Lê a identidade configurada para os commits.
+///
+/// Executa `git config --get user.name` e `git config --get user.email`, que
+/// consideram todas as camadas (repositório, global e sistema).
+///
+/// # Returns
+/// `Ok((nome, email))`, com `None` em cada campo não configurado, ou `Err`
+/// se o Git falhar por outro motivo.
+pub fn get_user_identity() -> Result<(Option<String>, Option<String>)> {
+    Ok((get_value("user.name")?, get_value("user.email")?))
+}
+
+/// Grava a identidade usada nos commits.
+///
+/// Executa `git config [--global] user.name <name>` e o equivalente para
+/// `user.email`.
+///
+/// # Arguments
+/// * `name` - O nome do autor (ex: "Fulano de Tal").
+/// * `email` - O email do autor.
+/// * `global` - Se `true`, grava na configuração global do usuário (vale para
+///   todos os repositórios); caso contrário, apenas no repositório atual.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se algum valor for vazio ou inválido
+/// ou se o Git não conseguir gravar a configuração.
+pub fn set_user_identity(name: &str, email: &str, global: bool) -> Result<()> {
+    let (name, email) = (name.trim(), email.trim());
+    if name.is_empty() || email.is_empty() {
+        return Err(anyhow!("O nome e o email não podem ser vazios."));
+    }
+    if !email.contains('@') || email.contains(char::is_whitespace) {
+        return Err(anyhow!("'{}' não parece um endereço de email válido.", email));
+    }
+
+    let scope = if global { "--global" } else { "--local" };
+    for (key, value) in [("user.name", name), ("user.email", email)] {
+        run_git(&["config", scope, key, value])
+            .map_err(|e| anyhow!("Falha ao gravar '{}' na configuração do Git: {}", key, e))?;
+    }
+    Ok(())
+}
+
+/// Lê o template de mensagem de commit configurado em `commit.template`.
+///
+/// O caminho é lido com `git config --get --path`, que expande `~/` como o
+/// próprio Git faz; caminhos relativos são resolvidos a partir do diretório
+/// de trabalho.
+///
+/// # Returns
+/// `Ok(Some(String))` com o conteúdo do template, `Ok(None)` se nenhum estiver
+/// configurado, ou `Err` se o arquivo configurado não puder ser lido.
+pub fn commit_template() -> Result<Option<String>> {
+    let Some(path) = get_value_with(&["--path"], "commit.template")? else {
+        return Ok(None);
+    };
+    let content = fs::read_to_string(resolve_path(&path))
+        .map_err(|e| anyhow!("Não foi possível ler o template de commit '{}': {}", path, e))?;
+    Ok(Some(content))
+}
+
+/// Lê um único valor da configuração do Git.
+fn get_value(key: &str) -> Result<Option<String>> {
+    get_value_with(&[], key)
+}
+
+/// Lê um único valor da configuração do Git, com opções extras (ex: `--path`).
+///
+/// O `git config --get` termina com o código 1, sem mensagem, quando a chave
+/// não existe; isso é tratado como `None`, não como erro.
+fn get_value_with(options: &[&str], key: &str) -> Result<Option<String>> {
+    let mut args = vec!["config", "--get"];
+    args.extend_from_slice(options);
+    args.push(key);
+    match run_git(&args) {
+        Ok(output) => Ok(Some(output.stdout.trim().to_string()).filter(|v| !v.is_empty())),
+        Err(e) => match e.downcast_ref::<GitCommandError>() {
+            Some(failure) if failure.code == Some(1) => Ok(None),
+            _ => Err(e),
+        },
+    }
+}
\ No newline at end of file
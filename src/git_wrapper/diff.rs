@@ -0,0 +1,40 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo de Diff do Git
+//
+// Este módulo obtém as diferenças entre o diretório de trabalho, o stage
+// (index) e o último commit, no formato unificado do `git diff`.
+// ==============================================================================
+
+use super::run_git;
+use anyhow::{anyhow, Result};
+
+/// Obtém o diff das alterações pendentes.
+///
+/// Executa `git diff` (alterações ainda não adicionadas ao stage) ou
+/// `git diff --cached` (alterações no stage, prontas para o commit).
+///
+/// # Arguments
+/// * `staged` - Se `true`, retorna o diff do stage; caso contrário, o do
+///   diretório de trabalho.
+///
+/// # Returns
+/// `Ok(String)` com o diff no formato unificado (vazio se não houver
+/// alterações), ou `Err` se o comando falhar.
+pub fn diff(staged: bool) -> Result<String> {
+    // `--no-color` garante que nenhum código ANSI contamine a saída, mesmo que
+    // o usuário tenha `color.diff=always` configurado.
+    let mut args = vec!["diff", "--no-color"];
+    if staged {
+        args.push("--cached");
+    }
+
+    let output = run_git(&args).map_err(|e| anyhow!("O comando 'git diff' falhou: {}", e))?;
+
+    Ok(output.stdout)
+}
\ No newline at end of file
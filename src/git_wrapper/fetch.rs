@@ -0,0 +1,266 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo de Fetch do Git
+//
+// Este módulo atualiza as referências remotas (`origin/main`, etc.) sem
+// integrar nada à branch local, ao contrário do `git pull`. Depois de um
+// fetch, as contagens de "ahead/behind" exibidas pelo status ficam precisas.
+//
+// Assim como no push, o progresso do Git aparece em um spinner enquanto o
+// processo executa, e o resumo final (as referências criadas, atualizadas ou
+// removidas) é retornado para a UI.
+// ==============================================================================
+
+use super::push::{is_progress_line, strip_progress};
+use super::{git_command, stream_stderr_with, LoggedCommand};
+use crate::config;
+use crate::ui::output;
+use anyhow::{anyhow, Context, Result};
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+use std::process::Stdio;
+use std::time::Duration;
+
+/// Busca as atualizações de um único repositório remoto.
+///
+/// Executa `git fetch [--prune] <remote>`.
+///
+/// # Arguments
+/// * `remote` - O nome do remoto (ex: "origin").
+/// * `prune` - Se `true`, remove as referências locais de branches que foram
+///   apagadas no remoto.
+///
+/// # Returns
+/// `Ok(String)` com o resumo do Git, sem as linhas de progresso (veja
+/// `parse_fetch_summary`), ou `Err` se o nome for vazio ou o fetch falhar.
+pub fn fetch(remote: &str, prune: bool) -> Result<String> {
+    let trimmed_remote = remote.trim();
+    if trimmed_remote.is_empty() {
+        return Err(anyhow!("O nome do remoto não pode ser vazio."));
+    }
+    config::ensure_online("o 'git fetch'")?;
+
+    run_fetch(&[trimmed_remote], prune, &format!("Buscando atualizações de '{}'...", trimmed_remote))
+}
+
+/// Busca as atualizações de todos os remotos configurados.
+///
+/// Executa `git fetch --all [--prune]`.
+///
+/// # Arguments
+/// * `prune` - Se `true`, remove as referências de branches apagadas nos remotos.
+///
+/// # Returns
+/// O mesmo que `fetch`.
+pub fn fetch_all(prune: bool) -> Result<String> {
+    config::ensure_online("o 'git fetch'")?;
+    run_fetch(&["--all"], prune, "Buscando atualizações de todos os remotos...")
+}
+
+/// Executa o `git fetch` com os argumentos fornecidos, exibindo o progresso
+/// em um spinner.
+fn run_fetch(args: &[&str], prune: bool, message: &str) -> Result<String> {
+    let spinner = ProgressBar::new_spinner();
+    spinner.set_style(
+        ProgressStyle::default_spinner()
+            .template("{spinner:.green} {msg}")
+            .unwrap(),
+    );
+    spinner.set_message(message.to_string());
+    if output::is_quiet() {
+        spinner.set_draw_target(ProgressDrawTarget::hidden());
+    }
+    crate::interrupt::track_progress(&spinner);
+    spinner.enable_steady_tick(Duration::from_millis(100));
+
+    // `--progress` força o Git a emitir o progresso mesmo com o stderr
+    // redirecionado para um pipe (por padrão, ele só o faz em terminais).
+    let mut command = git_command();
+    command.arg("fetch").arg("--progress");
+    if prune {
+        command.arg("--prune");
+    }
+    let mut child = match command.args(args).stderr(Stdio::piped()).logged().spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            spinner.finish_and_clear();
+            return Err(e).context("Falha ao iniciar o processo 'git fetch'.");
+        }
+    };
+
+    let progress_spinner = spinner.clone();
+    let result = stream_stderr_with(&mut child, "git fetch", move |line| {
+        if let Some(progress) = line.rsplit('\r').map(str::trim).find(|s| is_progress_line(s)) {
+            progress_spinner.set_message(progress.to_string());
+        }
+    });
+    spinner.finish_and_clear();
+
+    let (status, captured) = result?;
+    let summary = strip_progress(&captured);
+    if status.success() {
+        Ok(summary)
+    } else {
+        Err(anyhow!("O comando 'git fetch' falhou:\n\n{}", summary))
+    }
+}
+
+/// O que um `git fetch` fez com uma referência.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FetchedRefKind {
+    /// Uma branch ou tag que não existia localmente (`*`).
+    New,
+    /// Uma atualização normal, sem reescrita de histórico (` ` ou `t`).
+    Updated,
+    /// Uma atualização forçada: o histórico remoto foi reescrito (`+`).
+    Forced,
+    /// Uma referência removida por `--prune` (`-`).
+    Pruned,
+    /// Uma atualização recusada pelo Git (`!`).
+    Rejected,
+}
+
+/// Uma referência alterada por um `git fetch`, extraída do resumo do Git.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchedRef {
+    /// O que aconteceu com a referência.
+    pub kind: FetchedRefKind,
+    /// O intervalo de commits (ex: "abc1234..def5678") ou a descrição do Git
+    /// entre colchetes (ex: "[new branch]", "[deleted]").
+    pub summary: String,
+    /// A referência local afetada (ex: "origin/main").
+    pub reference: String,
+}
+
+/// Extrai as referências alteradas do resumo retornado por `fetch`.
+///
+/// O Git descreve cada referência em uma linha como:
+/// ```text
+///    abc1234..def5678  main       -> origin/main
+///  + abc1234...def5678 rebased    -> origin/rebased  (forced update)
+///  * [new branch]      feature    -> origin/feature
+///  - [deleted]         (none)     -> origin/removida
+/// ```
+/// Linhas em outros formatos (ex: "From github.com:owner/repo") são ignoradas.
+///
+/// # Arguments
+/// * `output` - O resumo retornado por `fetch` ou `fetch_all`.
+///
+/// # Returns
+/// As referências reconhecidas. Um vetor vazio indica que nada mudou.
+pub fn parse_fetch_summary(output: &str) -> Vec<FetchedRef> {
+    output.lines().filter_map(parse_fetch_line).collect()
+}
+
+/// Analisa uma única linha do resumo do `git fetch`.
+fn parse_fetch_line(line: &str) -> Option<FetchedRef> {
+    // O formato é " <flag> <resumo> <origem> -> <destino> [(<motivo>)]".
+    let mut chars = line.strip_prefix(' ')?.chars();
+    let kind = match chars.next()? {
+        '*' => FetchedRefKind::New,
+        ' ' | 't' => FetchedRefKind::Updated,
+        '+' => FetchedRefKind::Forced,
+        '-' => FetchedRefKind::Pruned,
+        '!' => FetchedRefKind::Rejected,
+        _ => return None,
+    };
+    let (left, right) = chars.as_str().split_once(" -> ")?;
+    let reference = right.split_whitespace().next()?.to_string();
+    let (summary, _source) = left.trim().rsplit_once(char::is_whitespace)?;
+
+    Some(FetchedRef {
+        kind,
+        summary: summary.trim().to_string(),
+        reference,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Analisa uma única linha e falha o teste se ela não for reconhecida.
+    fn parse_single(line: &str) -> FetchedRef {
+        parse_fetch_line(line).unwrap_or_else(|| panic!("linha não reconhecida: {:?}", line))
+    }
+
+    #[test]
+    fn parses_new_branch() {
+        let fetched = parse_single(" * [new branch]      nova       -> origin/nova");
+        assert_eq!(fetched.kind, FetchedRefKind::New);
+        assert_eq!(fetched.summary, "[new branch]");
+        assert_eq!(fetched.reference, "origin/nova");
+    }
+
+    #[test]
+    fn parses_fast_forward_update() {
+        let fetched = parse_single("   4024485..cccfb7e  main       -> origin/main");
+        assert_eq!(fetched.kind, FetchedRefKind::Updated);
+        assert_eq!(fetched.summary, "4024485..cccfb7e");
+        assert_eq!(fetched.reference, "origin/main");
+    }
+
+    #[test]
+    fn parses_tag_update_as_updated() {
+        let fetched = parse_single(" t [tag update]      v1.0       -> v1.0");
+        assert_eq!(fetched.kind, FetchedRefKind::Updated);
+        assert_eq!(fetched.summary, "[tag update]");
+        assert_eq!(fetched.reference, "v1.0");
+    }
+
+    #[test]
+    fn parses_forced_update_and_ignores_the_reason() {
+        let fetched = parse_single(" + a1b2c3d...e4f5a6b feat       -> origin/feat  (forced update)");
+        assert_eq!(fetched.kind, FetchedRefKind::Forced);
+        assert_eq!(fetched.summary, "a1b2c3d...e4f5a6b");
+        assert_eq!(fetched.reference, "origin/feat");
+    }
+
+    #[test]
+    fn parses_pruned_branch() {
+        let fetched = parse_single(" - [deleted]         (none)     -> origin/antiga");
+        assert_eq!(fetched.kind, FetchedRefKind::Pruned);
+        assert_eq!(fetched.summary, "[deleted]");
+        assert_eq!(fetched.reference, "origin/antiga");
+    }
+
+    #[test]
+    fn parses_rejected_update() {
+        let fetched = parse_single(" ! [rejected]        v1         -> v1  (would clobber existing tag)");
+        assert_eq!(fetched.kind, FetchedRefKind::Rejected);
+        assert_eq!(fetched.summary, "[rejected]");
+        assert_eq!(fetched.reference, "v1");
+    }
+
+    #[test]
+    fn summary_skips_headers_and_unknown_lines() {
+        let output = "\
+From github.com:owner/repo
+ - [deleted]         (none)     -> origin/antiga
+ - [deleted]         (none)     -> origin/outra
+   4024485..cccfb7e  main       -> origin/main
+ * [new tag]         v2.0       -> v2.0
+ = [up to date]      estavel    -> origin/estavel";
+        let refs = parse_fetch_summary(output);
+        let kinds: Vec<FetchedRefKind> = refs.iter().map(|r| r.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                FetchedRefKind::Pruned,
+                FetchedRefKind::Pruned,
+                FetchedRefKind::Updated,
+                FetchedRefKind::New,
+            ]
+        );
+        assert_eq!(refs[1].reference, "origin/outra");
+    }
+
+    #[test]
+    fn empty_summary_has_no_refs() {
+        assert!(parse_fetch_summary("").is_empty());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,136 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo de Instalação de Hooks do Git
+//
+// Instala no repositório um hook `commit-msg` que valida o formato Conventional
+// Commits (`tipo(escopo): assunto`), o mesmo que o prompt guiado do `gitph`
+// produz. Assim o time padroniza as mensagens sem que cada pessoa precise
+// configurar o hook manualmente — inclusive quem commita fora do `gitph`.
+//
+// O hook é um script `sh` simples, que o Git executa também no Windows (pelo
+// shell que acompanha o Git for Windows).
+// ==============================================================================
+
+use super::{resolve_path, run_git};
+use anyhow::{anyhow, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Marca que identifica os hooks instalados pelo `gitph`. Um hook com essa
+/// marca é simplesmente substituído, sem backup.
+const HOOK_MARKER: &str = "# Instalado por `gitph install-hooks`.";
+
+/// O script do hook `commit-msg`.
+///
+/// Mensagens geradas pelo próprio Git (merge, revert, fixup/squash do rebase)
+/// são aceitas como estão. O limite de 72 caracteres do cabeçalho é o mesmo
+/// do prompt guiado. O `${#var}` do `sh` conta bytes em shells como o `dash`,
+/// então o tamanho é medido descartando os bytes de continuação do UTF-8
+/// (`\200` a `\277`) e contando os que restam, um por caractere.
+const COMMIT_MSG_HOOK: &str = r#"#!/bin/sh
+# Instalado por `gitph install-hooks`.
+# Valida se a mensagem de commit segue o padrão Conventional Commits.
+
+header=$(grep -v '^#' "$1" | sed -n '/[^[:space:]]/{p;q;}')
+
+case "$header" in
+    "Merge "* | "Revert "* | "fixup! "* | "squash! "* | "amend! "*) exit 0 ;;
+esac
+
+pattern='^(feat|fix|docs|chore|refactor|test|style|perf|build|ci|revert)(\([^()[:space:]]+\))?!?: [^[:space:]].*'
+if ! printf '%s\n' "$header" | grep -Eq "$pattern"; then
+    echo "gitph: a mensagem não segue o padrão Conventional Commits." >&2
+    echo "  Esperado: tipo(escopo opcional): assunto  (ex: feat(api): adiciona login)" >&2
+    echo "  Recebido: $header" >&2
+    exit 1
+fi
+
+length=$(printf '%s' "$header" | LC_ALL=C tr -d '\200-\277' | wc -c | tr -d ' ')
+if [ "$length" -gt 72 ]; then
+    echo "gitph: a primeira linha tem $length caracteres; o máximo é 72." >&2
+    exit 1
+fi
+"#;
+
+/// O resultado da instalação de um hook.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookInstall {
+    /// O caminho do hook instalado.
+    pub path: PathBuf,
+    /// Onde o hook que existia antes foi guardado, se havia um.
+    pub backup: Option<PathBuf>,
+}
+
+/// Instala o hook `commit-msg` que valida o formato Conventional Commits.
+///
+/// O diretório de hooks é obtido com `git rev-parse --git-path hooks`, que
+/// respeita a configuração `core.hooksPath`. Um hook `commit-msg` que não
+/// tenha sido instalado pelo `gitph` é preservado como `commit-msg.bak` (ou
+/// `commit-msg.bak.N`, se o nome já estiver em uso).
+///
+/// # Returns
+/// `Ok(HookInstall)` com o caminho do hook e do backup, ou `Err` se o
+/// diretório atual não for um repositório ou se o arquivo não puder ser
+/// escrito ou tornado executável.
+pub fn install_commit_msg_hook() -> Result<HookInstall> {
+    let output = run_git(&["rev-parse", "--git-path", "hooks"])
+        .map_err(|e| anyhow!("Não foi possível localizar o diretório de hooks: {}", e))?;
+    let hooks_dir = resolve_path(output.stdout.trim());
+    fs::create_dir_all(&hooks_dir)
+        .with_context(|| format!("Falha ao criar o diretório de hooks '{}'.", hooks_dir.display()))?;
+
+    let path = hooks_dir.join("commit-msg");
+    // Um hook que não é texto (ex: um binário) também é de outra pessoa.
+    let installed_by_gitph = fs::read_to_string(&path).is_ok_and(|hook| hook.contains(HOOK_MARKER));
+    let backup = if path.exists() && !installed_by_gitph {
+        let backup = backup_path(&path);
+        fs::rename(&path, &backup)
+            .with_context(|| format!("Falha ao guardar o hook existente em '{}'.", backup.display()))?;
+        Some(backup)
+    } else {
+        None
+    };
+
+    fs::write(&path, COMMIT_MSG_HOOK).with_context(|| format!("Falha ao escrever o hook '{}'.", path.display()))?;
+    make_executable(&path)?;
+    Ok(HookInstall { path, backup })
+}
+
+/// Escolhe um nome livre para o backup de um hook existente.
+fn backup_path(path: &Path) -> PathBuf {
+    let first = path.with_extension("bak");
+    if !first.exists() {
+        return first;
+    }
+    (1..)
+        .map(|n| path.with_extension(format!("bak.{}", n)))
+        .find(|candidate| !candidate.exists())
+        .expect("sempre há um nome de backup livre")
+}
+
+/// Garante que o hook tenha permissão de execução (`chmod 755`).
+///
+/// Sem ela, o Git ignora o hook silenciosamente (apenas com um aviso).
+#[cfg(unix)]
+fn make_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut permissions = fs::metadata(path)?.permissions();
+    if permissions.mode() & 0o111 != 0o111 {
+        permissions.set_mode(0o755);
+        fs::set_permissions(path, permissions)
+            .with_context(|| format!("Falha ao tornar o hook '{}' executável.", path.display()))?;
+    }
+    Ok(())
+}
+
+/// No Windows não há bit de execução: o Git executa o hook pelo seu shell.
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
\ No newline at end of file
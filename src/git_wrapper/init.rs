@@ -0,0 +1,53 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo de Inicialização de Repositórios
+//
+// Implementa a criação de um novo repositório Git vazio com `git init`.
+// ==============================================================================
+
+use super::{resolve_path, run_git};
+use anyhow::{anyhow, Result};
+use std::path::Path;
+
+/// O nome da branch inicial usado quando nenhum é informado.
+pub const DEFAULT_INITIAL_BRANCH: &str = "main";
+
+/// Inicializa um novo repositório Git no diretório informado.
+///
+/// Executa `git init -b <branch> <caminho>`. O diretório é criado pelo Git
+/// caso ainda não exista.
+///
+/// # Arguments
+/// * `path` - O diretório onde o repositório será criado.
+/// * `initial_branch` - O nome da branch inicial, ou `None` para usar
+///   `DEFAULT_INITIAL_BRANCH`.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se o diretório já contiver um
+/// repositório Git ou se o comando `git init` falhar.
+pub fn init(path: &Path, initial_branch: Option<&str>) -> Result<()> {
+    // O `git init` em um repositório existente apenas o "reinicializa" sem
+    // erro, o que raramente é a intenção. Por isso, recusamos explicitamente.
+    if resolve_path(path).join(".git").exists() {
+        return Err(anyhow!(
+            "O diretório '{}' já contém um repositório Git.",
+            path.display()
+        ));
+    }
+
+    let branch = match initial_branch.map(str::trim) {
+        Some(b) if !b.is_empty() => b,
+        _ => DEFAULT_INITIAL_BRANCH,
+    };
+    let path_arg = path.to_string_lossy();
+
+    run_git(&["init", "-b", branch, &path_arg])
+        .map_err(|e| anyhow!("Falha ao inicializar o repositório: {}", e))?;
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,403 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo de Histórico (Log) do Git
+//
+// Este módulo lê o histórico de commits do repositório através do `git log`.
+// Para obter uma saída estável e fácil de analisar, usamos um `--format`
+// personalizado em que os campos são separados pelo caractere de controle
+// "Unit Separator" (0x1F), que nunca aparece em mensagens de commit comuns.
+// ==============================================================================
+
+use super::{head_exists, run_git, FIELD_SEPARATOR, GitCommandError};
+use anyhow::{anyhow, Result};
+
+/// Representa um único commit do histórico.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitEntry {
+    /// O hash completo do commit.
+    pub hash: String,
+    /// A primeira linha (assunto) da mensagem de commit.
+    pub subject: String,
+}
+
+/// Lista os commits alcançáveis a partir de `HEAD` que não estão em `since`.
+///
+/// Executa `git log <since>..HEAD`. Quando `since` é `None`, todo o histórico
+/// da branch atual é retornado.
+///
+/// # Arguments
+/// * `since` - Uma referência (tag, branch ou hash) que marca o ponto de partida,
+///   exclusivo. Tipicamente, a última tag de release.
+///
+/// # Returns
+/// `Ok(Vec<CommitEntry>)` do mais recente para o mais antigo, ou `Err` se o
+/// comando `git log` falhar (ex: referência inexistente).
+pub fn commits_since(since: Option<&str>) -> Result<Vec<CommitEntry>> {
+    let range = match since {
+        Some(reference) => format!("{}..HEAD", reference),
+        None => "HEAD".to_string(),
+    };
+
+    read_commits(&[&range])
+}
+
+/// Lista os commits mais recentes da branch atual.
+///
+/// # Arguments
+/// * `limit` - O número máximo de commits retornados.
+///
+/// # Returns
+/// `Ok(Vec<CommitEntry>)` do mais recente para o mais antigo (vazio se a
+/// branch ainda não tiver commits), ou `Err` se o comando `git log` falhar.
+pub fn recent_commits(limit: usize) -> Result<Vec<CommitEntry>> {
+    if !head_exists()? {
+        return Ok(Vec::new());
+    }
+    let limit = limit.to_string();
+    read_commits(&["-n", &limit])
+}
+
+/// Obtém a mensagem completa (assunto e corpo) do último commit.
+///
+/// Executa `git log -1 --format=%B`.
+///
+/// # Returns
+/// `Ok(String)` com a mensagem, sem espaços nas extremidades, ou `Err` se a
+/// branch ainda não tiver commits.
+pub fn last_commit_message() -> Result<String> {
+    let output = run_git(&["log", "-1", "--format=%B"])
+        .map_err(|e| anyhow!("O comando 'git log' falhou: {}", e))?;
+    Ok(output.stdout.trim().to_string())
+}
+
+/// Lista os commits de `reference` que ainda não estão na branch atual.
+///
+/// Executa `git log -n <limit> HEAD..<reference>`. É a lista de candidatos
+/// naturais para um cherry-pick a partir de outra branch.
+///
+/// # Arguments
+/// * `reference` - A branch (ou outra referência) de origem.
+/// * `limit` - O número máximo de commits retornados.
+///
+/// # Returns
+/// `Ok(Vec<CommitEntry>)` do mais recente para o mais antigo, ou `Err` se o
+/// comando `git log` falhar.
+pub fn commits_not_in_head(reference: &str, limit: usize) -> Result<Vec<CommitEntry>> {
+    let limit = limit.to_string();
+    let range = format!("HEAD..{}", reference);
+    read_commits(&["-n", &limit, &range])
+}
+
+/// Executa `git log` com os argumentos informados e analisa a saída.
+fn read_commits(args: &[&str]) -> Result<Vec<CommitEntry>> {
+    let format = format!("--format=%H{}%s", FIELD_SEPARATOR);
+    let mut full_args = vec!["log", format.as_str()];
+    full_args.extend_from_slice(args);
+
+    let output = run_git(&full_args)
+        .map_err(|e| anyhow!("O comando 'git log' falhou: {}", e))?;
+
+    let entries = output
+        .stdout
+        .lines()
+        .filter_map(|line| {
+            let (hash, subject) = line.split_once(FIELD_SEPARATOR)?;
+            Some(CommitEntry {
+                hash: hash.to_string(),
+                subject: subject.to_string(),
+            })
+        })
+        .collect();
+
+    Ok(entries)
+}
+
+/// Uma entrada do reflog de `HEAD`: um ponto pelo qual a branch atual passou.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReflogEntry {
+    /// O seletor da entrada (ex: `HEAD@{2}`).
+    pub selector: String,
+    /// O hash completo do commit para o qual `HEAD` apontava.
+    pub sha: String,
+    /// A operação que moveu `HEAD` (ex: "commit", "reset", "rebase (finish)").
+    pub action: String,
+    /// A descrição da operação (ex: o assunto do commit ou "moving to HEAD~1").
+    pub subject: String,
+}
+
+/// Lista as entradas mais recentes do reflog de `HEAD`.
+///
+/// Executa `git reflog -n <limit>` com um `--format` próprio. O reflog guarda
+/// os commits pelos quais `HEAD` passou, inclusive os que deixaram de ser
+/// alcançáveis após um reset ou rebase, e é a forma de recuperá-los.
+///
+/// # Arguments
+/// * `limit` - O número máximo de entradas retornadas.
+///
+/// # Returns
+/// `Ok(Vec<ReflogEntry>)` da mais recente para a mais antiga (vazio se a
+/// branch ainda não tiver commits ou o reflog estiver vazio), ou `Err` se o
+/// comando `git reflog` falhar.
+pub fn reflog(limit: usize) -> Result<Vec<ReflogEntry>> {
+    if !head_exists()? {
+        return Ok(Vec::new());
+    }
+    let limit = limit.to_string();
+    let format = format!("--format=%H{0}%gd{0}%gs", FIELD_SEPARATOR);
+    let output = run_git(&["reflog", "-n", &limit, &format])
+        .map_err(|e| anyhow!("O comando 'git reflog' falhou: {}", e))?;
+
+    Ok(output.stdout.lines().filter_map(parse_reflog_line).collect())
+}
+
+/// Analisa uma linha do `git reflog` no formato `%H<sep>%gd<sep>%gs`.
+///
+/// A mensagem (`%gs`) tem a forma "ação: descrição" (ex: "reset: moving to
+/// HEAD~1"); sem o ": ", ela inteira é tratada como a ação.
+fn parse_reflog_line(line: &str) -> Option<ReflogEntry> {
+    let mut fields = line.splitn(3, FIELD_SEPARATOR);
+    let sha = fields.next()?;
+    let selector = fields.next()?;
+    let message = fields.next().unwrap_or_default();
+    if sha.is_empty() || selector.is_empty() {
+        return None;
+    }
+    let (action, subject) = message.split_once(": ").unwrap_or((message, ""));
+    Some(ReflogEntry {
+        selector: selector.to_string(),
+        sha: sha.to_string(),
+        action: action.to_string(),
+        subject: subject.to_string(),
+    })
+}
+
+/// Obtém o grafo do histórico recente, como desenhado pelo próprio Git.
+///
+/// Executa `git log --graph --oneline --decorate -n <limit> --color=never`.
+/// As linhas são retornadas sem análise: cada uma contém os caracteres do
+/// grafo (`*`, `|`, `/`, `\`) seguidos, nas linhas de commit, pelo hash
+/// abreviado, pelas referências entre parênteses (com o nome completo, ex:
+/// `refs/remotes/origin/main`, para que a UI distinga branches locais, remotas
+/// e tags) e pelo assunto. A coloração fica a cargo da UI.
+///
+/// # Arguments
+/// * `limit` - O número máximo de commits exibidos.
+///
+/// # Returns
+/// `Ok(Vec<String>)` com as linhas do grafo (vazio se a branch ainda não tiver
+/// commits), ou `Err` se o comando `git log` falhar.
+pub fn commit_graph(limit: usize) -> Result<Vec<String>> {
+    if !head_exists()? {
+        return Ok(Vec::new());
+    }
+
+    let limit = limit.to_string();
+    let output = run_git(&[
+        "log",
+        "--graph",
+        "--oneline",
+        "--decorate=full",
+        "--color=never",
+        "-n",
+        &limit,
+    ])
+    .map_err(|e| anyhow!("O comando 'git log' falhou: {}", e))?;
+
+    Ok(output.stdout.lines().map(String::from).collect())
+}
+
+/// Os detalhes de um commit, como exibidos por `git show --pretty=fuller`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CommitDetails {
+    /// O hash completo do commit.
+    pub hash: String,
+    /// O autor, no formato "Nome <email>", canonicalizado pelo `.mailmap`.
+    pub author: String,
+    /// A data de autoria, como formatada pelo Git.
+    pub author_date: String,
+    /// Quem registrou o commit (difere do autor em cherry-picks, rebases, etc.).
+    pub committer: String,
+    /// A data do registro do commit.
+    pub commit_date: String,
+    /// A mensagem completa, sem a indentação adicionada pelo Git.
+    pub message: String,
+    /// Os arquivos alterados e o resumo de cada alteração (ex: "3 ++-").
+    /// Vazio para commits de merge, que o `--stat` não detalha.
+    pub files: Vec<FileChangeStat>,
+    /// A linha de resumo (ex: "2 files changed, 5 insertions(+)"), se houver.
+    pub summary: Option<String>,
+}
+
+/// Uma linha do `--stat`: um arquivo e o tamanho da sua alteração.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileChangeStat {
+    /// O caminho do arquivo (pode vir abreviado com "..." pelo Git).
+    pub path: String,
+    /// O resumo da alteração, como "3 ++-" ou "Bin 0 -> 5 bytes".
+    pub changes: String,
+}
+
+/// Obtém os detalhes de um commit.
+///
+/// A referência é validada com `git rev-parse --verify` antes de executar
+/// `git show --stat --pretty=fuller`, para que uma referência inexistente
+/// resulte em uma mensagem clara. O `--use-mailmap` garante que autor e
+/// committer apareçam com o nome e o email canônicos do `.mailmap`, mesmo
+/// com `log.mailmap = false` (o padrão antes do Git 2.29).
+///
+/// # Arguments
+/// * `sha` - O hash (completo ou abreviado) ou qualquer referência a um commit
+///   (ex: "HEAD~2", "v1.0").
+///
+/// # Returns
+/// `Ok(CommitDetails)`, ou `Err` se a referência não apontar para um commit.
+pub fn show_commit(sha: &str) -> Result<CommitDetails> {
+    let sha = sha.trim();
+    // Uma referência iniciada por '-' seria interpretada como uma opção.
+    if sha.is_empty() || sha.starts_with('-') {
+        return Err(anyhow!("'{}' não é uma referência válida.", sha));
+    }
+
+    let target = format!("{}^{{commit}}", sha);
+    let hash = match run_git(&["rev-parse", "--verify", "--quiet", &target]) {
+        Ok(output) => output.stdout.trim().to_string(),
+        Err(e) if e.is::<GitCommandError>() => {
+            return Err(anyhow!("Revisão desconhecida: '{}' não aponta para nenhum commit.", sha));
+        }
+        Err(e) => return Err(e),
+    };
+
+    let output = run_git(&["show", "--stat", "--pretty=fuller", "--use-mailmap", "--no-color", &hash])
+        .map_err(|e| anyhow!("O comando 'git show' falhou: {}", e))?;
+
+    Ok(parse_show_output(&output.stdout))
+}
+
+/// Analisa a saída de `git show --stat --pretty=fuller`.
+///
+/// A saída tem três blocos: os cabeçalhos (`commit`, `Author:`, etc.), a
+/// mensagem indentada com quatro espaços e, por fim, as linhas do `--stat`,
+/// indentadas com um único espaço.
+fn parse_show_output(output: &str) -> CommitDetails {
+    let mut details = CommitDetails::default();
+    let mut message = Vec::new();
+    let mut lines = output.lines();
+
+    // Cabeçalhos, até a primeira linha em branco.
+    for line in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+        if let Some(hash) = line.strip_prefix("commit ") {
+            details.hash = hash.trim().to_string();
+        } else if let Some((key, value)) = line.split_once(':') {
+            let value = value.trim().to_string();
+            match key {
+                "Author" => details.author = value,
+                "AuthorDate" => details.author_date = value,
+                "Commit" => details.committer = value,
+                "CommitDate" => details.commit_date = value,
+                _ => {}
+            }
+        }
+    }
+
+    for line in lines {
+        if let Some(text) = line.strip_prefix("    ") {
+            message.push(text);
+        } else if line.is_empty() {
+            // Linhas em branco da mensagem não recebem a indentação.
+            if details.files.is_empty() && details.summary.is_none() {
+                message.push("");
+            }
+        } else if let Some((path, changes)) = line.rsplit_once(" | ") {
+            details.files.push(FileChangeStat {
+                path: path.trim().to_string(),
+                changes: changes.trim().to_string(),
+            });
+        } else if line.contains(" changed") {
+            details.summary = Some(line.trim().to_string());
+        }
+    }
+
+    details.message = message.join("\n").trim().to_string();
+    details
+}
+
+/// Gera um changelog em Markdown a partir de uma lista de commits.
+///
+/// Os commits são agrupados pelo tipo do Conventional Commits presente no
+/// assunto (`feat: ...`, `fix(api): ...`, etc.). O prefixo de tipo é removido
+/// da linha final e o escopo, quando existir, é destacado em negrito.
+/// Commits fora da convenção vão para a seção "Outras Alterações".
+///
+/// # Arguments
+/// * `commits` - Os commits a incluir, em qualquer ordem.
+///
+/// # Returns
+/// O texto Markdown do changelog. Seções sem commits são omitidas.
+pub fn format_changelog(commits: &[CommitEntry]) -> String {
+    // (título da seção, tipos de commit que ela agrupa)
+    let sections: [(&str, &[&str]); 3] = [
+        ("## Novidades", &["feat"]),
+        ("## Correções", &["fix"]),
+        ("## Melhorias", &["perf", "refactor"]),
+    ];
+    let mut grouped: Vec<Vec<String>> = vec![Vec::new(); sections.len()];
+    let mut others = Vec::new();
+
+    for commit in commits {
+        match parse_conventional_subject(&commit.subject) {
+            Some((kind, scope, description)) => {
+                let line = match scope {
+                    Some(scope) => format!("- **{}:** {}", scope, description),
+                    None => format!("- {}", description),
+                };
+                match sections.iter().position(|(_, kinds)| kinds.contains(&kind)) {
+                    Some(index) => grouped[index].push(line),
+                    None => others.push(line),
+                }
+            }
+            None => others.push(format!("- {}", commit.subject)),
+        }
+    }
+
+    let mut changelog = String::new();
+    for ((title, _), lines) in sections.iter().zip(&grouped) {
+        if !lines.is_empty() {
+            changelog.push_str(&format!("{}\n\n{}\n\n", title, lines.join("\n")));
+        }
+    }
+    if !others.is_empty() {
+        changelog.push_str(&format!("## Outras Alterações\n\n{}\n\n", others.join("\n")));
+    }
+
+    changelog
+}
+
+/// Decompõe um assunto no formato `tipo(escopo)!: descrição`.
+///
+/// # Returns
+/// `Some((tipo, escopo, descrição))` se o assunto seguir a convenção, ou
+/// `None` caso contrário. O `!` de breaking change é aceito e descartado.
+fn parse_conventional_subject(subject: &str) -> Option<(&str, Option<&str>, &str)> {
+    let (prefix, description) = subject.split_once(": ")?;
+    let prefix = prefix.strip_suffix('!').unwrap_or(prefix);
+
+    let (kind, scope) = match prefix.split_once('(') {
+        Some((kind, rest)) => (kind, Some(rest.strip_suffix(')')?)),
+        None => (prefix, None),
+    };
+
+    // O tipo deve ser uma única palavra em letras minúsculas (ex: "feat").
+    if kind.is_empty() || !kind.chars().all(|c| c.is_ascii_lowercase()) {
+        return None;
+    }
+
+    Some((kind, scope, description.trim()))
+}
\ No newline at end of file
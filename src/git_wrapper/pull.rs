@@ -0,0 +1,254 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo de Pull e Sincronização do Git
+//
+// Integra à branch atual as alterações do seu upstream, por merge ou rebase
+// (conforme `pull_mode` na configuração), e oferece o fluxo `sync`, que
+// combina fetch, integração e push em uma única ação.
+//
+// No `sync`, diferente do `git pull`, a integração é feita sobre as
+// referências remotas já buscadas: assim, o fetch exibe seu progresso em tempo
+// real e respeita o tempo limite de rede, e a etapa local pode ser desfeita
+// com segurança se houver conflitos. Para quem quer apenas atualizar a branch,
+// `pull` executa o próprio `git pull`.
+// ==============================================================================
+
+use super::branch::{self, BranchInfo};
+use super::{conflicted_files, fetch, output_has_conflicts, push, run_git, run_git_network, ConflictError, GitCommandError};
+use crate::config;
+use crate::ui::output;
+use anyhow::{anyhow, Result};
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Como as alterações do upstream são integradas à branch local.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum PullMode {
+    /// Cria um commit de merge quando as histórias divergem (padrão do Git).
+    #[default]
+    Merge,
+    /// Reaplica os commits locais sobre o upstream, mantendo o histórico linear.
+    Rebase,
+}
+
+impl PullMode {
+    /// O nome do comando Git usado na integração.
+    fn operation(self) -> &'static str {
+        match self {
+            PullMode::Merge => "merge",
+            PullMode::Rebase => "rebase",
+        }
+    }
+}
+
+/// O resultado de um `sync` bem-sucedido.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncReport {
+    /// A branch sincronizada.
+    pub branch: String,
+    /// O upstream da branch (ex: "origin/main").
+    pub upstream: String,
+    /// Quantos commits do upstream foram integrados localmente.
+    pub integrated: u32,
+    /// A saída do `git push`, ou `None` se não havia commits locais a enviar.
+    pub push_output: Option<String>,
+}
+
+/// Integra o upstream (já buscado) à branch atual.
+///
+/// Executa `git merge --no-edit --autostash @{u}` ou
+/// `git rebase --autostash @{u}`. O `--autostash` guarda temporariamente as
+/// alterações não commitadas, que seriam um impedimento para as duas operações.
+///
+/// # Arguments
+/// * `mode` - Merge ou rebase.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso. Retorna um `ConflictError` se a integração
+/// parar por conflitos (a operação fica em andamento), ou outro `Err` em caso
+/// de falha.
+pub fn integrate_upstream(mode: PullMode) -> Result<()> {
+    let args: &[&str] = match mode {
+        PullMode::Merge => &["merge", "--no-edit", "--autostash", "@{u}"],
+        PullMode::Rebase => &["rebase", "--autostash", "@{u}"],
+    };
+
+    match run_git(args) {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            let files = conflicted_files().unwrap_or_default();
+            if !files.is_empty() || output_has_conflicts(&e.to_string()) {
+                return Err(ConflictError {
+                    operation: mode.operation(),
+                    files,
+                }
+                .into());
+            }
+            Err(anyhow!("Falha ao integrar o upstream ({}): {}", mode.operation(), e))
+        }
+    }
+}
+
+/// Desfaz uma integração interrompida por conflitos.
+///
+/// Executa `git merge --abort` ou `git rebase --abort`, restaurando a branch
+/// (e as alterações guardadas pelo `--autostash`) ao estado anterior.
+pub fn abort_integration(mode: PullMode) -> Result<()> {
+    run_git(&[mode.operation(), "--abort"])
+        .map_err(|e| anyhow!("Falha ao abortar o {}: {}", mode.operation(), e))?;
+    Ok(())
+}
+
+/// Atualiza a branch atual com as alterações do seu upstream.
+///
+/// Executa `git pull --no-edit --autostash` com `--no-rebase` ou `--rebase`,
+/// exibindo um spinner enquanto o Git trabalha. O modo é sempre explícito,
+/// para que o Git não recuse o pull de branches divergentes por falta de um
+/// `pull.rebase` configurado.
+///
+/// Um pull nunca é reportado como bem-sucedido se restarem arquivos em
+/// conflito no repositório, mesmo que o Git tenha terminado sem erro.
+///
+/// # Arguments
+/// * `mode` - Merge ou rebase (normalmente, `Config::pull_mode`).
+///
+/// # Returns
+/// `Ok(String)` com o resumo do Git (`stderr` e `stdout` combinados). Retorna
+/// um `ConflictError` se houver conflitos (a integração fica em andamento),
+/// ou outro `Err` se o pull falhar.
+pub fn pull(mode: PullMode) -> Result<String> {
+    config::ensure_online("o 'git pull'")?;
+
+    let spinner = ProgressBar::new_spinner();
+    spinner.set_style(
+        ProgressStyle::default_spinner()
+            .template("{spinner:.green} {msg}")
+            .unwrap(),
+    );
+    spinner.set_message("Puxando as alterações do remoto...");
+    if output::is_quiet() {
+        spinner.set_draw_target(ProgressDrawTarget::hidden());
+    }
+    crate::interrupt::track_progress(&spinner);
+    spinner.enable_steady_tick(Duration::from_millis(100));
+
+    let mode_flag = match mode {
+        PullMode::Merge => "--no-rebase",
+        PullMode::Rebase => "--rebase",
+    };
+    let result = run_git_network(&["pull", "--no-edit", "--autostash", mode_flag]);
+
+    spinner.finish_and_clear();
+
+    let summary = match result {
+        // O Git escreve o "From <url>" no `stderr`, antes do resumo no `stdout`.
+        Ok(git_output) => format!("{}\n{}", git_output.stderr.trim(), git_output.stdout.trim())
+            .trim()
+            .to_string(),
+        Err(e) => {
+            let Some(git_error) = e.downcast_ref::<GitCommandError>() else {
+                return Err(e);
+            };
+            let files = conflicted_files().unwrap_or_default();
+            if !files.is_empty() || output_has_conflicts(&git_error.combined_output()) {
+                return Err(ConflictError {
+                    operation: mode.operation(),
+                    files,
+                }
+                .into());
+            }
+            return Err(anyhow!("O comando 'git pull' falhou:\n\n{}", git_error.combined_output()));
+        }
+    };
+
+    // Mesmo quando o Git termina sem erro, não reportamos sucesso se restarem
+    // conflitos no repositório.
+    let files = conflicted_files().unwrap_or_default();
+    if !files.is_empty() {
+        return Err(ConflictError {
+            operation: mode.operation(),
+            files,
+        }
+        .into());
+    }
+    Ok(summary)
+}
+
+/// Sincroniza a branch atual com o remoto: fetch, integração e push.
+///
+/// 1. Busca o remoto do upstream da branch atual.
+/// 2. Se o upstream tiver commits novos, integra-os com `integrate_upstream`.
+/// 3. Se a branch tiver commits locais à frente, envia-os com `push`.
+///
+/// Se a integração parar por conflitos, ela é abortada antes de retornar,
+/// deixando a branch exatamente como estava, e nada é enviado.
+///
+/// # Arguments
+/// * `mode` - Como integrar o upstream (normalmente, `Config::pull_mode`).
+///
+/// # Returns
+/// Um `SyncReport` com o que foi feito, ou `Err` se a branch não tiver um
+/// upstream válido, se houver conflitos ou se alguma etapa falhar.
+pub fn sync(mode: PullMode) -> Result<SyncReport> {
+    let info = current_branch_info()?;
+    let upstream = match (&info.upstream, info.upstream_gone) {
+        (Some(_), true) | (None, _) => {
+            return Err(anyhow!(
+                "A branch '{}' não tem uma branch remota para sincronizar. \
+                 Envie-a primeiro com o fluxo SND (snd).",
+                info.name
+            ))
+        }
+        (Some(upstream), false) => upstream.clone(),
+    };
+
+    let remote = branch::tracking_remote(&info.name);
+    fetch::fetch(&remote, false)?;
+
+    // As contagens só são confiáveis depois do fetch.
+    let info = current_branch_info()?;
+    if info.behind > 0 {
+        output::info(format!("Integrando {} commit(s) de '{}' ({})...", info.behind, upstream, mode.operation()));
+        if let Err(e) = integrate_upstream(mode) {
+            let Some(conflict) = e.downcast_ref::<ConflictError>() else {
+                return Err(e);
+            };
+            let files = conflict.files.join(", ");
+            abort_integration(mode)?;
+            return Err(anyhow!(
+                "As alterações de '{}' conflitam com as suas{}. O {} foi desfeito e nada foi enviado.\n\
+                 Integre-as manualmente (ex: pela opção de rebase) e sincronize novamente.",
+                upstream,
+                if files.is_empty() { String::new() } else { format!(" em: {}", files) },
+                mode.operation()
+            ));
+        }
+    }
+
+    let ahead = current_branch_info()?.ahead;
+    let push_output = if ahead > 0 { Some(push::push(false)?) } else { None };
+
+    Ok(SyncReport {
+        branch: info.name,
+        upstream,
+        integrated: info.behind,
+        push_output,
+    })
+}
+
+/// Retorna as informações de rastreamento da branch atual.
+fn current_branch_info() -> Result<BranchInfo> {
+    let name = branch::current_branch()?
+        .ok_or_else(|| anyhow!("Você está em detached HEAD; mude para uma branch antes de sincronizar."))?;
+    branch::list_branches()?
+        .into_iter()
+        .find(|b| b.name == name)
+        .ok_or_else(|| anyhow!("A branch '{}' ainda não tem commits.", name))
+}
\ No newline at end of file
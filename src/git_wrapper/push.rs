@@ -16,27 +16,151 @@
 // as principais prioridades aqui.
 // ==============================================================================
 
+use super::{git_command, run_git, stream_stderr_with, GitCommandError, LoggedCommand};
+use crate::config;
+use crate::ui::output;
 use anyhow::{anyhow, Context, Result};
-use indicatif::{ProgressBar, ProgressStyle};
-use std::process::Command;
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+use std::fmt;
+use std::process::Stdio;
 use std::time::Duration;
 
+/// Os prefixos das linhas de progresso que o Git escreve durante um push (ou
+/// um fetch, que reaproveita estas funções).
+///
+/// Elas são exibidas apenas na mensagem do spinner e não fazem parte do
+/// resumo retornado por `push()`.
+const PROGRESS_PREFIXES: &[&str] = &[
+    "Enumerating objects",
+    "Counting objects",
+    "Delta compression",
+    "Compressing objects",
+    "Writing objects",
+    "Resolving deltas",
+    "Receiving objects",
+    "Unpacking objects",
+    "Total ",
+];
+
 /// Envia os commits locais para o repositório remoto configurado.
 ///
 /// Esta função executa o comando `git push`. Ela assume que a branch atual
 /// já está configurada para rastrear uma branch remota (upstream).
 ///
 /// Durante a execução, um spinner é exibido para indicar ao usuário que uma
-/// operação de rede está em andamento.
+/// operação de rede está em andamento. Assim como no `clone`, o `stderr` é
+/// lido em tempo real: cada linha de progresso do Git (ex: "Writing objects:
+/// 45% (9/20)") substitui a mensagem do spinner. Se o Git não emitir
+/// progresso (ex: em um dry-run), a mensagem genérica permanece.
+///
+/// # Arguments
+/// * `dry_run` - Se `true`, executa `git push --dry-run`: o Git calcula e
+///   reporta as atualizações de referências, mas nada é enviado ao remoto.
 ///
 /// # Returns
 /// Um `Result<String>`:
 /// - `Ok(String)`: Em caso de sucesso, contém a mensagem de saída do Git,
-///   que geralmente inclui um resumo das atualizações.
+///   que geralmente inclui um resumo das atualizações (sem as linhas de
+///   progresso).
 /// - `Err(anyhow::Error)`: Se o comando falhar. O erro conterá a mensagem
 ///   detalhada do `stderr` do Git, explicando o motivo da falha (ex: o
 ///   remoto contém trabalho que você não tem localmente).
-pub fn push() -> Result<String> {
+pub fn push(dry_run: bool) -> Result<String> {
+    let message = if dry_run {
+        "Simulando o envio para o repositório remoto..."
+    } else {
+        "Enviando commits para o repositório remoto..."
+    };
+    run_push(if dry_run { &["--dry-run"] } else { &[] }, message)
+}
+
+/// Erro retornado por `force_push` quando a referência remota mudou desde o
+/// último fetch.
+///
+/// O `--force-with-lease` só sobrescreve a referência remota se ela ainda
+/// estiver onde o último fetch a viu. Se alguém enviou commits depois disso,
+/// o Git recusa o push com "stale info", evitando que esse trabalho se perca.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaleInfoError {
+    /// O remoto (ex: "origin").
+    pub remote: String,
+    /// A branch enviada.
+    pub branch: String,
+}
+
+impl fmt::Display for StaleInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "A branch '{}/{}' mudou no remoto desde o último fetch, então o push forçado foi \
+             recusado para não apagar commits de outra pessoa. Busque as atualizações (fetch), \
+             integre-as e tente novamente.",
+            self.remote, self.branch
+        )
+    }
+}
+
+impl std::error::Error for StaleInfoError {}
+
+/// Envia uma branch reescrita (ex: após um rebase) substituindo a remota.
+///
+/// Executa `git push --force-with-lease <remote> <branch>`. Diferente do
+/// `--force`, a referência remota só é sobrescrita se ainda apontar para o
+/// commit visto no último fetch, o que torna esta a forma segura de publicar
+/// um histórico reescrito. O chamador deve pedir confirmação antes, exibindo
+/// a referência que será sobrescrita (veja `remote_tracking_commit`).
+///
+/// # Arguments
+/// * `remote` - O remoto de destino (ex: "origin").
+/// * `branch` - A branch local a enviar, com o mesmo nome no remoto.
+///
+/// # Returns
+/// `Ok(String)` com a saída do Git (sem as linhas de progresso), ou `Err`. Se
+/// o remoto tiver mudado desde o último fetch, o erro é um `StaleInfoError`.
+pub fn force_push(remote: &str, branch: &str) -> Result<String> {
+    let message = format!("Enviando '{}' para '{}' (push forçado)...", branch, remote);
+    run_push(&["--force-with-lease", remote, branch], &message).map_err(|e| {
+        if e.to_string().contains("(stale info)") {
+            StaleInfoError {
+                remote: remote.to_string(),
+                branch: branch.to_string(),
+            }
+            .into()
+        } else {
+            e
+        }
+    })
+}
+
+/// Obtém o commit para o qual a cópia local de uma branch remota aponta.
+///
+/// É o commit que um `force_push` sobrescreveria no remoto (segundo o último
+/// fetch), usado para mostrar ao usuário o que será substituído.
+///
+/// # Returns
+/// `Ok(Some(String))` com o hash abreviado, ou `Ok(None)` se a branch remota
+/// não for conhecida localmente.
+pub fn remote_tracking_commit(remote: &str, branch: &str) -> Result<Option<String>> {
+    let reference = format!("refs/remotes/{}/{}", remote, branch);
+    match run_git(&["rev-parse", "--short", "--verify", "-q", &reference]) {
+        Ok(output) => Ok(Some(output.stdout.trim().to_string())),
+        Err(e) if e.is::<GitCommandError>() => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Executa `git push --progress <extra_args>` exibindo um spinner com o progresso.
+///
+/// # Arguments
+/// * `extra_args` - Argumentos adicionais do `git push` (opções e refspecs).
+/// * `message` - A mensagem inicial do spinner.
+///
+/// # Returns
+/// A saída do Git sem as linhas de progresso, ou `Err` com essa saída se o
+/// push falhar.
+fn run_push(extra_args: &[&str], message: &str) -> Result<String> {
+    config::ensure_online("o 'git push'")?;
+
     // --- Preparação do Feedback Visual ---
     // Uma operação de 'push' pode ser demorada. Para evitar que o usuário
     // pense que a aplicação travou, criamos um spinner de progresso.
@@ -53,18 +177,37 @@ pub fn push() -> Result<String> {
             .unwrap(),
     );
 
-    spinner.set_message("Enviando commits para o repositório remoto...");
+    spinner.set_message(message.to_string());
+    if output::is_quiet() {
+        spinner.set_draw_target(ProgressDrawTarget::hidden());
+    }
+    crate::interrupt::track_progress(&spinner);
 
     // Ativamos o spinner para que ele comece a animar na tela.
     // O `Duration` define a velocidade da animação.
     spinner.enable_steady_tick(Duration::from_millis(100));
 
-    // --- Execução do Comando ---
-    // Executamos `git push` e capturamos sua saída.
-    let output = Command::new("git")
-        .arg("push")
-        .output()
-        .context("Falha ao executar o comando 'git push'.")?;
+    // --- Execução do Comando com Streaming ---
+    // Como o `stderr` não é um terminal, o Git só reporta o progresso se
+    // `--progress` for pedido explicitamente.
+    let mut command = git_command();
+    command.args(["push", "--progress"]).args(extra_args);
+    let mut child = match command.stderr(Stdio::piped()).logged().spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            spinner.finish_and_clear();
+            return Err(e).context("Falha ao iniciar o processo 'git push'.");
+        }
+    };
+
+    let progress_spinner = spinner.clone();
+    let result = stream_stderr_with(&mut child, "git push", move |line| {
+        // Uma mesma linha pode conter várias atualizações separadas por '\r';
+        // a última é a mais recente.
+        if let Some(progress) = line.rsplit('\r').map(str::trim).find(|s| is_progress_line(s)) {
+            progress_spinner.set_message(progress.to_string());
+        }
+    });
 
     // --- Finalização do Feedback Visual ---
     // Independentemente do resultado, o spinner cumpriu sua função.
@@ -72,21 +215,242 @@ pub fn push() -> Result<String> {
     spinner.finish_and_clear();
 
     // --- Processamento do Resultado ---
-    if output.status.success() {
-        // SUCESSO: O push foi aceito pelo remoto.
-        // É uma peculiaridade importante do `git push` que ele frequentemente
-        // escreve informações de sucesso no `stderr` em vez do `stdout`.
-        // Portanto, retornamos o conteúdo do `stderr` como a mensagem de sucesso.
-        let success_message = String::from_utf8_lossy(&output.stderr).trim().to_string();
-        Ok(success_message)
+    // É uma peculiaridade importante do `git push` que ele escreve tanto as
+    // informações de sucesso quanto o motivo das falhas no `stderr`.
+    let (status, captured) = result?;
+    let output = strip_progress(&captured);
+    if status.success() {
+        Ok(output)
     } else {
-        // FALHA: O push foi rejeitado ou ocorreu outro erro.
-        // A razão exata da falha estará no `stderr`. Capturamos essa mensagem
-        // para fornecer um erro claro e acionável ao usuário.
-        let error_message = String::from_utf8_lossy(&output.stderr).trim().to_string();
-        Err(anyhow!(
-            "O comando 'git push' falhou:\n\n{}",
-            error_message
-        ))
+        Err(anyhow!("O comando 'git push' falhou:\n\n{}", output))
+    }
+}
+
+/// Indica se um trecho do `stderr` é uma atualização de progresso do Git.
+pub(super) fn is_progress_line(segment: &str) -> bool {
+    let segment = segment.strip_prefix("remote:").unwrap_or(segment).trim_start();
+    PROGRESS_PREFIXES.iter().any(|prefix| segment.starts_with(prefix))
+}
+
+/// Remove as linhas de progresso de uma saída capturada do `git push` ou do
+/// `git fetch`.
+pub(super) fn strip_progress(output: &str) -> String {
+    output
+        .lines()
+        .flat_map(|line| line.split('\r'))
+        .filter(|segment| !segment.trim().is_empty() && !is_progress_line(segment.trim()))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// O que um `git push` fez com uma referência remota.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PushedRefKind {
+    /// Uma branch ou tag que não existia no remoto (`*`).
+    New,
+    /// Uma atualização normal, sem reescrita de histórico (` `).
+    Updated,
+    /// Uma atualização forçada: o histórico remoto foi reescrito (`+`).
+    Forced,
+    /// Uma referência removida do remoto (`-`).
+    Deleted,
+    /// Uma referência que já estava atualizada (`=`).
+    UpToDate,
+    /// Uma atualização recusada pelo remoto (`!`).
+    Rejected,
+}
+
+/// Uma referência atualizada por um `git push`, extraída do resumo do Git.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PushSummary {
+    /// O que aconteceu com a referência.
+    pub kind: PushedRefKind,
+    /// O commit anterior da referência remota, ou `None` se o Git não informou
+    /// um intervalo de commits (ex: `[new branch]`, `[deleted]`).
+    pub old_sha: Option<String>,
+    /// O novo commit da referência remota, ou `None` se o Git não informou um
+    /// intervalo de commits.
+    pub new_sha: Option<String>,
+    /// A referência local enviada (ex: "main"), ou `None` em uma remoção.
+    pub local_ref: Option<String>,
+    /// A referência remota afetada (ex: "main").
+    pub remote_ref: String,
+}
+
+/// Extrai as referências atualizadas da saída de sucesso de um `git push`.
+///
+/// O Git descreve cada referência em uma linha como:
+/// ```text
+///    abc1234..def5678  main -> main
+///  + abc1234...def5678 main -> main (forced update)
+///  * [new branch]      feature -> feature
+///  - [deleted]         antiga
+/// ```
+/// Linhas em outros formatos (ex: "To github.com:owner/repo.git") são ignoradas.
+///
+/// # Arguments
+/// * `output` - A mensagem retornada por `push()`.
+///
+/// # Returns
+/// As referências reconhecidas. Um vetor vazio indica que nada pôde ser
+/// analisado, e o chamador deve exibir a saída original.
+pub fn parse_push_summary(output: &str) -> Vec<PushSummary> {
+    output.lines().filter_map(parse_summary_line).collect()
+}
+
+/// Analisa uma única linha do resumo do `git push`.
+fn parse_summary_line(line: &str) -> Option<PushSummary> {
+    // O formato é " <flag> <resumo> <origem> -> <destino> [(<motivo>)]". A
+    // flag de uma atualização normal é um espaço, que pode ter sido removido
+    // junto com o recuo da linha.
+    let line = line.trim_start();
+    let mut chars = line.chars();
+    let flag = chars.next()?;
+    let (kind, rest) = if chars.next() == Some(' ') {
+        let kind = match flag {
+            '*' => PushedRefKind::New,
+            '+' => PushedRefKind::Forced,
+            '-' => PushedRefKind::Deleted,
+            '=' => PushedRefKind::UpToDate,
+            '!' => PushedRefKind::Rejected,
+            _ => return None,
+        };
+        (kind, chars.as_str().trim())
+    } else {
+        (PushedRefKind::Updated, line)
+    };
+
+    // Remoções não têm uma referência local: "- [deleted]         antiga".
+    if kind == PushedRefKind::Deleted {
+        let (summary, remote_ref) = rest.rsplit_once(char::is_whitespace)?;
+        if !summary.trim_end().starts_with('[') {
+            return None;
+        }
+        return Some(PushSummary {
+            kind,
+            old_sha: None,
+            new_sha: None,
+            local_ref: None,
+            remote_ref: remote_ref.to_string(),
+        });
+    }
+
+    let (left, right) = rest.split_once(" -> ")?;
+    let remote_ref = right.split_whitespace().next()?.to_string();
+
+    // À esquerda da seta: "<resumo> <ref local>".
+    let (summary, local_ref) = left.trim_end().rsplit_once(char::is_whitespace)?;
+    let summary = summary.trim();
+
+    let (old_sha, new_sha) = if summary.starts_with('[') {
+        // Ex: "[new branch]", "[up to date]": não há um intervalo de commits.
+        (None, None)
+    } else {
+        // Atualizações normais usam "..", e as forçadas, "...".
+        let (old, new) = summary
+            .split_once("...")
+            .or_else(|| summary.split_once(".."))?;
+        (Some(old.to_string()), Some(new.to_string()))
+    };
+
+    Some(PushSummary {
+        kind,
+        old_sha,
+        new_sha,
+        local_ref: Some(local_ref.to_string()),
+        remote_ref,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Analisa uma única linha e falha o teste se ela não for reconhecida.
+    fn parse_single(line: &str) -> PushSummary {
+        parse_summary_line(line).unwrap_or_else(|| panic!("linha não reconhecida: {:?}", line))
+    }
+
+    #[test]
+    fn parses_fast_forward_update() {
+        let pushed = parse_single("   72d5e2e..c220491  main -> main");
+        assert_eq!(pushed.kind, PushedRefKind::Updated);
+        assert_eq!(pushed.old_sha.as_deref(), Some("72d5e2e"));
+        assert_eq!(pushed.new_sha.as_deref(), Some("c220491"));
+        assert_eq!(pushed.local_ref.as_deref(), Some("main"));
+        assert_eq!(pushed.remote_ref, "main");
+    }
+
+    #[test]
+    fn parses_update_whose_indentation_was_trimmed() {
+        let pushed = parse_single("72d5e2e..c220491  HEAD -> feat");
+        assert_eq!(pushed.kind, PushedRefKind::Updated);
+        assert_eq!(pushed.local_ref.as_deref(), Some("HEAD"));
+        assert_eq!(pushed.remote_ref, "feat");
+    }
+
+    #[test]
+    fn parses_forced_update_and_ignores_the_reason() {
+        let pushed = parse_single(" + a1b2c3d...e4f5a6b feat -> feat (forced update)");
+        assert_eq!(pushed.kind, PushedRefKind::Forced);
+        assert_eq!(pushed.old_sha.as_deref(), Some("a1b2c3d"));
+        assert_eq!(pushed.new_sha.as_deref(), Some("e4f5a6b"));
+        assert_eq!(pushed.remote_ref, "feat");
+    }
+
+    #[test]
+    fn parses_new_branch() {
+        let pushed = parse_single(" * [new branch]      nova -> nova");
+        assert_eq!(pushed.kind, PushedRefKind::New);
+        assert_eq!(pushed.old_sha, None);
+        assert_eq!(pushed.new_sha, None);
+        assert_eq!(pushed.remote_ref, "nova");
+    }
+
+    #[test]
+    fn parses_deleted_branch_without_a_local_ref() {
+        let pushed = parse_single(" - [deleted]         antiga");
+        assert_eq!(pushed.kind, PushedRefKind::Deleted);
+        assert_eq!(pushed.local_ref, None);
+        assert_eq!(pushed.remote_ref, "antiga");
+    }
+
+    #[test]
+    fn parses_up_to_date_branch() {
+        let pushed = parse_single(" = [up to date]      main -> main");
+        assert_eq!(pushed.kind, PushedRefKind::UpToDate);
+        assert_eq!(pushed.old_sha, None);
+        assert_eq!(pushed.local_ref.as_deref(), Some("main"));
+    }
+
+    #[test]
+    fn parses_rejected_update() {
+        let pushed = parse_single(" ! [rejected]        HEAD~1 -> main (non-fast-forward)");
+        assert_eq!(pushed.kind, PushedRefKind::Rejected);
+        assert_eq!(pushed.local_ref.as_deref(), Some("HEAD~1"));
+        assert_eq!(pushed.remote_ref, "main");
+    }
+
+    #[test]
+    fn summary_skips_headers_and_unknown_lines() {
+        let output = "\
+Pushing to github.com:owner/repo.git
+To github.com:owner/repo.git
+   72d5e2e..c220491  main -> main
+ - [deleted]         antiga
+ * [new tag]         v1.0 -> v1.0
+updating local tracking ref 'refs/remotes/origin/main'";
+        let kinds: Vec<PushedRefKind> = parse_push_summary(output).iter().map(|p| p.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![PushedRefKind::Updated, PushedRefKind::Deleted, PushedRefKind::New]
+        );
+    }
+
+    #[test]
+    fn empty_output_has_no_refs() {
+        assert!(parse_push_summary("").is_empty());
     }
 }
\ No newline at end of file
@@ -0,0 +1,172 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo de Rebase do Git
+//
+// Reaplica os commits da branch atual sobre outra base, produzindo um
+// histórico linear. É a alternativa ao merge para quem prefere sincronizar
+// com o upstream sem commits de merge.
+//
+// Apenas o rebase NÃO interativo é suportado. O rebase interativo (`-i`)
+// depende de um editor com a lista de commits e de decisões do usuário a cada
+// passo, o que não se encaixa no fluxo guiado do `gitph`. A exceção é o
+// `--autosquash`, que usa o `-i` apenas para reordenar os commits de fixup:
+// a lista gerada pelo Git é aceita sem abrir o editor.
+//
+// Como no fetch, a saída do Git é exibida em tempo real.
+// ==============================================================================
+
+use super::{conflicted_files, git_command, output_has_conflicts, resolve_path, run_git, ConflictError, LoggedCommand};
+use crate::ui::output;
+use anyhow::{anyhow, Context, Result};
+use std::io::{BufRead, BufReader};
+use std::process::Stdio;
+
+/// Reaplica os commits da branch atual sobre `onto`.
+///
+/// Executa `git rebase <onto>`.
+///
+/// # Arguments
+/// * `onto` - A nova base (ex: "main" ou "origin/main").
+///
+/// # Returns
+/// `Ok(())` se o rebase terminar. Retorna um `ConflictError` se ele parar por
+/// conflitos (o repositório fica com o rebase em andamento), ou outro `Err`
+/// em caso de falha.
+pub fn rebase(onto: &str) -> Result<()> {
+    let onto = onto.trim();
+    if onto.is_empty() {
+        return Err(anyhow!("A base do rebase não pode ser vazia."));
+    }
+
+    output::info(format!("Reaplicando os commits sobre '{}'...", onto));
+    run_rebase(&[onto])
+}
+
+/// Incorpora os commits de fixup aos commits que eles corrigem.
+///
+/// Executa `git rebase -i --autosquash <target>~1` (ou `--root`, se o alvo for
+/// o primeiro commit), aceitando automaticamente a lista de commits montada
+/// pelo Git, na qual cada `fixup! <assunto>` já vem logo após o seu alvo.
+///
+/// Todos os commits reaplicados são recriados, então, com `sign`, o rebase
+/// recebe `-S` para que eles continuem assinados.
+///
+/// # Arguments
+/// * `target` - O commit mais antigo que recebeu um fixup; o rebase começa
+///   no seu pai.
+/// * `sign` - Se `true`, assina com GPG os commits recriados pelo rebase.
+///
+/// # Returns
+/// `Ok(())` se o rebase terminar, um `ConflictError` se ele parar por
+/// conflitos, ou outro `Err` em caso de falha.
+pub fn autosquash(target: &str, sign: bool) -> Result<()> {
+    let parent = format!("{}~1", target.trim());
+    let has_parent = run_git(&["rev-parse", "--verify", "-q", &parent]).is_ok();
+    let base = if has_parent { parent.as_str() } else { "--root" };
+
+    let mut args = vec!["-i", "--autosquash"];
+    if sign {
+        args.push("-S");
+    }
+    args.push(base);
+
+    output::info("Incorporando os commits de fixup...");
+    run_rebase(&args)
+}
+
+/// Continua um rebase interrompido, após a resolução dos conflitos.
+///
+/// Executa `git rebase --continue`. As mensagens dos commits reaplicados são
+/// mantidas sem abrir o editor.
+///
+/// # Returns
+/// `Ok(())` se o rebase terminar, um `ConflictError` se um próximo commit
+/// também gerar conflitos, ou outro `Err` em caso de falha.
+pub fn rebase_continue() -> Result<()> {
+    output::info("Continuando o rebase...");
+    run_rebase(&["--continue"])
+}
+
+/// Aborta o rebase em andamento, restaurando a branch ao estado anterior.
+///
+/// Executa `git rebase --abort`.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se não houver rebase em andamento.
+pub fn rebase_abort() -> Result<()> {
+    run_git(&["rebase", "--abort"]).map_err(|e| anyhow!("Falha ao abortar o rebase: {}", e))?;
+    Ok(())
+}
+
+/// Indica se há um rebase em andamento no repositório.
+///
+/// O Git mantém o estado do rebase nos diretórios `rebase-merge` (backend
+/// padrão) ou `rebase-apply` (backend antigo) dentro de `.git`.
+pub fn rebase_in_progress() -> Result<bool> {
+    for state_dir in ["rebase-merge", "rebase-apply"] {
+        let output = run_git(&["rev-parse", "--git-path", state_dir])?;
+        if resolve_path(output.stdout.trim()).is_dir() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Executa `git rebase <args>`, exibindo a saída em tempo real e convertendo
+/// uma parada por conflitos em um `ConflictError`.
+fn run_rebase(args: &[&str]) -> Result<()> {
+    // `GIT_EDITOR=true` faz o `--continue` aceitar a mensagem existente em
+    // vez de abrir um editor, que travaria a leitura da saída. Pelo mesmo
+    // motivo, `GIT_SEQUENCE_EDITOR=true` aceita a lista do `--autosquash`.
+    let mut child = git_command()
+        .arg("rebase")
+        .args(args)
+        .env("GIT_EDITOR", "true")
+        .env("GIT_SEQUENCE_EDITOR", "true")
+        .stderr(Stdio::piped())
+        .logged()
+        .spawn()
+        .context("Falha ao iniciar o processo 'git rebase'.")?;
+
+    // O `stdout` é herdado e aparece diretamente no terminal; guardamos o
+    // `stderr`, onde o Git descreve a parada por conflitos.
+    let mut captured_output = String::new();
+    if let Some(stderr) = child.stderr.take() {
+        let reader = BufReader::new(stderr);
+        for line in reader.lines() {
+            match line {
+                Ok(line_content) => {
+                    output::info(&line_content);
+                    captured_output.push_str(&line_content);
+                    captured_output.push('\n');
+                }
+                Err(e) => return Err(anyhow!(e).context("Falha ao ler a saída do git rebase.")),
+            }
+        }
+    }
+
+    let status = child
+        .wait()
+        .context("Falha ao aguardar o término do processo 'git rebase'.")?;
+
+    if !status.success() {
+        let files = conflicted_files().unwrap_or_default();
+        if !files.is_empty() || output_has_conflicts(&captured_output) {
+            return Err(ConflictError {
+                operation: "rebase",
+                files,
+            }
+            .into());
+        }
+        return Err(anyhow!(
+            "O comando 'git rebase' falhou. Verifique a saída acima para detalhes."
+        ));
+    }
+
+    Ok(())
+}
\ No newline at end of file
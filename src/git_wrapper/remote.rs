@@ -11,31 +11,422 @@
 // repositórios remotos do Git, como obter a URL do 'origin'.
 // ==============================================================================
 
-use anyhow::{anyhow, Context, Result};
-use std::process::Command;
+use super::{run_git, working_dir, GitCommandError};
+use anyhow::{anyhow, Result};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// Representa um repositório remoto configurado, com suas URLs de fetch e push.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Remote {
+    pub name: String,
+    pub fetch_url: String,
+    /// A URL de push. Normalmente igual à de fetch, mas pode ser diferente
+    /// se configurada com `git remote set-url --push`.
+    pub push_url: String,
+}
+
+/// Lista todos os repositórios remotos configurados.
+///
+/// Executa `git remote -v`, cuja saída tem duas linhas por remoto:
+/// ```text
+/// origin  https://github.com/owner/repo.git (fetch)
+/// origin  https://github.com/owner/repo.git (push)
+/// ```
+/// As duas linhas são combinadas em uma única entrada `Remote`.
+///
+/// # Returns
+/// `Ok(Vec<Remote>)` na ordem em que o Git os lista. Um repositório sem
+/// remotos resulta em um vetor vazio, não em erro.
+pub fn list_remotes() -> Result<Vec<Remote>> {
+    let output = run_git(&["remote", "-v"]).map_err(|e| anyhow!("Falha ao listar os remotos: {}", e))?;
+
+    let mut remotes: Vec<Remote> = Vec::new();
+
+    for line in output.stdout.lines() {
+        // Cada linha: "<nome>\t<url> (<fetch|push>)".
+        let mut parts = line.split_whitespace();
+        let (name, url, kind) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(name), Some(url), Some(kind)) => (name, url, kind),
+            _ => continue, // Ignora linhas malformadas.
+        };
+
+        // Procuramos a entrada existente deste remoto ou criamos uma nova.
+        let index = match remotes.iter().position(|r| r.name == name) {
+            Some(index) => index,
+            None => {
+                remotes.push(Remote {
+                    name: name.to_string(),
+                    fetch_url: url.to_string(),
+                    push_url: url.to_string(),
+                });
+                remotes.len() - 1
+            }
+        };
+
+        match kind {
+            "(fetch)" => remotes[index].fetch_url = url.to_string(),
+            "(push)" => remotes[index].push_url = url.to_string(),
+            _ => {}
+        }
+    }
+
+    Ok(remotes)
+}
+
+/// Adiciona um novo repositório remoto.
+///
+/// Executa `git remote add <name> <url>`. Antes de invocar o Git, verifica se
+/// já existe um remoto com o mesmo nome, para fornecer um erro mais claro.
+///
+/// # Arguments
+/// * `name` - O nome do novo remoto (ex: "upstream").
+/// * `url` - A URL do repositório remoto.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se o nome for inválido, já existir,
+/// ou se o comando do Git falhar.
+pub fn add_remote(name: &str, url: &str) -> Result<()> {
+    let (name, url) = (name.trim(), url.trim());
+    if name.is_empty() || url.is_empty() {
+        return Err(anyhow!("O nome e a URL do remoto não podem ser vazios."));
+    }
+    if list_remotes()?.iter().any(|r| r.name == name) {
+        return Err(anyhow!("Já existe um remoto chamado '{}'.", name));
+    }
+
+    run_remote_command(&["add", name, url])
+        .map_err(|e| e.context(format!("Falha ao adicionar o remoto '{}'.", name)))
+}
+
+/// Remove um repositório remoto e todas as suas referências locais.
+///
+/// Executa `git remote remove <name>`.
+///
+/// # Arguments
+/// * `name` - O nome do remoto a ser removido.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se o remoto não existir.
+pub fn remove_remote(name: &str) -> Result<()> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(anyhow!("O nome do remoto não pode ser vazio."));
+    }
+
+    run_remote_command(&["remove", name])
+        .map_err(|e| e.context(format!("Falha ao remover o remoto '{}'.", name)))
+}
+
+/// Altera a URL de um repositório remoto existente.
+///
+/// Executa `git remote set-url <name> <url>`.
+///
+/// # Arguments
+/// * `name` - O nome do remoto.
+/// * `url` - A nova URL.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se o remoto não existir.
+pub fn set_remote_url(name: &str, url: &str) -> Result<()> {
+    let (name, url) = (name.trim(), url.trim());
+    if name.is_empty() || url.is_empty() {
+        return Err(anyhow!("O nome e a URL do remoto não podem ser vazios."));
+    }
+
+    run_remote_command(&["set-url", name, url])
+        .map_err(|e| e.context(format!("Falha ao alterar a URL do remoto '{}'.", name)))
+}
+
+/// Executa `git remote <args>`. Uma falha é retornada com a mensagem do Git.
+///
+/// Como qualquer alteração de remotos pode afetar o 'origin', o cache de
+/// `get_origin_url` é descartado.
+fn run_remote_command(args: &[&str]) -> Result<()> {
+    let mut full_args = vec!["remote"];
+    full_args.extend_from_slice(args);
+    let result = run_git(&full_args);
+    invalidate_origin_cache();
+    result?;
+    Ok(())
+}
+
+/// A URL do 'origin' e seus componentes, guardados na primeira consulta.
+#[derive(Clone)]
+struct OriginInfo {
+    /// O diretório em que a consulta foi feita; o cache só vale para ele.
+    dir: Option<PathBuf>,
+    /// A URL como configurada no remoto.
+    url: String,
+    /// A URL após as reescritas `insteadOf`, que é a que o Git de fato usa.
+    effective_url: String,
+    /// Os componentes de `effective_url`, ou `None` se ela não estiver em um
+    /// formato reconhecido.
+    parsed: Option<RemoteUrl>,
+}
+
+/// Cache do remoto 'origin' nesta sessão (veja `get_origin_url`).
+static ORIGIN_CACHE: Mutex<Option<OriginInfo>> = Mutex::new(None);
+
+/// Descarta o cache do remoto 'origin'.
+///
+/// As funções deste módulo que alteram remotos já o fazem; chame-a apenas se
+/// o 'origin' for alterado por outro meio.
+pub fn invalidate_origin_cache() {
+    *ORIGIN_CACHE.lock().unwrap() = None;
+}
+
+/// O diretório onde os comandos Git rodam, usado como chave do cache.
+fn current_repo_dir() -> Option<PathBuf> {
+    match working_dir() {
+        Some(dir) => Some(dir.to_path_buf()),
+        None => std::env::current_dir().ok(),
+    }
+}
 
 /// Obtém a URL do repositório remoto 'origin'.
 ///
-/// Executa `git config --get remote.origin.url` para ler a URL configurada.
+/// Executa `git config --get remote.origin.url` e retorna a URL exatamente
+/// como configurada, sem as reescritas `url.<base>.insteadOf`: ela é exibida
+/// ao usuário e pode ser regravada no remoto. Para obter o dono e o nome do
+/// repositório, use `origin_remote` ou `origin_github_repo`, que analisam a
+/// URL reescrita. O resultado fica em cache pelo restante da sessão, pois
+/// fluxos como o `rls` precisam dele várias vezes; alterar os remotos por
+/// este módulo descarta o cache.
 ///
 /// # Returns
 /// `Ok(String)` com a URL, ou `Err` se o comando falhar ou o remoto não estiver configurado.
 pub fn get_origin_url() -> Result<String> {
-    let output = Command::new("git")
-        .arg("config")
-        .arg("--get")
-        .arg("remote.origin.url")
-        .output()
-        .context("Falha ao executar 'git config' para obter a URL do remoto.")?;
-
-    if !output.status.success() {
-        return Err(anyhow!(
+    Ok(load_origin()?.url)
+}
+
+/// Lê o remoto 'origin' do cache ou, na primeira consulta, do Git.
+fn load_origin() -> Result<OriginInfo> {
+    let dir = current_repo_dir();
+    if let Some(info) = ORIGIN_CACHE.lock().unwrap().as_ref().filter(|info| info.dir == dir) {
+        return Ok(info.clone());
+    }
+
+    let output = run_git(&["config", "--get", "remote.origin.url"]).map_err(|_| {
+        anyhow!(
             "Não foi possível encontrar a URL do remoto 'origin'. O repositório está configurado para um remoto?"
-        ));
+        )
+    })?;
+
+    let url = output.stdout.trim().to_string();
+    let effective_url = apply_url_rewrites(&url, &url_rewrites()?);
+    let info = OriginInfo {
+        dir,
+        url,
+        parsed: parse_remote_url(&effective_url).ok(),
+        effective_url,
+    };
+    *ORIGIN_CACHE.lock().unwrap() = Some(info.clone());
+    Ok(info)
+}
+
+/// Lê as reescritas de URL configuradas com `url.<base>.insteadOf`.
+///
+/// Executa `git config --get-regexp '^url\..*\.insteadof$'`, cuja saída tem
+/// uma linha `url.<base>.insteadof <prefixo>` por reescrita.
+///
+/// # Returns
+/// `Ok(Vec<(prefixo, base)>)`, vazio se não houver reescritas, ou `Err` se o
+/// Git falhar.
+pub fn url_rewrites() -> Result<Vec<(String, String)>> {
+    let output = match run_git(&["config", "--get-regexp", r"^url\..*\.insteadof$"]) {
+        Ok(output) => output,
+        // Código 1: nenhuma chave corresponde.
+        Err(e) if e.downcast_ref::<GitCommandError>().is_some_and(|g| g.code == Some(1)) => return Ok(Vec::new()),
+        Err(e) => return Err(anyhow!("Falha ao ler as reescritas de URL (insteadOf): {}", e)),
+    };
+
+    Ok(output
+        .stdout
+        .lines()
+        .filter_map(|line| {
+            let (key, prefix) = line.split_once(' ')?;
+            let base = key.strip_prefix("url.")?.strip_suffix(".insteadof")?;
+            Some((prefix.to_string(), base.to_string()))
+        })
+        .collect())
+}
+
+/// Aplica a uma URL a reescrita `insteadOf` correspondente.
+///
+/// Como no Git, vence o prefixo mais longo que corresponder ao início da URL,
+/// e apenas uma reescrita é aplicada.
+///
+/// # Arguments
+/// * `url` - A URL como configurada no remoto (ex: "corp:dono/repo.git").
+/// * `rewrites` - Os pares `(prefixo, base)` obtidos com `url_rewrites`.
+///
+/// # Returns
+/// A URL efetiva (ex: "https://github.com/dono/repo.git"), ou a própria URL
+/// se nenhum prefixo corresponder.
+pub fn apply_url_rewrites(url: &str, rewrites: &[(String, String)]) -> String {
+    rewrites
+        .iter()
+        .filter(|(prefix, _)| !prefix.is_empty() && url.starts_with(prefix.as_str()))
+        .max_by_key(|(prefix, _)| prefix.len())
+        .map(|(prefix, base)| format!("{}{}", base, &url[prefix.len()..]))
+        .unwrap_or_else(|| url.to_string())
+}
+
+/// Obtém os componentes (host, dono, nome) do remoto 'origin'.
+///
+/// Analisa a URL após as reescritas `insteadOf` (veja `apply_url_rewrites`),
+/// e usa o mesmo cache de `get_origin_url`, então a URL é lida e analisada
+/// uma única vez por sessão.
+///
+/// # Returns
+/// `Ok(RemoteUrl)`, ou `Err` se o 'origin' não estiver configurado ou sua URL
+/// não estiver em um formato reconhecido.
+pub fn origin_remote() -> Result<RemoteUrl> {
+    let info = load_origin()?;
+    match info.parsed {
+        Some(parsed) => Ok(parsed),
+        // Reanalisa apenas para produzir a mensagem de erro detalhada.
+        None => parse_remote_url(&info.effective_url),
+    }
+}
+
+/// Obtém o dono e o nome do repositório GitHub apontado pelo remoto 'origin'.
+///
+/// Equivale a `parse_github_owner_and_repo` aplicada à URL do 'origin' após
+/// as reescritas `insteadOf`.
+///
+/// # Returns
+/// `Ok((String, String))` contendo `(owner, repo)`, ou `Err` se o 'origin'
+/// não estiver configurado ou não apontar para o GitHub.
+pub fn origin_github_repo() -> Result<(String, String)> {
+    parse_github_owner_and_repo(&load_origin()?.effective_url)
+}
+
+/// Obtém a branch padrão do remoto 'origin', conforme registrada localmente.
+///
+/// Executa `git symbolic-ref --short refs/remotes/origin/HEAD`, que é
+/// configurado pelo `git clone` (ou por `git remote set-head origin -a`).
+/// Não realiza nenhuma operação de rede.
+///
+/// # Returns
+/// `Ok(Some(String))` com o nome da branch (ex: "main", sem o prefixo
+/// "origin/"), ou `Ok(None)` se a referência não estiver configurada.
+pub fn default_branch() -> Result<Option<String>> {
+    let output = match run_git(&["symbolic-ref", "--short", "refs/remotes/origin/HEAD"]) {
+        Ok(output) => output,
+        Err(e) if e.is::<GitCommandError>() => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    let reference = output.stdout.trim().to_string();
+    let branch = reference.strip_prefix("origin/").unwrap_or(&reference).to_string();
+    Ok(if branch.is_empty() { None } else { Some(branch) })
+}
+
+/// Representa os componentes de uma URL de repositório remoto.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteUrl {
+    /// O host do servidor Git (ex: "github.com", "gitlab.empresa.com").
+    pub host: String,
+    /// O dono do repositório. No GitLab, pode conter subgrupos (ex: "grupo/sub").
+    pub owner: String,
+    /// O nome do repositório, sem o sufixo `.git`.
+    pub repo: String,
+}
+
+impl RemoteUrl {
+    /// Retorna o caminho completo do projeto no servidor (ex: "owner/repo").
+    pub fn full_path(&self) -> String {
+        format!("{}/{}", self.owner, self.repo)
+    }
+}
+
+/// Analisa uma URL de repositório Git em seus componentes (host, dono, nome).
+///
+/// Formatos suportados:
+/// - HTTPS: `https://host/owner/repo.git` (com ou sem `.git`, com ou sem usuário)
+/// - SSH (estilo scp): `git@host:owner/repo.git`
+/// - SSH (URL):        `ssh://git@host[:porta]/owner/repo.git`
+///
+/// # Arguments
+/// * `url` - A URL do Git a ser analisada.
+///
+/// # Returns
+/// `Ok(RemoteUrl)` com os componentes, ou `Err` se a URL não corresponder a
+/// um formato reconhecido.
+pub fn parse_remote_url(url: &str) -> Result<RemoteUrl> {
+    let url = url.trim();
+
+    // Separamos a URL em (host, caminho) de acordo com o formato.
+    let (host, path) = if let Some(rest) = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .or_else(|| url.strip_prefix("ssh://"))
+    {
+        let (authority, path) = rest
+            .split_once('/')
+            .ok_or_else(|| anyhow!("URL de remoto sem caminho de repositório: '{}'", url))?;
+        // Removemos credenciais (`usuario@`) e a porta (`:2222`) do host.
+        let host = authority.rsplit('@').next().unwrap_or(authority);
+        let host = host.split(':').next().unwrap_or(host);
+        (host, path)
+    } else if let Some((user_host, path)) = url.split_once(':') {
+        // Estilo scp: `usuario@host:caminho`. Exigimos o `@` para não confundir
+        // com caminhos locais do Windows (ex: `C:\repos\projeto`).
+        match user_host.split_once('@') {
+            Some((_, host)) => (host, path),
+            None => return Err(anyhow!("Formato de URL de remoto não reconhecido: '{}'", url)),
+        }
+    } else {
+        return Err(anyhow!("Formato de URL de remoto não reconhecido: '{}'", url));
+    };
+
+    let path = path.trim_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    match path.rsplit_once('/') {
+        Some((owner, repo)) if !host.is_empty() && !owner.is_empty() && !repo.is_empty() => Ok(RemoteUrl {
+            host: host.to_lowercase(),
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+        }),
+        _ => Err(anyhow!(
+            "Não foi possível extrair o dono e o nome do repositório da URL: '{}'",
+            url
+        )),
     }
+}
+
+/// O protocolo de transporte usado por uma URL de repositório.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlScheme {
+    /// `https://` ou `http://`: autenticação por usuário e token/senha.
+    Https,
+    /// `ssh://` ou o formato scp `git@host:caminho`: autenticação por chave SSH.
+    Ssh,
+    /// Caminhos locais, `file://`, `git://` e outros formatos.
+    Other,
+}
 
-    let url = String::from_utf8_lossy(&output.stdout).trim().to_string();
-    Ok(url)
+/// Identifica o protocolo de uma URL de repositório.
+///
+/// # Arguments
+/// * `url` - A URL do Git a ser analisada.
+pub fn url_scheme(url: &str) -> UrlScheme {
+    let url = url.trim();
+    if url.starts_with("https://") || url.starts_with("http://") {
+        UrlScheme::Https
+    } else if url.starts_with("ssh://") {
+        UrlScheme::Ssh
+    } else if url.contains("://") {
+        UrlScheme::Other
+    } else {
+        // Formato scp (`usuario@host:caminho`), o mesmo critério de `parse_remote_url`.
+        match url.split_once(':') {
+            Some((user_host, _)) if user_host.contains('@') => UrlScheme::Ssh,
+            _ => UrlScheme::Other,
+        }
+    }
 }
 
 /// Analisa uma URL de repositório Git e extrai o proprietário e o nome do repositório.
@@ -49,30 +440,15 @@ pub fn get_origin_url() -> Result<String> {
 ///
 /// # Returns
 /// `Ok((String, String))` contendo `(owner, repo)`, ou `Err` se a URL não
-/// corresponder a um formato reconhecido.
+/// corresponder a um formato reconhecido ou não apontar para o GitHub.
 pub fn parse_github_owner_and_repo(url: &str) -> Result<(String, String)> {
-    // Tenta analisar o formato SSH primeiro.
-    if let Some(ssh_path) = url.strip_prefix("git@github.com:") {
-        if let Some(path) = ssh_path.strip_suffix(".git") {
-            if let Some((owner, repo)) = path.split_once('/') {
-                return Ok((owner.to_string(), repo.to_string()));
-            }
-        }
+    match parse_remote_url(url) {
+        Ok(remote) if remote.host == "github.com" => Ok((remote.owner, remote.repo)),
+        // Se a URL não for do GitHub, retornamos um erro claro.
+        _ => Err(anyhow!(
+            "Formato de URL do GitHub não reconhecido: '{}'.\n\
+             Formatos esperados: 'https://github.com/owner/repo.git' ou 'git@github.com:owner/repo.git'",
+            url
+        )),
     }
-
-    // Se não for SSH, tenta analisar o formato HTTPS.
-    if let Some(https_path) = url.strip_prefix("https://github.com/") {
-        if let Some(path) = https_path.strip_suffix(".git") {
-            if let Some((owner, repo)) = path.split_once('/') {
-                return Ok((owner.to_string(), repo.to_string()));
-            }
-        }
-    }
-
-    // Se nenhum dos formatos corresponder, retornamos um erro claro.
-    Err(anyhow!(
-        "Formato de URL do GitHub não reconhecido: '{}'.\n\
-         Formatos esperados: 'https://github.com/owner/repo.git' ou 'git@github.com:owner/repo.git'",
-        url
-    ))
 }
\ No newline at end of file
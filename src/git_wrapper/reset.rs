@@ -0,0 +1,115 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo de Reset do Git
+//
+// Este módulo implementa a operação inversa do `git add`: remover arquivos do
+// stage (index) sem tocar no conteúdo do diretório de trabalho. Também desfaz
+// o último commit, devolvendo suas alterações ao stage ou ao diretório de
+// trabalho.
+//
+// Um cuidado especial é necessário em repositórios recém-criados, que ainda
+// não possuem nenhum commit. Nesse estado, `HEAD` não aponta para nada e o
+// comando `git reset HEAD -- <arquivos>` falha com "ambiguous argument 'HEAD'".
+// Para esses casos, recorremos a comandos equivalentes que operam apenas
+// sobre o index.
+// ==============================================================================
+
+use super::{head_exists, run_git, GitCommandError};
+use anyhow::{anyhow, Result};
+
+/// Remove os caminhos fornecidos do stage, mantendo as alterações no disco.
+///
+/// Executa `git reset HEAD -- <caminhos>`. Se o repositório ainda não tiver
+/// nenhum commit, executa `git rm --cached -r -- <caminhos>`, que tem o mesmo
+/// efeito prático de "desfazer o `git add`".
+///
+/// # Arguments
+/// * `paths` - Os caminhos dos arquivos a serem removidos do stage.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se a lista estiver vazia ou o Git falhar.
+pub fn reset_paths(paths: &[String]) -> Result<()> {
+    if paths.is_empty() {
+        return Err(anyhow!("Nenhum arquivo foi informado para remover do stage."));
+    }
+
+    let mut args = if head_exists()? {
+        vec!["reset", "-q", "HEAD", "--"]
+    } else {
+        // Sem commits, não há uma árvore em `HEAD` para restaurar. Removemos
+        // as entradas do index diretamente, sem apagar os arquivos do disco.
+        vec!["rm", "--cached", "-r", "-q", "--ignore-unmatch", "--"]
+    };
+    args.extend(paths.iter().map(String::as_str));
+
+    run_git(&args).map_err(|e| anyhow!("Falha ao remover os arquivos do stage: {}", e))?;
+
+    Ok(())
+}
+
+/// Remove todas as alterações do stage, mantendo-as no diretório de trabalho.
+///
+/// Executa `git reset HEAD`. Em um repositório sem commits, executa
+/// `git read-tree --empty`, que esvazia o index sem tocar nos arquivos.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se o comando do Git falhar.
+pub fn reset_all() -> Result<()> {
+    let args: &[&str] = if head_exists()? {
+        &["reset", "-q", "HEAD"]
+    } else {
+        &["read-tree", "--empty"]
+    };
+
+    run_git(args).map_err(|e| anyhow!("Falha ao limpar o stage: {}", e))?;
+
+    Ok(())
+}
+
+/// Desfaz o último commit, sem perder suas alterações.
+///
+/// Executa `git reset --soft HEAD~1` (as alterações voltam ao stage) ou
+/// `git reset --mixed HEAD~1` (as alterações voltam ao diretório de trabalho,
+/// fora do stage). Se o último commit for o primeiro do repositório, a branch
+/// volta a não ter commits (`git update-ref -d HEAD`) e, no modo `--mixed`, o
+/// index é esvaziado.
+///
+/// # Arguments
+/// * `keep_changes` - Se `true`, usa `--soft`, mantendo as alterações no
+///   stage; se `false`, usa `--mixed`.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se não houver commits ou o Git falhar.
+pub fn undo_last_commit(keep_changes: bool) -> Result<()> {
+    if !head_exists()? {
+        return Err(anyhow!("A branch atual ainda não tem commits para desfazer."));
+    }
+
+    let has_parent = match run_git(&["rev-parse", "--verify", "-q", "HEAD~1"]) {
+        Ok(_) => true,
+        Err(e) if e.is::<GitCommandError>() => false,
+        Err(e) => return Err(e),
+    };
+
+    let result = if has_parent {
+        let mode = if keep_changes { "--soft" } else { "--mixed" };
+        run_git(&["reset", "-q", mode, "HEAD~1"]).map(|_| ())
+    } else {
+        // O commit raiz não tem um pai para onde voltar: removemos a referência
+        // da branch, que volta ao estado de "sem commits".
+        run_git(&["update-ref", "-d", "HEAD"]).and_then(|_| {
+            if keep_changes {
+                Ok(())
+            } else {
+                run_git(&["read-tree", "--empty"]).map(|_| ())
+            }
+        })
+    };
+
+    result.map_err(|e| anyhow!("Falha ao desfazer o último commit: {}", e))
+}
\ No newline at end of file
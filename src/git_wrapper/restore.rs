@@ -0,0 +1,43 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo de Restauração de Arquivos do Git
+//
+// Descarta as alterações feitas em arquivos do diretório de trabalho,
+// devolvendo-os ao conteúdo que está no stage (ou no último commit, se não
+// houver nada no stage para eles). As alterações já no stage são mantidas.
+//
+// ATENÇÃO: esta é uma operação destrutiva. O Git não guarda cópia das
+// alterações descartadas, então a UI deve sempre pedir confirmação antes.
+// ==============================================================================
+
+use super::{git_version_at_least, run_git, RESTORE_SWITCH_VERSION};
+use anyhow::{anyhow, Result};
+
+/// Descarta as alterações fora do stage dos caminhos fornecidos.
+///
+/// Executa `git restore -- <caminhos>`. Versões do Git anteriores à 2.23 não
+/// têm o `restore`; nelas, executa o equivalente `git checkout -- <caminhos>`
+/// (veja `git_version`).
+///
+/// # Arguments
+/// * `paths` - Os caminhos dos arquivos a restaurar.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se a lista estiver vazia ou o Git falhar.
+pub fn restore_paths(paths: &[String]) -> Result<()> {
+    if paths.is_empty() {
+        return Err(anyhow!("Nenhum arquivo foi informado para restaurar."));
+    }
+
+    let command = if git_version_at_least(RESTORE_SWITCH_VERSION) { "restore" } else { "checkout" };
+    let mut args = vec![command, "--"];
+    args.extend(paths.iter().map(String::as_str));
+
+    run_git(&args).map_err(|e| anyhow!("Falha ao descartar as alterações: {}", e))?;
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,142 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo de Stash do Git
+//
+// Guarda temporariamente as alterações não commitadas, limpando o diretório
+// de trabalho. É a saída usual quando alterações locais impedem uma operação,
+// como mudar de branch. Os stashes podem depois ser listados, inspecionados e
+// reaplicados.
+// ==============================================================================
+
+use super::{conflicted_files, output_has_conflicts, run_git, FIELD_SEPARATOR, GitCommandError};
+use anyhow::{anyhow, Result};
+
+/// Um stash guardado no repositório.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StashEntry {
+    /// A posição na pilha: 0 é o stash mais recente (`stash@{0}`).
+    pub index: usize,
+    /// A descrição do stash (ex: "On main: antes de mudar de branch").
+    pub description: String,
+    /// Há quanto tempo o stash foi criado (ex: "2 hours ago").
+    pub created: String,
+}
+
+/// Guarda as alterações não commitadas (no stage e fora dele) em um stash.
+///
+/// Executa `git stash push -m <message>`. Arquivos não rastreados não são
+/// incluídos, como no comportamento padrão do Git.
+///
+/// # Arguments
+/// * `message` - A descrição do stash, exibida em `git stash list`.
+///
+/// # Returns
+/// `Ok(true)` se um stash foi criado, `Ok(false)` se não havia alterações a
+/// guardar, ou `Err` se o Git falhar.
+pub fn stash_push(message: &str) -> Result<bool> {
+    let output = run_git(&["stash", "push", "-m", message.trim()])
+        .map_err(|e| anyhow!("Falha ao guardar as alterações no stash: {}", e))?;
+
+    Ok(!output.stdout.contains("No local changes to save"))
+}
+
+/// Lista os stashes do repositório, do mais recente para o mais antigo.
+///
+/// Executa `git stash list` com um `--format` separado por 0x1F.
+///
+/// # Returns
+/// `Ok(Vec<StashEntry>)`, vazio se não houver stashes, ou `Err` se o Git falhar.
+pub fn list_stashes() -> Result<Vec<StashEntry>> {
+    let format = format!("--format=%gd{sep}%s{sep}%cr", sep = FIELD_SEPARATOR);
+    let output = run_git(&["stash", "list", &format])?;
+    Ok(output
+        .stdout
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split(FIELD_SEPARATOR);
+            let reference = fields.next()?;
+            let index = reference.strip_prefix("stash@{")?.strip_suffix('}')?.parse().ok()?;
+            Some(StashEntry {
+                index,
+                description: fields.next().unwrap_or_default().to_string(),
+                created: fields.next().unwrap_or_default().to_string(),
+            })
+        })
+        .collect())
+}
+
+/// Obtém o diff de um stash, para que o usuário o revise antes de aplicá-lo.
+///
+/// Executa `git stash show -p stash@{<index>}`.
+///
+/// # Arguments
+/// * `index` - A posição do stash na pilha (veja `StashEntry::index`).
+///
+/// # Returns
+/// `Ok(String)` com o diff no formato unificado, ou `Err` se o stash não existir.
+pub fn stash_show(index: usize) -> Result<String> {
+    let output = run_git(&["stash", "show", "-p", &stash_ref(index)])
+        .map_err(|e| anyhow!("Falha ao exibir o stash {}: {}", stash_ref(index), e))?;
+    Ok(output.stdout)
+}
+
+/// Reaplica um stash no diretório de trabalho, mantendo-o na pilha.
+///
+/// Executa `git stash apply stash@{<index>}`.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` (veja `apply_or_pop`).
+pub fn stash_apply(index: usize) -> Result<()> {
+    apply_or_pop("apply", index)
+}
+
+/// Reaplica um stash e o remove da pilha.
+///
+/// Executa `git stash pop stash@{<index>}`. Se houver conflitos, o Git mantém
+/// o stash na pilha, e ele precisa ser removido manualmente depois.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` (veja `apply_or_pop`).
+pub fn stash_pop(index: usize) -> Result<()> {
+    apply_or_pop("pop", index)
+}
+
+/// Executa `git stash apply` ou `git stash pop` e descreve as falhas.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` com os arquivos em conflito (quando
+/// as alterações do stash conflitam com as atuais) ou com a mensagem do Git.
+fn apply_or_pop(command: &str, index: usize) -> Result<()> {
+    let reference = stash_ref(index);
+    let error = match run_git(&["stash", command, &reference]) {
+        Ok(_) => return Ok(()),
+        Err(e) => e,
+    };
+
+    let is_conflict = error
+        .downcast_ref::<GitCommandError>()
+        .is_some_and(|failure| output_has_conflicts(&failure.combined_output()));
+    if is_conflict {
+        let files = conflicted_files().unwrap_or_default();
+        return Err(anyhow!(
+            "As alterações de {} conflitam com as atuais{}.\n\
+             Resolva os conflitos e adicione os arquivos ao stage. O stash foi mantido \
+             na pilha; remova-o com 'git stash drop {}' quando não precisar mais dele.",
+            reference,
+            if files.is_empty() { String::new() } else { format!(" em: {}", files.join(", ")) },
+            reference
+        ));
+    }
+
+    Err(anyhow!("Falha ao aplicar {}: {}", reference, error))
+}
+
+/// A referência de um stash pela sua posição (ex: `stash@{0}`).
+fn stash_ref(index: usize) -> String {
+    format!("stash@{{{}}}", index)
+}
\ No newline at end of file
@@ -0,0 +1,117 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo de Estatísticas do Repositório
+//
+// Resume a atividade do projeto: commits por autor (`git shortlog -sn`) e o
+// total de linhas adicionadas e removidas (`git log --numstat`). A soma do
+// `--numstat` é delegada ao módulo nativo C++, já que a saída de um histórico
+// longo pode ter centenas de milhares de linhas.
+//
+// Em históricos muito grandes, a análise é limitada aos commits mais recentes
+// (veja `DEFAULT_COMMIT_LIMIT`), para que o comando responda rapidamente.
+// ==============================================================================
+
+use super::{head_exists, run_git};
+use crate::native_bindings;
+use anyhow::{anyhow, Result};
+
+/// Número padrão de commits recentes analisados por `repo_stats`.
+pub const DEFAULT_COMMIT_LIMIT: usize = 5000;
+
+/// Os commits de um autor no período analisado.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorStats {
+    /// O nome do autor, canonicalizado pelo `.mailmap` (o `git shortlog`
+    /// sempre o aplica): commits feitos com emails diferentes são somados.
+    pub name: String,
+    /// O número de commits do autor.
+    pub commits: u64,
+}
+
+/// O resumo da atividade do repositório.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RepoStats {
+    /// O número total de commits da branch atual.
+    pub total_commits: u64,
+    /// O número de commits efetivamente analisados (no máximo o limite).
+    pub analyzed_commits: u64,
+    /// Os autores, do que tem mais commits para o que tem menos.
+    pub authors: Vec<AuthorStats>,
+    /// Quantas vezes arquivos foram alterados, somando todos os commits.
+    pub files_changed: u64,
+    /// O total de linhas adicionadas.
+    pub lines_added: u64,
+    /// O total de linhas removidas.
+    pub lines_removed: u64,
+}
+
+impl RepoStats {
+    /// Indica se o histórico é maior que o limite e nem todos os commits foram analisados.
+    pub fn is_truncated(&self) -> bool {
+        self.analyzed_commits < self.total_commits
+    }
+}
+
+/// Calcula as estatísticas dos commits mais recentes da branch atual.
+///
+/// Executa `git rev-list --count HEAD`, `git shortlog -sn` e
+/// `git log --numstat`, os dois últimos limitados a `limit` commits.
+///
+/// # Arguments
+/// * `limit` - O número máximo de commits analisados (tipicamente
+///   `DEFAULT_COMMIT_LIMIT`).
+///
+/// # Returns
+/// `Ok(RepoStats)` com o resumo (vazio se a branch ainda não tiver commits),
+/// ou `Err` se algum comando Git falhar.
+pub fn repo_stats(limit: usize) -> Result<RepoStats> {
+    if !head_exists()? {
+        return Ok(RepoStats::default());
+    }
+    if limit == 0 {
+        return Err(anyhow!("O limite de commits deve ser maior que zero."));
+    }
+
+    let total_commits = run_git(&["rev-list", "--count", "HEAD"])?
+        .stdout
+        .trim()
+        .parse::<u64>()
+        .map_err(|e| anyhow!("Não foi possível contar os commits: {}", e))?;
+
+    let max_count = format!("--max-count={}", limit);
+    // O `HEAD` é obrigatório: sem uma revisão, o `shortlog` lê o log da
+    // entrada padrão quando ela não é um terminal.
+    let shortlog = run_git(&["shortlog", "-sn", &max_count, "HEAD"])?;
+    let numstat = run_git(&["log", "--numstat", "--format=", &max_count, "HEAD"])?;
+    let totals = native_bindings::numstat_totals(&numstat.stdout)?;
+
+    Ok(RepoStats {
+        total_commits,
+        analyzed_commits: total_commits.min(limit as u64),
+        authors: parse_shortlog(&shortlog.stdout),
+        files_changed: totals.files_changed,
+        lines_added: totals.lines_added,
+        lines_removed: totals.lines_removed,
+    })
+}
+
+/// Analisa a saída de `git shortlog -sn`, no formato `<commits>\t<nome>`.
+///
+/// Linhas fora do formato são ignoradas.
+fn parse_shortlog(output: &str) -> Vec<AuthorStats> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (count, name) = line.trim_start().split_once('\t')?;
+            Some(AuthorStats {
+                name: name.trim().to_string(),
+                commits: count.trim().parse().ok()?,
+            })
+        })
+        .collect()
+}
\ No newline at end of file
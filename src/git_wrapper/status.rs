@@ -11,8 +11,16 @@
 // repositório Git.
 // ==============================================================================
 
+use super::{git_command, head_exists, run_git, FIELD_SEPARATOR, GitCommandError, LoggedCommand};
 use anyhow::{anyhow, Context, Result};
-use std::process::Command;
+use std::io::{BufRead, BufReader, Read};
+use std::process::Stdio;
+
+/// Quantos arquivos a tela de status mantém em memória e exibe por padrão.
+///
+/// Em diretórios de trabalho com dezenas de milhares de alterações, listar
+/// todas tornaria a tela lenta e inútil; o restante é resumido como "+N".
+pub const DEFAULT_MAX_FILES: usize = 1000;
 
 /// Representa o tipo de mudança detectada em um arquivo.
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -27,26 +35,193 @@ pub enum ChangeType {
     Untracked,
 }
 
+/// O que mudou em um submódulo, segundo o campo `<sub>` do `git status`.
+///
+/// Não confundir com `submodule::SubmoduleState`, que descreve o estado de
+/// um submódulo listado por `git submodule status`.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct SubmoduleStatusFlags {
+    /// O submódulo aponta para um commit diferente do registrado.
+    pub commit_changed: bool,
+    /// O submódulo tem alterações em arquivos rastreados.
+    pub has_tracked_changes: bool,
+    /// O submódulo tem arquivos não rastreados.
+    pub has_untracked_changes: bool,
+}
+
 /// Representa o status de um único arquivo no repositório.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
 pub struct FileStatus {
+    /// O caminho atual do arquivo. Em renomeações e cópias, é o caminho de destino.
     pub path: String,
+    /// O caminho de origem, presente apenas em renomeações e cópias.
+    pub old_path: Option<String>,
     pub staged_status: Option<ChangeType>,
     pub unstaged_status: Option<ChangeType>,
+    /// A similaridade (0 a 100) entre origem e destino, presente apenas em
+    /// renomeações e cópias.
+    pub rename_score: Option<u8>,
+    /// O estado do submódulo, se o caminho for um.
+    pub submodule: Option<SubmoduleStatusFlags>,
+}
+
+impl FileStatus {
+    /// Retorna o caminho formatado para exibição, indicando a origem de
+    /// arquivos renomeados (ex: "novo.rs (renomeado de velho.rs, 87% similar)")
+    /// e o que mudou em submódulos.
+    pub fn display_path(&self) -> String {
+        let mut display = match (&self.old_path, self.rename_score) {
+            (Some(old_path), Some(score)) if score < 100 => {
+                format!("{} (renomeado de {}, {}% similar)", self.path, old_path, score)
+            }
+            (Some(old_path), _) => format!("{} (renomeado de {})", self.path, old_path),
+            (None, _) => self.path.clone(),
+        };
+        if let Some(submodule) = &self.submodule {
+            let mut changes = Vec::new();
+            if submodule.commit_changed {
+                changes.push("novo commit");
+            }
+            if submodule.has_tracked_changes {
+                changes.push("arquivos modificados");
+            }
+            if submodule.has_untracked_changes {
+                changes.push("arquivos não rastreados");
+            }
+            if !changes.is_empty() {
+                display.push_str(&format!(" (submódulo: {})", changes.join(", ")));
+            }
+        }
+        display
+    }
+
+    /// Retorna todos os caminhos afetados pela alteração.
+    ///
+    /// Para desfazer uma renomeação no stage, é preciso restaurar tanto o
+    /// destino quanto a origem; para as demais alterações, há um único caminho.
+    pub fn affected_paths(&self) -> Vec<String> {
+        let mut paths = vec![self.path.clone()];
+        paths.extend(self.old_path.clone());
+        paths
+    }
+}
+
+/// A branch atual e sua sincronia com o upstream, extraídas da linha de
+/// branch do status (ex: `main...origin/main [ahead 2, behind 1]`).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BranchTracking {
+    /// O nome da branch atual (ex: "main"), ou "HEAD (no branch)" com o
+    /// HEAD destacado.
+    pub branch: String,
+    /// A branch remota rastreada (ex: "origin/main"), se configurada.
+    pub upstream: Option<String>,
+    /// Commits locais ainda não enviados ao upstream. `None` se a branch não
+    /// estiver à frente (ou não tiver upstream).
+    pub ahead: Option<u32>,
+    /// Commits do upstream ainda não integrados localmente. `None` se a
+    /// branch não estiver atrás (ou não tiver upstream).
+    pub behind: Option<u32>,
+    /// `true` se o upstream configurado não existe mais no remoto (ex: a
+    /// branch foi apagada após o merge de um PR).
+    pub gone: bool,
 }
 
 /// Contém um resumo completo do status do repositório Git.
 /// Esta estrutura é o resultado da análise da saída de `git status`.
 #[derive(Debug, Default)]
 pub struct GitStatus {
+    /// A linha de branch, como o Git a escreveu (sem o prefixo "## ").
     pub branch_info: String,
+    /// A mesma linha de branch, já analisada.
+    pub tracking: BranchTracking,
     pub files: Vec<FileStatus>,
+    /// Quantos arquivos alterados ficaram de fora de `files` por causa do
+    /// limite de `get_status_limited`. É sempre zero em `get_status`.
+    pub omitted: usize,
+}
+
+impl GitStatus {
+    /// Retorna quantos commits a branch está à frente e atrás do upstream.
+    ///
+    /// Um atalho para `tracking.ahead` e `tracking.behind`: sem upstream (ou
+    /// com a branch em dia), ambos são zero.
+    ///
+    /// # Returns
+    /// Uma tupla `(à frente, atrás)`.
+    pub fn ahead_behind(&self) -> (u32, u32) {
+        (
+            self.tracking.ahead.unwrap_or(0),
+            self.tracking.behind.unwrap_or(0),
+        )
+    }
+}
+
+/// Informações sobre o commit mais recente da branch atual.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LastCommit {
+    /// O hash abreviado do commit.
+    pub hash: String,
+    /// A primeira linha da mensagem de commit.
+    pub subject: String,
+    /// A data do commit em formato relativo (ex: "2 hours ago").
+    pub relative_date: String,
+}
+
+/// Um resumo dos "sinais vitais" do repositório.
+#[derive(Debug, Clone, Default)]
+pub struct RepoSummary {
+    /// O número de commits alcançáveis a partir de `HEAD`.
+    pub commit_count: u64,
+    /// O último commit, ou `None` em um repositório sem commits.
+    pub last_commit: Option<LastCommit>,
+    /// `true` se houver qualquer alteração (no stage, fora dele ou não rastreada).
+    pub is_dirty: bool,
+}
+
+/// Obtém um resumo do repositório: número de commits, último commit e se
+/// há alterações pendentes.
+///
+/// Executa `git rev-list --count HEAD` e `git log -1`. Em um repositório
+/// recém-criado, sem commits, retorna contagem zero e `last_commit` vazio em
+/// vez de um erro.
+///
+/// # Returns
+/// `Ok(RepoSummary)`, ou `Err` se o diretório não for um repositório Git.
+pub fn repo_summary() -> Result<RepoSummary> {
+    let is_dirty = !get_status()?.files.is_empty();
+    if !head_exists()? {
+        return Ok(RepoSummary {
+            is_dirty,
+            ..Default::default()
+        });
+    }
+
+    let count = run_git(&["rev-list", "--count", "HEAD"])?;
+    let commit_count = count.stdout.trim().parse().unwrap_or(0);
+
+    let format = format!("--format=%h{0}%s{0}%cr", FIELD_SEPARATOR);
+    let log = run_git(&["log", "-1", &format])?;
+    let mut fields = log.stdout.trim_end().splitn(3, FIELD_SEPARATOR);
+    let last_commit = match (fields.next(), fields.next(), fields.next()) {
+        (Some(hash), Some(subject), Some(date)) => Some(LastCommit {
+            hash: hash.to_string(),
+            subject: subject.to_string(),
+            relative_date: date.to_string(),
+        }),
+        _ => None,
+    };
+
+    Ok(RepoSummary {
+        commit_count,
+        last_commit,
+        is_dirty,
+    })
 }
 
 /// Executa `git status` e analisa sua saída para um formato estruturado.
 ///
 /// Esta é a função pública do módulo. Ela invoca o Git com flags específicas
-/// para uma saída estável e legível por máquina (`--porcelain=v1 --branch`)
+/// para uma saída estável e legível por máquina (`--porcelain=v2 --branch`)
 /// e, em seguida, chama um analisador interno para construir o objeto `GitStatus`.
 ///
 /// # Returns
@@ -54,84 +229,335 @@ pub struct GitStatus {
 /// `anyhow::Error` se o comando falhar (ex: não é um repositório Git) ou se a
 /// análise da saída falhar.
 pub fn get_status() -> Result<GitStatus> {
+    read_status(None)
+}
+
+/// Obtém o status do repositório guardando no máximo `max_files` arquivos.
+///
+/// Os demais são apenas contados em `GitStatus::omitted`, o que mantém a
+/// tela de status responsiva em diretórios de trabalho enormes.
+///
+/// # Arguments
+/// * `max_files` - Quantos arquivos guardar (veja `DEFAULT_MAX_FILES`).
+pub fn get_status_limited(max_files: usize) -> Result<GitStatus> {
+    read_status(Some(max_files))
+}
+
+/// Executa o `git status` e analisa sua saída à medida que ela chega.
+///
+/// A saída é lida linha a linha de um pipe, em vez de ser acumulada em uma
+/// única `String`: com muitos arquivos, ela pode ter vários megabytes.
+fn read_status(max_files: Option<usize>) -> Result<GitStatus> {
     // Executa o comando `git status` com flags para saída de máquina.
-    // --porcelain=v1: Formato estável e fácil de analisar.
+    // --porcelain=v2: Formato estável, com renomeações sem ambiguidade e o
+    // estado dos submódulos.
     // --branch: Inclui informações sobre a branch atual na saída.
-    let output = Command::new("git")
-        .arg("status")
-        .arg("--porcelain=v1")
-        .arg("--branch")
-        .output()
+    // core.quotepath=false: Mantém caracteres não-ASCII (ex: acentos) como
+    // UTF-8, em vez de escapá-los em octal. Caminhos com aspas ou caracteres
+    // de controle continuam entre aspas e são decodificados por `take_path`.
+    let mut child = git_command()
+        .args(["-c", "core.quotepath=false", "status", "--porcelain=v2", "--branch"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .logged()
+        .spawn()
         .context("Falha ao executar o comando 'git status'. O Git está instalado e no PATH?")?;
 
-    // Verifica se o comando foi executado com sucesso.
-    if !output.status.success() {
-        // Se o comando falhou, o erro geralmente está em `stderr`.
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(anyhow!(
-            "O comando 'git status' falhou: {}",
-            stderr.trim()
-        ));
+    let stdout = child.stdout.take().expect("stdout configurado como pipe");
+    let parsed = parse_porcelain_stream(BufReader::new(stdout), max_files);
+
+    // O `stderr` do status é curto (uma mensagem de erro), então é seguro
+    // lê-lo só depois do `stdout`.
+    let mut stderr = String::new();
+    if let Some(mut pipe) = child.stderr.take() {
+        let _ = pipe.read_to_string(&mut stderr);
+    }
+    let exit = child.wait().context("Falha ao aguardar o término do 'git status'.")?;
+    if !exit.success() {
+        let error = GitCommandError {
+            code: exit.code(),
+            stdout: String::new(),
+            stderr,
+        };
+        return Err(anyhow!("O comando 'git status' falhou: {}", error));
     }
 
-    // Converte a saída `stdout` para uma string para análise.
-    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-    parse_porcelain_output(&stdout)
+    parsed
 }
 
-/// Analisa a saída de `git status --porcelain=v1 --branch`.
+/// Analisa a saída completa do status, já em memória (usada pelos testes).
+#[cfg(test)]
+fn parse_porcelain_output(output: &str) -> Result<GitStatus> {
+    parse_porcelain_stream(output.as_bytes(), None)
+}
+
+/// Analisa, linha a linha, a saída de `git status --porcelain=v2 --branch`.
 ///
 /// A saída tem o seguinte formato:
-/// 1. Uma linha de cabeçalho de branch: `## <branch_name>...<upstream> [<ahead/behind>]`
-/// 2. Linhas de status de arquivo: `XY <path>`
-///    - X: Status do "index" (staged)
-///    - Y: Status da "working tree" (unstaged)
-fn parse_porcelain_output(output: &str) -> Result<GitStatus> {
+/// 1. Cabeçalhos de branch, iniciados por `#`:
+///    - `# branch.oid <commit>` (ou `(initial)` antes do primeiro commit)
+///    - `# branch.head <branch>` (ou `(detached)` com o HEAD destacado)
+///    - `# branch.upstream <upstream>`, se houver upstream
+///    - `# branch.ab +<à frente> -<atrás>`, ausente se o upstream não existir mais
+/// 2. Um registro por arquivo, identificado pelo primeiro campo:
+///    - `1 XY sub mH mI mW hH hI <path>`: alteração comum
+///    - `2 XY sub mH mI mW hH hI Xscore <path>\t<origem>`: renomeação ou cópia
+///    - `u XY sub m1 m2 m3 mW h1 h2 h3 <path>`: conflito de merge
+///    - `? <path>`: arquivo não rastreado
+///
+///    Em `XY`, X é o status do "index" (staged) e Y, o da "working tree"
+///    (unstaged); `.` indica nenhuma mudança.
+///
+/// Caminhos com caracteres especiais vêm entre aspas, no estilo de uma
+/// string C (ex: `"caf\303\251.txt"`).
+///
+/// # Arguments
+/// * `reader` - A saída do Git.
+/// * `max_files` - Quantos arquivos guardar; os demais são apenas contados
+///   em `omitted`. `None` guarda todos.
+fn parse_porcelain_stream<R: BufRead>(mut reader: R, max_files: Option<usize>) -> Result<GitStatus> {
     let mut status = GitStatus::default();
-    let mut lines = output.lines();
+    let mut headers = BranchHeaders::default();
+    let mut buffer = Vec::new();
 
-    // A primeira linha é sempre a informação da branch.
-    if let Some(branch_line) = lines.next() {
-        status.branch_info = branch_line.strip_prefix("## ").unwrap_or(branch_line).to_string();
+    loop {
+        buffer.clear();
+        if reader.read_until(b'\n', &mut buffer).context("Falha ao ler a saída do 'git status'.")? == 0 {
+            break;
+        }
+        // Caminhos que não são UTF-8 válido não devem interromper a leitura.
+        let line = String::from_utf8_lossy(&buffer);
+        let line = line.trim_end_matches(['\n', '\r']);
+
+        if let Some(header) = line.strip_prefix("# ") {
+            headers.parse(header);
+            continue;
+        }
+
+        if max_files.is_some_and(|max| status.files.len() >= max) {
+            // Acima do limite, só contamos: nem vale a pena analisar a linha.
+            if !line.is_empty() {
+                status.omitted += 1;
+            }
+            continue;
+        }
+        status.files.extend(parse_status_line(line));
+    }
+
+    status.branch_info = headers.branch_info();
+    status.tracking = headers.tracking;
+    // Sem nenhuma saída, o status fica vazio, o que é um estado válido.
+    Ok(status)
+}
+
+/// Os cabeçalhos `# branch.*` do status v2, acumulados durante a leitura.
+#[derive(Debug, Default)]
+struct BranchHeaders {
+    tracking: BranchTracking,
+    /// `true` antes do primeiro commit (`# branch.oid (initial)`).
+    initial: bool,
+    /// `true` se houve uma linha `# branch.ab`. Com upstream, a ausência dela
+    /// indica que o upstream não existe mais.
+    has_ab: bool,
+}
+
+impl BranchHeaders {
+    /// Analisa um cabeçalho, já sem o prefixo "# ". Cabeçalhos desconhecidos
+    /// (ex: `# stash`) são ignorados.
+    fn parse(&mut self, header: &str) {
+        let Some((key, value)) = header.split_once(' ') else {
+            return;
+        };
+        match key {
+            "branch.oid" => self.initial = value == "(initial)",
+            "branch.head" => {
+                self.tracking.branch = match value {
+                    // Mantém o nome que o formato v1 usava para o HEAD destacado.
+                    "(detached)" => "HEAD (no branch)".to_string(),
+                    name => name.to_string(),
+                }
+            }
+            "branch.upstream" => self.tracking.upstream = Some(value.to_string()),
+            "branch.ab" => {
+                self.has_ab = true;
+                for part in value.split(' ') {
+                    if let Some(n) = part.strip_prefix('+') {
+                        self.tracking.ahead = n.parse().ok().filter(|&n| n > 0);
+                    } else if let Some(n) = part.strip_prefix('-') {
+                        self.tracking.behind = n.parse().ok().filter(|&n| n > 0);
+                    }
+                }
+            }
+            _ => {}
+        }
+        self.tracking.gone = self.tracking.upstream.is_some() && !self.has_ab;
+    }
+
+    /// Reconstrói a linha de branch no formato do v1 (ex:
+    /// `main...origin/main [ahead 2, behind 1]`), exposta em `branch_info`.
+    fn branch_info(&self) -> String {
+        let tracking = &self.tracking;
+        if tracking.branch.is_empty() {
+            return String::new();
+        }
+
+        let mut info = if self.initial {
+            format!("No commits yet on {}", tracking.branch)
+        } else {
+            tracking.branch.clone()
+        };
+        if let Some(upstream) = &tracking.upstream {
+            info.push_str("...");
+            info.push_str(upstream);
+        }
+
+        let mut track = Vec::new();
+        if tracking.gone {
+            track.push("gone".to_string());
+        }
+        if let Some(ahead) = tracking.ahead {
+            track.push(format!("ahead {}", ahead));
+        }
+        if let Some(behind) = tracking.behind {
+            track.push(format!("behind {}", behind));
+        }
+        if !track.is_empty() {
+            info.push_str(&format!(" [{}]", track.join(", ")));
+        }
+        info
+    }
+}
+
+/// Analisa um registro de arquivo do status v2.
+///
+/// # Returns
+/// `Some(FileStatus)`, ou `None` se a linha estiver malformada ou for de um
+/// tipo que não nos interessa (ex: `!`, arquivos ignorados).
+fn parse_status_line(line: &str) -> Option<FileStatus> {
+    let (kind, rest) = line.split_once(' ')?;
+    // Quantos campos separados por espaço vêm antes do caminho, que pode
+    // conter espaços e por isso é o último campo.
+    let fields = match kind {
+        "?" => {
+            let (path, _) = take_path(rest);
+            return Some(FileStatus {
+                path,
+                staged_status: Some(ChangeType::Untracked),
+                unstaged_status: Some(ChangeType::Untracked),
+                ..Default::default()
+            });
+        }
+        "1" => 7,
+        "2" => 8,
+        "u" => 9,
+        _ => return None,
+    };
+
+    let mut parts = rest.splitn(fields + 1, ' ');
+    let code = parts.next()?;
+    let submodule = parse_submodule_field(parts.next()?);
+    let mut rename_score = None;
+    if kind == "2" {
+        // O campo `Xscore` (ex: "R87") vem logo antes do caminho.
+        rename_score = parts.nth(5)?.get(1..)?.parse().ok();
     } else {
-        // Se não houver saída, o repositório está limpo e sem branch?
-        // Retornamos um status vazio, o que é um estado válido.
-        return Ok(status);
+        parts.nth(fields - 3)?;
     }
+    let path_part = parts.next()?;
 
-    // Analisa as linhas de status de arquivo restantes.
-    for line in lines {
-        if line.len() < 4 { continue; } // Ignora linhas malformadas.
+    let mut chars = code.chars();
+    let staged_status = parse_status_char(chars.next()?);
+    let unstaged_status = parse_status_char(chars.next()?);
 
-        let (code, path_part) = line.split_at(2);
-        let path = path_part.trim_start();
+    // Em renomeações e cópias, o destino e a origem são separados por um
+    // TAB, que não pode aparecer sem aspas em um caminho: não há ambiguidade.
+    let (path, old_path) = if kind == "2" {
+        let (to, rest) = take_path(path_part);
+        let (from, _) = take_path(rest.strip_prefix('\t')?);
+        (to, Some(from))
+    } else {
+        (take_path(path_part).0, None)
+    };
+
+    Some(FileStatus {
+        path,
+        old_path,
+        staged_status,
+        unstaged_status,
+        rename_score,
+        submodule,
+    })
+}
 
-        let staged_char = code.chars().next().unwrap();
-        let unstaged_char = code.chars().nth(1).unwrap();
+/// Analisa o campo `sub` de um registro: `N...` para arquivos comuns, ou
+/// `S<c><m><u>` para submódulos.
+fn parse_submodule_field(field: &str) -> Option<SubmoduleStatusFlags> {
+    let flags = field.strip_prefix('S')?.as_bytes();
+    Some(SubmoduleStatusFlags {
+        commit_changed: flags.first() == Some(&b'C'),
+        has_tracked_changes: flags.get(1) == Some(&b'M'),
+        has_untracked_changes: flags.get(2) == Some(&b'U'),
+    })
+}
 
-        let staged_status = parse_status_char(staged_char);
-        let unstaged_status = parse_status_char(unstaged_char);
+/// Extrai um caminho do início de `input`, decodificando-o se estiver entre aspas.
+///
+/// O Git coloca entre aspas os caminhos com aspas, barras invertidas ou
+/// caracteres de controle, usando os escapes de uma string C (`\t`, `\"`,
+/// `\\`) e sequências octais (`\303\251`) para bytes arbitrários.
+///
+/// # Returns
+/// O caminho decodificado e o restante de `input` após ele. Um caminho sem
+/// aspas vai até o primeiro TAB (o separador das renomeações), ou até o fim.
+fn take_path(input: &str) -> (String, &str) {
+    let Some(quoted) = input.strip_prefix('"') else {
+        let end = input.find('\t').unwrap_or(input.len());
+        return (input[..end].to_string(), &input[end..]);
+    };
 
-        // Lida com arquivos renomeados, que têm um formato especial: "R  origem -> destino"
-        if staged_char == 'R' || unstaged_char == 'R' {
-            if let Some(paths) = path.split_once(" -> ") {
-                status.files.push(FileStatus {
-                    path: format!("{} (renomeado de {})", paths.1, paths.0),
-                    staged_status,
-                    unstaged_status,
-                });
-                continue;
+    let bytes = quoted.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => {
+                let path = String::from_utf8_lossy(&decoded).into_owned();
+                return (path, &quoted[i + 1..]);
+            }
+            b'\\' if i + 1 < bytes.len() => {
+                let escaped = bytes[i + 1];
+                i += 2;
+                match escaped {
+                    b'0'..=b'7' => {
+                        // Sequência octal de até três dígitos representando um byte.
+                        let mut value = u32::from(escaped - b'0');
+                        let mut digits = 1;
+                        while digits < 3 && i < bytes.len() && (b'0'..=b'7').contains(&bytes[i]) {
+                            value = value * 8 + u32::from(bytes[i] - b'0');
+                            i += 1;
+                            digits += 1;
+                        }
+                        decoded.push(value as u8);
+                    }
+                    b'a' => decoded.push(0x07),
+                    b'b' => decoded.push(0x08),
+                    b't' => decoded.push(b'\t'),
+                    b'n' => decoded.push(b'\n'),
+                    b'v' => decoded.push(0x0b),
+                    b'f' => decoded.push(0x0c),
+                    b'r' => decoded.push(b'\r'),
+                    other => decoded.push(other),
+                }
+            }
+            byte => {
+                decoded.push(byte);
+                i += 1;
             }
         }
-
-        status.files.push(FileStatus {
-            path: path.to_string(),
-            staged_status,
-            unstaged_status,
-        });
     }
 
-    Ok(status)
+    // Sem a aspa de fechamento, a linha está malformada: usamos o que foi lido.
+    (String::from_utf8_lossy(&decoded).into_owned(), "")
 }
 
 /// Converte um único caractere de status do Git em um `ChangeType`.
@@ -145,7 +571,462 @@ fn parse_status_char(c: char) -> Option<ChangeType> {
         'T' => Some(ChangeType::TypeChanged),
         'U' => Some(ChangeType::Unmerged),
         '?' => Some(ChangeType::Untracked),
-        ' ' => None, // Espaço indica nenhuma mudança nesta área.
-        _ => None,   // Caractere desconhecido.
+        '.' | ' ' => None, // Nenhuma mudança nesta área.
+        _ => None,         // Caractere desconhecido.
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Um hash qualquer para os campos de objeto dos registros.
+    const HASH: &str = "e69de29bb2d1d6434b8b29ae775ad8c2e48c5391";
+
+    /// Um registro `1` (alteração comum) de um arquivo regular.
+    fn ordinary(xy: &str, path: &str) -> String {
+        format!("1 {} N... 100644 100644 100644 {} {} {}", xy, HASH, HASH, path)
+    }
+
+    /// Um registro `2` (renomeação ou cópia) de um arquivo regular.
+    fn renamed(xy: &str, score: &str, path: &str, orig_path: &str) -> String {
+        format!(
+            "2 {} N... 100644 100644 100644 {} {} {} {}\t{}",
+            xy, HASH, HASH, score, path, orig_path
+        )
+    }
+
+    /// Um registro `u` (conflito de merge) de um arquivo regular.
+    fn unmerged(xy: &str, path: &str) -> String {
+        format!(
+            "u {} N... 100644 100644 100644 100644 {} {} {} {}",
+            xy, HASH, HASH, HASH, path
+        )
+    }
+
+    /// Os cabeçalhos de uma branch sem upstream.
+    fn local_branch(name: &str) -> String {
+        format!("# branch.oid {}\n# branch.head {}\n", HASH, name)
+    }
+
+    /// Os cabeçalhos de uma branch que rastreia `origin/<name>`.
+    fn tracking_branch(name: &str, ahead: u32, behind: u32) -> String {
+        format!(
+            "{}# branch.upstream origin/{}\n# branch.ab +{} -{}\n",
+            local_branch(name),
+            name,
+            ahead,
+            behind
+        )
+    }
+
+    /// Monta uma saída completa a partir dos cabeçalhos e dos registros.
+    fn output(headers: &str, records: &[String]) -> String {
+        let mut output = headers.to_string();
+        for record in records {
+            output.push_str(record);
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Analisa uma saída com um único registro de arquivo e retorna essa entrada.
+    fn parse_single(record: &str) -> FileStatus {
+        let output = output(&local_branch("main"), &[record.to_string()]);
+        let mut status = parse_porcelain_output(&output).unwrap();
+        assert_eq!(status.files.len(), 1);
+        status.files.remove(0)
+    }
+
+    #[test]
+    fn parses_path_with_spaces() {
+        // No v2, espaços não fazem o Git colocar o caminho entre aspas.
+        let file = parse_single("? meu arquivo.txt");
+        assert_eq!(file.path, "meu arquivo.txt");
+        assert_eq!(file.unstaged_status, Some(ChangeType::Untracked));
+    }
+
+    #[test]
+    fn parses_accented_path_in_utf8() {
+        // Com `core.quotepath=false`, o Git mantém o UTF-8 sem aspas.
+        let file = parse_single(&ordinary(".M", "relatório.md"));
+        assert_eq!(file.path, "relatório.md");
+        assert_eq!(file.staged_status, None);
+        assert_eq!(file.unstaged_status, Some(ChangeType::Modified));
+    }
+
+    #[test]
+    fn decodes_octal_escapes() {
+        // "é" em UTF-8 é 0xC3 0xA9, que o Git escapa como \303\251.
+        let file = parse_single(&ordinary("A.", r#""caf\303\251 \"forte\".txt""#));
+        assert_eq!(file.path, "café \"forte\".txt");
+        assert_eq!(file.staged_status, Some(ChangeType::Added));
+    }
+
+    #[test]
+    fn unquotes_path_with_spaces() {
+        let (path, rest) = take_path(r#""arquivo com espaço.txt""#);
+        assert_eq!(path, "arquivo com espaço.txt");
+        assert_eq!(rest, "");
+    }
+
+    #[test]
+    fn unquotes_octal_escaped_utf8() {
+        // Com `core.quotepath` ativo (o padrão), o Git escapa cada byte não-ASCII.
+        let (path, rest) = take_path(r#""arquivo com espa\303\247o \342\234\224.txt""#);
+        assert_eq!(path, "arquivo com espaço ✔.txt");
+        assert_eq!(rest, "");
+    }
+
+    #[test]
+    fn unquotes_embedded_quotes_and_backslashes() {
+        let (path, _) = take_path(r#""diga \"oi\" \\ adeus.txt""#);
+        assert_eq!(path, r#"diga "oi" \ adeus.txt"#);
+    }
+
+    #[test]
+    fn unquoted_path_is_returned_as_is() {
+        assert_eq!(take_path("relatório final.md"), ("relatório final.md".to_string(), ""));
+    }
+
+    #[test]
+    fn unquoting_stops_at_the_closing_quote() {
+        let (path, rest) = take_path("\"novo\\tnome.txt\"\t\"velho.txt\"");
+        assert_eq!(path, "novo\tnome.txt");
+        assert_eq!(rest, "\t\"velho.txt\"");
+    }
+
+    #[test]
+    fn arrow_in_filename_is_not_a_rename() {
+        let file = parse_single(&ordinary(".M", "antes -> depois.txt"));
+        assert_eq!(file.path, "antes -> depois.txt");
+        assert_eq!(file.old_path, None);
+        assert_eq!(file.unstaged_status, Some(ChangeType::Modified));
+    }
+
+    #[test]
+    fn parses_rename_with_spaces_in_both_paths() {
+        let file = parse_single(&renamed("R.", "R100", "novo nome.txt", "velho nome.txt"));
+        assert_eq!(file.path, "novo nome.txt");
+        assert_eq!(file.old_path.as_deref(), Some("velho nome.txt"));
+        assert_eq!(file.staged_status, Some(ChangeType::Renamed));
+        assert_eq!(file.rename_score, Some(100));
+    }
+
+    #[test]
+    fn parses_rename_with_quoted_paths() {
+        let record = renamed("R.", "R100", r#""\303\247.txt""#, r#""caf\303\251.txt""#);
+        let file = parse_single(&record);
+        assert_eq!(file.path, "ç.txt");
+        assert_eq!(file.old_path.as_deref(), Some("café.txt"));
+    }
+
+    #[test]
+    fn parses_rename_score_of_a_modified_rename() {
+        let file = parse_single(&renamed("RM", "R87", "src/novo.rs", "src/velho.rs"));
+        assert_eq!(file.path, "src/novo.rs");
+        assert_eq!(file.old_path.as_deref(), Some("src/velho.rs"));
+        assert_eq!(file.staged_status, Some(ChangeType::Renamed));
+        assert_eq!(file.unstaged_status, Some(ChangeType::Modified));
+        assert_eq!(file.rename_score, Some(87));
+    }
+
+    #[test]
+    fn rename_of_file_whose_name_contains_an_arrow() {
+        let file = parse_single(&renamed("R.", "R100", "c.txt", "a -> b.txt"));
+        assert_eq!(file.path, "c.txt");
+        assert_eq!(file.old_path.as_deref(), Some("a -> b.txt"));
+        assert_eq!(file.staged_status, Some(ChangeType::Renamed));
+    }
+
+    #[test]
+    fn parses_copy_record() {
+        let file = parse_single(&renamed("C.", "C75", "copia.rs", "original.rs"));
+        assert_eq!(file.path, "copia.rs");
+        assert_eq!(file.old_path.as_deref(), Some("original.rs"));
+        assert_eq!(file.staged_status, Some(ChangeType::Copied));
+        assert_eq!(file.rename_score, Some(75));
+    }
+
+    #[test]
+    fn ordinary_records_have_no_rename_score_or_submodule() {
+        let file = parse_single(&ordinary("M.", "a -> b.txt"));
+        assert_eq!(file.old_path, None);
+        assert_eq!(file.rename_score, None);
+        assert_eq!(file.submodule, None);
+    }
+
+    #[test]
+    fn parses_submodule_state() {
+        let record = format!("1 .M SCMU 160000 160000 160000 {} {} libs/sub", HASH, HASH);
+        let file = parse_single(&record);
+        assert_eq!(file.path, "libs/sub");
+        assert_eq!(
+            file.submodule,
+            Some(SubmoduleStatusFlags {
+                commit_changed: true,
+                has_tracked_changes: true,
+                has_untracked_changes: true,
+            })
+        );
+
+        let record = format!("1 .M S.M. 160000 160000 160000 {} {} libs/sub", HASH, HASH);
+        let state = parse_single(&record).submodule.unwrap();
+        assert!(!state.commit_changed);
+        assert!(state.has_tracked_changes);
+        assert!(!state.has_untracked_changes);
+    }
+
+    #[test]
+    fn display_path_shows_rename_score_and_submodule_changes() {
+        let file = parse_single(&renamed("R.", "R87", "novo.rs", "velho.rs"));
+        assert_eq!(file.display_path(), "novo.rs (renomeado de velho.rs, 87% similar)");
+
+        let record = format!("1 .M SC.U 160000 160000 160000 {} {} sub", HASH, HASH);
+        assert_eq!(
+            parse_single(&record).display_path(),
+            "sub (submódulo: novo commit, arquivos não rastreados)"
+        );
+    }
+
+    #[test]
+    fn ignored_records_and_unknown_headers_are_skipped() {
+        let output = format!("{}# stash 2\n! alvo/\n? solto\n", local_branch("main"));
+        let status = parse_porcelain_output(&output).unwrap();
+        assert_eq!(status.files.len(), 1);
+        assert_eq!(status.files[0].path, "solto");
+        assert_eq!(status.branch_info, "main");
+    }
+
+    // --- Fixtures: saídas completas de `git status --porcelain=v2 --branch` ---
+    //
+    // Cada fixture reproduz a saída real do Git para um estado típico do
+    // repositório; `parse_fixture` a analisa sem precisar de um repositório.
+
+    /// Repositório limpo, em dia com o upstream.
+    fn clean() -> String {
+        tracking_branch("main", 0, 0)
+    }
+
+    /// Alterações no stage, fora dele e em ambos, além de uma renomeação.
+    fn staged_and_unstaged() -> String {
+        output(
+            &tracking_branch("main", 2, 1),
+            &[
+                renamed("R.", "R100", "LEIAME.md", "README.md"),
+                ordinary("M.", "a"),
+                ordinary(".M", "b"),
+                ordinary("MM", "c"),
+                ordinary("D.", "d"),
+                ordinary("A.", "novo"),
+                "? solto".to_string(),
+            ],
+        )
+    }
+
+    /// Apenas arquivos não rastreados (incluindo um diretório inteiro).
+    fn untracked() -> String {
+        output(
+            &local_branch("main"),
+            &["? rascunho.txt".to_string(), "? build/".to_string()],
+        )
+    }
+
+    /// Um merge interrompido por conflitos.
+    fn conflicts() -> String {
+        output(
+            &tracking_branch("main", 1, 0),
+            &[
+                unmerged("UU", "conflito.rs"),
+                unmerged("AA", "ambos_adicionaram.rs"),
+                unmerged("DU", "apagado_por_nos.rs"),
+                ordinary("M.", "resolvido.rs"),
+            ],
+        )
+    }
+
+    /// Um repositório recém-criado, antes do primeiro commit.
+    fn no_commits_yet() -> String {
+        output(
+            "# branch.oid (initial)\n# branch.head main\n",
+            &[ordinary("A.", "README.md")],
+        )
+    }
+
+    fn parse_fixture(fixture: &str) -> GitStatus {
+        parse_porcelain_output(fixture).expect("a fixture deve ser analisada sem erros")
+    }
+
+    /// Procura um arquivo pelo caminho, falhando o teste se ele não existir.
+    fn file<'a>(status: &'a GitStatus, path: &str) -> &'a FileStatus {
+        status
+            .files
+            .iter()
+            .find(|f| f.path == path)
+            .unwrap_or_else(|| panic!("'{}' não está no status", path))
+    }
+
+    #[test]
+    fn fixture_clean_repository() {
+        let status = parse_fixture(&clean());
+        assert_eq!(status.branch_info, "main...origin/main");
+        assert!(status.files.is_empty());
+        assert_eq!(status.ahead_behind(), (0, 0));
+    }
+
+    #[test]
+    fn fixture_empty_output_is_an_empty_status() {
+        let status = parse_fixture("");
+        assert_eq!(status.branch_info, "");
+        assert!(status.files.is_empty());
+    }
+
+    #[test]
+    fn fixture_staged_and_unstaged_mix() {
+        let status = parse_fixture(&staged_and_unstaged());
+        assert_eq!(status.files.len(), 7);
+        assert_eq!(status.branch_info, "main...origin/main [ahead 2, behind 1]");
+        assert_eq!(status.ahead_behind(), (2, 1));
+
+        let staged_only = file(&status, "a");
+        assert_eq!(staged_only.staged_status, Some(ChangeType::Modified));
+        assert_eq!(staged_only.unstaged_status, None);
+
+        let unstaged_only = file(&status, "b");
+        assert_eq!(unstaged_only.staged_status, None);
+        assert_eq!(unstaged_only.unstaged_status, Some(ChangeType::Modified));
+
+        let both = file(&status, "c");
+        assert_eq!(both.staged_status, Some(ChangeType::Modified));
+        assert_eq!(both.unstaged_status, Some(ChangeType::Modified));
+
+        assert_eq!(file(&status, "d").staged_status, Some(ChangeType::Deleted));
+        assert_eq!(file(&status, "novo").staged_status, Some(ChangeType::Added));
+        assert_eq!(file(&status, "solto").unstaged_status, Some(ChangeType::Untracked));
+
+        let renamed = file(&status, "LEIAME.md");
+        assert_eq!(renamed.old_path.as_deref(), Some("README.md"));
+        assert_eq!(renamed.staged_status, Some(ChangeType::Renamed));
+        assert_eq!(renamed.affected_paths(), vec!["LEIAME.md", "README.md"]);
+    }
+
+    #[test]
+    fn fixture_untracked_files() {
+        let status = parse_fixture(&untracked());
+        assert_eq!(status.files.len(), 2);
+        for f in &status.files {
+            assert_eq!(f.staged_status, Some(ChangeType::Untracked));
+            assert_eq!(f.unstaged_status, Some(ChangeType::Untracked));
+        }
+        assert_eq!(status.files[1].path, "build/");
+    }
+
+    #[test]
+    fn fixture_conflicts() {
+        let status = parse_fixture(&conflicts());
+        assert_eq!(status.ahead_behind(), (1, 0));
+
+        let conflict = file(&status, "conflito.rs");
+        assert_eq!(conflict.staged_status, Some(ChangeType::Unmerged));
+        assert_eq!(conflict.unstaged_status, Some(ChangeType::Unmerged));
+
+        let both_added = file(&status, "ambos_adicionaram.rs");
+        assert_eq!(both_added.staged_status, Some(ChangeType::Added));
+        assert_eq!(both_added.unstaged_status, Some(ChangeType::Added));
+
+        let deleted_by_us = file(&status, "apagado_por_nos.rs");
+        assert_eq!(deleted_by_us.staged_status, Some(ChangeType::Deleted));
+        assert_eq!(deleted_by_us.unstaged_status, Some(ChangeType::Unmerged));
+
+        assert_eq!(file(&status, "resolvido.rs").staged_status, Some(ChangeType::Modified));
+    }
+
+    #[test]
+    fn fixture_no_commits_yet() {
+        let status = parse_fixture(&no_commits_yet());
+        assert_eq!(status.branch_info, "No commits yet on main");
+        assert_eq!(status.ahead_behind(), (0, 0));
+        assert_eq!(status.files.len(), 1);
+        assert_eq!(file(&status, "README.md").staged_status, Some(ChangeType::Added));
+    }
+
+    #[test]
+    fn files_beyond_the_limit_are_only_counted() {
+        let output = staged_and_unstaged();
+        let status = parse_porcelain_stream(output.as_bytes(), Some(3)).unwrap();
+        assert_eq!(status.files.len(), 3);
+        assert_eq!(status.omitted, 4);
+        assert_eq!(status.files[0].path, "LEIAME.md");
+        assert_eq!(status.ahead_behind(), (2, 1));
+    }
+
+    #[test]
+    fn invalid_utf8_in_a_path_does_not_abort_parsing() {
+        let mut output = b"# branch.head main\n? caf\xe9.txt\n".to_vec();
+        output.extend_from_slice(ordinary(".M", "ok.txt").as_bytes());
+        let status = parse_porcelain_stream(&output[..], None).unwrap();
+        assert_eq!(status.files.len(), 2);
+        assert_eq!(status.files[1].path, "ok.txt");
+    }
+
+    #[test]
+    fn branch_without_upstream_has_no_tracking() {
+        let tracking = parse_fixture(&untracked()).tracking;
+        assert_eq!(tracking.branch, "main");
+        assert_eq!(tracking.upstream, None);
+        assert_eq!((tracking.ahead, tracking.behind), (None, None));
+        assert!(!tracking.gone);
+    }
+
+    #[test]
+    fn branch_in_sync_with_upstream() {
+        let tracking = parse_fixture(&clean()).tracking;
+        assert_eq!(tracking.branch, "main");
+        assert_eq!(tracking.upstream.as_deref(), Some("origin/main"));
+        assert_eq!((tracking.ahead, tracking.behind), (None, None));
+        assert!(!tracking.gone);
+    }
+
+    #[test]
+    fn branch_only_ahead_or_only_behind() {
+        let ahead = parse_fixture(&conflicts()).tracking;
+        assert_eq!((ahead.ahead, ahead.behind), (Some(1), None));
+
+        let status = parse_fixture(&tracking_branch("feat", 0, 3));
+        assert_eq!(status.tracking.branch, "feat");
+        assert_eq!((status.tracking.ahead, status.tracking.behind), (None, Some(3)));
+        assert_eq!(status.branch_info, "feat...origin/feat [behind 3]");
+    }
+
+    #[test]
+    fn branch_ahead_and_behind() {
+        let tracking = parse_fixture(&staged_and_unstaged()).tracking;
+        assert_eq!(tracking.upstream.as_deref(), Some("origin/main"));
+        assert_eq!((tracking.ahead, tracking.behind), (Some(2), Some(1)));
+    }
+
+    #[test]
+    fn branch_with_gone_upstream() {
+        // Sem o upstream no remoto, o Git omite a linha `# branch.ab`.
+        let output = format!("{}# branch.upstream origin/feat\n", local_branch("feat"));
+        let status = parse_fixture(&output);
+        assert_eq!(status.tracking.upstream.as_deref(), Some("origin/feat"));
+        assert!(status.tracking.gone);
+        assert_eq!((status.tracking.ahead, status.tracking.behind), (None, None));
+        assert_eq!(status.branch_info, "feat...origin/feat [gone]");
+    }
+
+    #[test]
+    fn branch_before_the_first_commit() {
+        let tracking = parse_fixture(&no_commits_yet()).tracking;
+        assert_eq!(tracking.branch, "main");
+        assert_eq!(tracking.upstream, None);
+    }
+
+    #[test]
+    fn detached_head_keeps_the_v1_name() {
+        let status = parse_fixture(&local_branch("(detached)"));
+        assert_eq!(status.tracking.branch, "HEAD (no branch)");
+        assert_eq!(status.branch_info, "HEAD (no branch)");
     }
 }
\ No newline at end of file
@@ -0,0 +1,115 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo de Submódulos do Git
+//
+// Lista os submódulos do repositório com o estado de cada um (através do
+// `git submodule status`) e os inicializa/atualiza para o commit registrado
+// no repositório principal.
+// ==============================================================================
+
+use super::{run_git, run_git_network};
+use anyhow::{anyhow, Result};
+
+/// O estado de um submódulo em relação ao commit registrado no repositório.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmoduleState {
+    /// O submódulo está no commit registrado.
+    UpToDate,
+    /// O submódulo ainda não foi inicializado (prefixo `-`).
+    Uninitialized,
+    /// O commit do submódulo difere do registrado (prefixo `+`).
+    OutOfDate,
+    /// O submódulo tem conflitos de merge (prefixo `U`).
+    Conflicted,
+}
+
+/// Um submódulo do repositório.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submodule {
+    /// O caminho do submódulo, relativo à raiz do repositório.
+    pub path: String,
+    /// O commit em que o submódulo está (ou o registrado, se não inicializado).
+    pub commit: String,
+    /// A descrição do commit (ex: "heads/main" ou "v1.2.0"), se disponível.
+    pub describe: Option<String>,
+    /// O estado do submódulo.
+    pub state: SubmoduleState,
+}
+
+impl Submodule {
+    /// Se o submódulo precisa de um `update` para ficar no commit registrado.
+    pub fn needs_update(&self) -> bool {
+        matches!(self.state, SubmoduleState::Uninitialized | SubmoduleState::OutOfDate)
+    }
+}
+
+/// Lista os submódulos do repositório.
+///
+/// Executa `git submodule status`.
+///
+/// # Returns
+/// `Ok(Vec<Submodule>)`, vazio se o repositório não tiver submódulos, ou
+/// `Err` se o Git falhar.
+pub fn list_submodules() -> Result<Vec<Submodule>> {
+    let output = run_git(&["submodule", "status"])
+        .map_err(|e| anyhow!("Falha ao listar os submódulos: {}", e))?;
+    Ok(output.stdout.lines().filter_map(parse_status_line).collect())
+}
+
+/// Atualiza os submódulos para os commits registrados no repositório.
+///
+/// Executa `git submodule update`, com `--init` para inicializar os
+/// submódulos ainda não inicializados e `--recursive` para também atualizar
+/// os submódulos dos submódulos. Pode baixar commits, então respeita o tempo
+/// limite de rede e o modo offline.
+///
+/// # Arguments
+/// * `init` - Se os submódulos não inicializados devem ser inicializados.
+/// * `recursive` - Se os submódulos aninhados também devem ser atualizados.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se o Git falhar.
+pub fn update_submodules(init: bool, recursive: bool) -> Result<()> {
+    let mut args = vec!["submodule", "update"];
+    if init {
+        args.push("--init");
+    }
+    if recursive {
+        args.push("--recursive");
+    }
+    run_git_network(&args).map_err(|e| anyhow!("Falha ao atualizar os submódulos: {}", e))?;
+    Ok(())
+}
+
+/// Analisa uma linha do `git submodule status`.
+///
+/// O formato é `<estado><hash> <caminho>[ (<descrição>)]`, onde o estado é um
+/// espaço, `-`, `+` ou `U`.
+fn parse_status_line(line: &str) -> Option<Submodule> {
+    let mut chars = line.chars();
+    let state = match chars.next()? {
+        ' ' => SubmoduleState::UpToDate,
+        '-' => SubmoduleState::Uninitialized,
+        '+' => SubmoduleState::OutOfDate,
+        'U' => SubmoduleState::Conflicted,
+        _ => return None,
+    };
+    let (commit, rest) = chars.as_str().split_once(' ')?;
+    // O caminho pode conter espaços; a descrição é sempre o último trecho
+    // entre parênteses.
+    let (path, describe) = match rest.strip_suffix(')').and_then(|r| r.rsplit_once(" (")) {
+        Some((path, describe)) => (path, Some(describe.to_string())),
+        None => (rest, None),
+    };
+    Some(Submodule {
+        path: path.to_string(),
+        commit: commit.to_string(),
+        describe,
+        state,
+    })
+}
\ No newline at end of file
@@ -12,9 +12,10 @@
 // projeto, como o lançamento de uma nova versão.
 // ==============================================================================
 
-use anyhow::{anyhow, Context, Result};
-use indicatif::{ProgressBar, ProgressStyle};
-use std::process::Command;
+use super::{run_git, run_git_network, run_verification, signing_failure_hint, GitCommandError, VerificationStatus};
+use crate::ui::output;
+use anyhow::{anyhow, Result};
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
 use std::time::Duration;
 
 /// Cria uma tag Git anotada localmente.
@@ -25,11 +26,12 @@ use std::time::Duration;
 /// # Arguments
 /// * `tag_name` - O nome da tag (ex: "v1.0.0").
 /// * `message` - A mensagem de anotação para a tag.
+/// * `sign` - Se `true`, cria uma tag assinada com GPG (`-s`).
 ///
 /// # Returns
-/// `Ok(())` em caso de sucesso, ou um `Err` se a tag já existir ou outro
-/// erro do Git ocorrer.
-pub fn create_annotated_tag(tag_name: &str, message: &str) -> Result<()> {
+/// `Ok(())` em caso de sucesso, ou um `Err` se a tag já existir, se a
+/// assinatura GPG falhar, ou se outro erro do Git ocorrer.
+pub fn create_annotated_tag(tag_name: &str, message: &str, sign: bool) -> Result<()> {
     // Realizamos uma validação de entrada para garantir que não estamos
     // tentando criar uma tag com nome ou mensagem vazios.
     if tag_name.trim().is_empty() {
@@ -39,26 +41,119 @@ pub fn create_annotated_tag(tag_name: &str, message: &str) -> Result<()> {
         return Err(anyhow!("A mensagem de anotação da tag não pode ser vazia."));
     }
 
-    // Construímos o comando `git tag -a <nome> -m <mensagem>`.
-    // O flag `-a` especifica que queremos uma tag anotada.
-    let output = Command::new("git")
-        .arg("tag")
-        .arg("-a")
-        .arg(tag_name)
-        .arg("-m")
-        .arg(message)
-        .output()
-        .context("Falha ao executar o comando 'git tag'.")?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(anyhow!(
-            "Falha ao criar a tag '{}': {}",
-            tag_name,
-            stderr.trim()
-        ));
+    if let Err(e) = run_git(&annotated_tag_args(tag_name, message, sign)) {
+        let hint = e
+            .downcast_ref::<GitCommandError>()
+            .and_then(|failure| signing_failure_hint(&failure.stderr));
+        if let Some(hint) = hint {
+            return Err(anyhow!(
+                "Falha ao criar a tag '{}': {}\n\n{}",
+                tag_name,
+                e,
+                hint
+            ));
+        }
+        return Err(anyhow!("Falha ao criar a tag '{}': {}", tag_name, e));
+    }
+
+    Ok(())
+}
+
+/// Monta os argumentos do `git tag` executado por `create_annotated_tag`.
+///
+/// O comando é `git tag -a <nome> -m <mensagem>`: o `-a` cria uma tag
+/// anotada, e o `-s` (opcional) pede ao Git para assiná-la com a chave GPG
+/// do usuário.
+pub fn annotated_tag_args<'a>(tag_name: &'a str, message: &'a str, sign: bool) -> Vec<&'a str> {
+    let mut args = vec!["tag", "-a"];
+    if sign {
+        args.push("-s");
+    }
+    args.extend([tag_name, "-m", message]);
+    args
+}
+
+/// Obtém a tag mais recente alcançável a partir de `HEAD`.
+///
+/// Executa `git describe --tags --abbrev=0`, que retorna o nome da tag mais
+/// próxima no histórico da branch atual.
+///
+/// # Returns
+/// `Ok(Some(String))` com o nome da tag, ou `Ok(None)` se o repositório ainda
+/// não tiver nenhuma tag (ou nenhum commit).
+pub fn latest_tag() -> Result<Option<String>> {
+    let output = match run_git(&["describe", "--tags", "--abbrev=0"]) {
+        Ok(output) => output,
+        // O `git describe` falha quando não encontra nenhuma tag. Para nós, esse
+        // é um estado válido (primeira release), e não um erro.
+        Err(e) if e.is::<GitCommandError>() => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    let tag = output.stdout.trim().to_string();
+    Ok(if tag.is_empty() { None } else { Some(tag) })
+}
+
+/// Lista as tags do repositório, das mais recentes para as mais antigas.
+///
+/// Executa `git tag --list --sort=-creatordate`.
+///
+/// # Returns
+/// `Ok(Vec<String>)` com os nomes das tags (vazio se não houver nenhuma), ou
+/// `Err` se o comando falhar.
+pub fn list_tags() -> Result<Vec<String>> {
+    let output = run_git(&["tag", "--list", "--sort=-creatordate"])
+        .map_err(|e| anyhow!("Falha ao listar as tags: {}", e))?;
+
+    Ok(output
+        .stdout
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+/// Verifica a assinatura de uma tag.
+///
+/// Executa `git verify-tag --raw <tag>` (o mesmo que `git tag -v`, com as
+/// linhas de status do GPG). Tags leves e tags anotadas sem assinatura
+/// resultam em `VerificationStatus::Unsigned`, não em erro.
+///
+/// # Arguments
+/// * `name` - O nome da tag.
+///
+/// # Returns
+/// `Ok(VerificationStatus)`, ou `Err` se a tag não existir.
+pub fn verify_tag(name: &str) -> Result<VerificationStatus> {
+    run_verification(&["verify-tag", "--raw", name.trim()])
+        .map_err(|e| anyhow!("Falha ao verificar a tag '{}': {}", name.trim(), e))
+}
+
+/// Faz o checkout do código como estava em uma tag.
+///
+/// Executa `git checkout refs/tags/<tag>`. O nome completo evita ambiguidade
+/// com uma branch de mesmo nome. Como uma tag não é uma branch, o repositório
+/// fica em detached HEAD: para fazer alterações a partir daqui, o usuário deve
+/// criar uma branch.
+///
+/// # Arguments
+/// * `name` - O nome da tag (ex: "v1.0.0").
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se a tag não existir ou se o Git
+/// recusar o checkout (ex: alterações locais que seriam sobrescritas).
+pub fn checkout_tag(name: &str) -> Result<()> {
+    let reference = format!("refs/tags/{}", name.trim());
+    if let Err(e) = run_git(&["rev-parse", "--verify", "--quiet", &reference]) {
+        if e.is::<GitCommandError>() {
+            return Err(anyhow!("A tag '{}' não existe.", name.trim()));
+        }
+        return Err(e);
     }
 
+    run_git(&["checkout", &reference])
+        .map_err(|e| anyhow!("Falha ao fazer checkout da tag '{}': {}", name.trim(), e))?;
     Ok(())
 }
 
@@ -68,11 +163,12 @@ pub fn create_annotated_tag(tag_name: &str, message: &str) -> Result<()> {
 ///
 /// # Arguments
 /// * `tag_name` - O nome da tag a ser enviada.
+/// * `dry_run` - Se `true`, apenas simula o envio (`--dry-run`).
 ///
 /// # Returns
 /// `Ok(String)` com a mensagem de sucesso do servidor, ou `Err` se o push
 /// da tag falhar.
-pub fn push_tag(tag_name: &str) -> Result<String> {
+pub fn push_tag(tag_name: &str, dry_run: bool) -> Result<String> {
     if tag_name.trim().is_empty() {
         return Err(anyhow!("O nome da tag a ser enviada não pode ser vazio."));
     }
@@ -86,28 +182,33 @@ pub fn push_tag(tag_name: &str) -> Result<String> {
             .unwrap(),
     );
     spinner.set_message(format!("Enviando tag '{}' para o remoto...", tag_name));
+    if output::is_quiet() {
+        spinner.set_draw_target(ProgressDrawTarget::hidden());
+    }
+    crate::interrupt::track_progress(&spinner);
     spinner.enable_steady_tick(Duration::from_millis(100));
 
-    // O comando para enviar uma única tag é `git push origin <nome_da_tag>`.
-    let output = Command::new("git")
-        .arg("push")
-        .arg("origin")
-        .arg(tag_name)
-        .output()
-        .context("Falha ao executar o comando 'git push' para a tag.")?;
+    let mut args = push_tag_args(tag_name);
+    if dry_run {
+        args.push("--dry-run");
+    }
+    let result = run_git_network(&args);
 
     spinner.finish_and_clear();
 
-    if output.status.success() {
+    match result {
         // Assim como no push normal, a mensagem de sucesso geralmente está no stderr.
-        let success_message = String::from_utf8_lossy(&output.stderr).trim().to_string();
-        Ok(success_message)
-    } else {
-        let error_message = String::from_utf8_lossy(&output.stderr).trim().to_string();
-        Err(anyhow!(
+        Ok(output) => Ok(output.stderr.trim().to_string()),
+        Err(e) => Err(anyhow!(
             "Falha ao enviar a tag '{}' para o remoto:\n\n{}",
             tag_name,
-            error_message
-        ))
+            e
+        )),
     }
+}
+
+/// Monta os argumentos do `git push` executado por `push_tag`: o comando para
+/// enviar uma única tag é `git push origin <nome_da_tag>`.
+pub fn push_tag_args(tag_name: &str) -> Vec<&str> {
+    vec!["push", "origin", tag_name]
 }
\ No newline at end of file
@@ -0,0 +1,133 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo de Worktrees do Git
+//
+// Um worktree é um diretório de trabalho adicional ligado ao mesmo
+// repositório. Ele permite manter várias branches abertas ao mesmo tempo,
+// em pastas diferentes, sem precisar de `stash` ou de clones extras.
+// ==============================================================================
+
+use super::run_git;
+use anyhow::{anyhow, Result};
+use std::path::Path;
+
+/// Representa um worktree ligado ao repositório.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Worktree {
+    /// O caminho absoluto do diretório do worktree.
+    pub path: String,
+    /// O commit em `HEAD`. Vazio para um repositório bare.
+    pub head: String,
+    /// A branch em uso (ex: "main"), ou `None` se estiver em detached HEAD
+    /// ou for o repositório bare.
+    pub branch: Option<String>,
+    /// `true` para a entrada do repositório bare principal.
+    pub is_bare: bool,
+    /// `true` se o worktree estiver em detached HEAD.
+    pub is_detached: bool,
+}
+
+/// Lista todos os worktrees do repositório, começando pelo principal.
+///
+/// Executa `git worktree list --porcelain`, cuja saída tem um bloco por
+/// worktree, separados por linhas em branco:
+/// ```text
+/// worktree /caminho/principal
+/// HEAD abc123...
+/// branch refs/heads/main
+///
+/// worktree /caminho/outro
+/// HEAD def456...
+/// detached
+/// ```
+///
+/// # Returns
+/// `Ok(Vec<Worktree>)`, ou `Err` se o comando falhar.
+pub fn list_worktrees() -> Result<Vec<Worktree>> {
+    let output = run_git(&["worktree", "list", "--porcelain"])
+        .map_err(|e| anyhow!("Falha ao listar os worktrees: {}", e))?;
+
+    Ok(parse_porcelain(&output.stdout))
+}
+
+/// Cria um novo worktree para uma branch.
+///
+/// Executa `git worktree add <caminho> <branch>` se a branch já existir, ou
+/// `git worktree add -b <branch> <caminho>` para criá-la a partir de `HEAD`.
+///
+/// # Arguments
+/// * `path` - O diretório do novo worktree. Não pode existir com conteúdo.
+/// * `branch` - A branch a ser usada no worktree.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se a branch já estiver em uso por
+/// outro worktree ou o diretório não puder ser usado.
+pub fn add_worktree(path: &Path, branch: &str) -> Result<()> {
+    let branch = branch.trim();
+    if branch.is_empty() {
+        return Err(anyhow!("O nome da branch não pode ser vazio."));
+    }
+
+    let path_arg = path.to_string_lossy();
+    let branch_ref = format!("refs/heads/{}", branch);
+    let branch_exists = run_git(&["show-ref", "--verify", "--quiet", &branch_ref]).is_ok();
+
+    let result = if branch_exists {
+        run_git(&["worktree", "add", &path_arg, branch])
+    } else {
+        run_git(&["worktree", "add", "-b", branch, &path_arg])
+    };
+    result.map_err(|e| anyhow!("Falha ao criar o worktree em '{}': {}", path.display(), e))?;
+
+    Ok(())
+}
+
+/// Analisa a saída de `git worktree list --porcelain`.
+fn parse_porcelain(output: &str) -> Vec<Worktree> {
+    let mut worktrees = Vec::new();
+    let mut current: Option<Worktree> = None;
+
+    for line in output.lines() {
+        let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+        match key {
+            "worktree" => {
+                // Cada bloco começa com "worktree"; fechamos o anterior.
+                worktrees.extend(current.take());
+                current = Some(Worktree {
+                    path: value.to_string(),
+                    ..Default::default()
+                });
+            }
+            "HEAD" => {
+                if let Some(w) = current.as_mut() {
+                    w.head = value.to_string();
+                }
+            }
+            "branch" => {
+                if let Some(w) = current.as_mut() {
+                    w.branch = Some(value.strip_prefix("refs/heads/").unwrap_or(value).to_string());
+                }
+            }
+            "bare" => {
+                if let Some(w) = current.as_mut() {
+                    w.is_bare = true;
+                }
+            }
+            "detached" => {
+                if let Some(w) = current.as_mut() {
+                    w.is_detached = true;
+                }
+            }
+            // Linhas em branco e atributos que não usamos ("locked", "prunable").
+            _ => {}
+        }
+    }
+    worktrees.extend(current);
+
+    worktrees
+}
\ No newline at end of file
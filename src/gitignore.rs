@@ -0,0 +1,170 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Gerador de Arquivos `.gitignore`
+//
+// Monta o conteúdo de um `.gitignore` para as tecnologias escolhidas pelo
+// usuário e o grava na raiz do diretório atual.
+//
+// O template é obtido do gitignore.io. Sem conexão (ou se o serviço falhar),
+// recorremos a um pequeno conjunto de templates embutidos no binário, que
+// cobrem as linguagens mais comuns.
+// ==============================================================================
+
+use crate::{api_client, git_wrapper};
+use anyhow::{anyhow, Context, Result};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+
+/// O caminho do arquivo gerado, relativo ao diretório atual.
+pub const GITIGNORE_PATH: &str = ".gitignore";
+
+/// Templates embutidos usados quando o gitignore.io não está acessível.
+const BUNDLED_TEMPLATES: &[(&str, &str)] = &[
+    (
+        "rust",
+        "### Rust ###\n\
+         # Artefatos de compilação\n\
+         /target/\n\
+         debug/\n\
+         \n\
+         # Arquivos de backup do rustfmt\n\
+         **/*.rs.bk\n\
+         \n\
+         # Dados de profiling do MSVC\n\
+         *.pdb\n",
+    ),
+    (
+        "node",
+        "### Node ###\n\
+         node_modules/\n\
+         npm-debug.log*\n\
+         yarn-debug.log*\n\
+         yarn-error.log*\n\
+         pnpm-debug.log*\n\
+         .npm\n\
+         .env\n\
+         .env.*.local\n\
+         dist/\n\
+         coverage/\n",
+    ),
+    (
+        "python",
+        "### Python ###\n\
+         __pycache__/\n\
+         *.py[cod]\n\
+         *$py.class\n\
+         .venv/\n\
+         venv/\n\
+         env/\n\
+         build/\n\
+         dist/\n\
+         *.egg-info/\n\
+         .pytest_cache/\n\
+         .mypy_cache/\n",
+    ),
+];
+
+/// De onde veio o conteúdo gerado por `generate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateSource {
+    /// Baixado do gitignore.io.
+    Online,
+    /// Montado a partir dos templates embutidos, após uma falha de rede.
+    Bundled,
+}
+
+/// Como gravar o conteúdo quando o `.gitignore` já existe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteMode {
+    /// Substitui o arquivo existente.
+    Overwrite,
+    /// Acrescenta o conteúdo ao final do arquivo existente.
+    Append,
+}
+
+/// Retorna os nomes das tecnologias com template embutido.
+pub fn bundled_languages() -> Vec<&'static str> {
+    BUNDLED_TEMPLATES.iter().map(|(name, _)| *name).collect()
+}
+
+/// Gera o conteúdo de um `.gitignore` para as tecnologias informadas.
+///
+/// Tenta o gitignore.io primeiro. Se a requisição falhar e TODAS as
+/// tecnologias tiverem um template embutido, usa os templates embutidos.
+///
+/// # Arguments
+/// * `langs` - As tecnologias desejadas (ex: `["rust", "python"]`).
+///
+/// # Returns
+/// `Ok((conteúdo, origem))`, ou `Err` se nenhuma das fontes puder atender.
+pub fn generate(langs: &[String]) -> Result<(String, TemplateSource)> {
+    let online_error = match api_client::block_on(api_client::gitignore::fetch_template(langs)) {
+        Ok(content) => return Ok((content, TemplateSource::Online)),
+        Err(e) => e,
+    };
+
+    let mut content = String::new();
+    for lang in langs {
+        let key = lang.trim().to_lowercase();
+        let template = BUNDLED_TEMPLATES
+            .iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, template)| *template)
+            .ok_or_else(|| {
+                anyhow!(
+                    "{}\nNão há template embutido para '{}' (disponíveis offline: {}).",
+                    online_error,
+                    lang.trim(),
+                    bundled_languages().join(", ")
+                )
+            })?;
+        if !content.is_empty() {
+            content.push('\n');
+        }
+        content.push_str(template);
+    }
+
+    Ok((content, TemplateSource::Bundled))
+}
+
+/// Indica se já existe um `.gitignore` no diretório atual.
+pub fn exists() -> bool {
+    git_wrapper::resolve_path(GITIGNORE_PATH).exists()
+}
+
+/// Grava o conteúdo no `.gitignore` do diretório atual.
+///
+/// # Arguments
+/// * `content` - O conteúdo gerado por `generate`.
+/// * `mode` - Se o arquivo existente deve ser substituído ou complementado.
+///
+/// # Returns
+/// `Ok(())` em caso de sucesso, ou `Err` se o arquivo não puder ser escrito.
+pub fn write(content: &str, mode: WriteMode) -> Result<()> {
+    let path = git_wrapper::resolve_path(GITIGNORE_PATH);
+    let result = match mode {
+        WriteMode::Overwrite => fs::write(&path, content),
+        WriteMode::Append => {
+            let existing = fs::read_to_string(&path).unwrap_or_default();
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .and_then(|mut file| {
+                    // Garante que o novo bloco comece em uma linha própria.
+                    if !existing.is_empty() && !existing.ends_with('\n') {
+                        file.write_all(b"\n")?;
+                    }
+                    file.write_all(b"\n")?;
+                    file.write_all(content.as_bytes())
+                })
+        }
+    };
+
+    result.with_context(|| format!("Falha ao escrever o arquivo '{}'.", GITIGNORE_PATH))
+}
\ No newline at end of file
@@ -0,0 +1,145 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo de Tratamento de Interrupções (Ctrl-C)
+//
+// Sem tratamento, um Ctrl-C encerra o `gitph` no meio de uma animação: o
+// spinner fica pela metade na tela e o cursor, escondido pelo `indicatif` ou
+// pelo `dialoguer`, continua invisível no shell do usuário.
+//
+// O manipulador instalado por `install_handler`:
+// 1. Marca a interrupção, para que as esperas por processos do Git (veja
+//    `git_wrapper::wait_with_timeout`) encerrem o processo em andamento.
+// 2. Remove da tela os spinners registrados com `track_progress`.
+// 3. Mostra o cursor novamente e encerra com o código 130 (o convencional
+//    para SIGINT).
+//
+// Enquanto um programa interativo controla o terminal (o paginador, veja
+// `foreground_program`), o Ctrl-C é deixado para ele, como o Git faz.
+// ==============================================================================
+
+use anyhow::{Context, Result};
+use console::{style, Term};
+use indicatif::{ProgressBar, WeakProgressBar};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Código de saída usado após uma interrupção (128 + SIGINT).
+pub const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// Quanto o manipulador espera os processos do Git serem encerrados antes de
+/// sair mesmo assim.
+const CHILD_SHUTDOWN_GRACE: Duration = Duration::from_secs(2);
+
+/// Se o usuário pediu a interrupção (Ctrl-C).
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Quantos processos do Git estão sendo aguardados neste momento.
+static RUNNING_CHILDREN: AtomicUsize = AtomicUsize::new(0);
+
+/// Se um programa interativo (ex: o `less`) está no controle do terminal.
+static FOREGROUND_PROGRAM: AtomicBool = AtomicBool::new(false);
+
+/// As barras de progresso ativas. Guardamos referências fracas para não
+/// prolongar a vida de uma barra já descartada por quem a criou.
+static ACTIVE_PROGRESS: Mutex<Vec<WeakProgressBar>> = Mutex::new(Vec::new());
+
+/// Instala o manipulador de Ctrl-C da aplicação.
+///
+/// Deve ser chamada uma única vez, no início de `main`.
+pub fn install_handler() -> Result<()> {
+    ctrlc::set_handler(handle_interrupt).context("Falha ao instalar o manipulador de Ctrl-C.")
+}
+
+/// Retorna se o usuário pediu a interrupção da aplicação.
+pub fn is_interrupted() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
+
+/// Registra uma barra de progresso para que ela seja removida da tela se a
+/// aplicação for interrompida.
+pub fn track_progress(bar: &ProgressBar) {
+    if let Ok(mut bars) = ACTIVE_PROGRESS.lock() {
+        bars.retain(|weak| weak.upgrade().is_some());
+        bars.push(bar.downgrade());
+    }
+}
+
+/// Marca um processo do Git como em execução até que o valor retornado seja
+/// descartado. O manipulador aguarda esses processos serem encerrados antes
+/// de sair.
+pub fn track_child() -> ChildGuard {
+    RUNNING_CHILDREN.fetch_add(1, Ordering::SeqCst);
+    ChildGuard { _private: () }
+}
+
+/// Marca que um programa interativo (ex: o paginador) está no controle do
+/// terminal até que o valor retornado seja descartado.
+///
+/// O `less` ignora o Ctrl-C; se o `gitph` encerrasse nesse meio tempo, o
+/// paginador ficaria órfão, disputando o terminal com o shell. Por isso, o
+/// manipulador ignora o Ctrl-C enquanto a marcação estiver ativa.
+pub fn foreground_program() -> ForegroundGuard {
+    FOREGROUND_PROGRAM.store(true, Ordering::SeqCst);
+    ForegroundGuard { _private: () }
+}
+
+/// Mantém um programa interativo marcado como no controle do terminal (veja
+/// `foreground_program`).
+pub struct ForegroundGuard {
+    _private: (),
+}
+
+impl Drop for ForegroundGuard {
+    fn drop(&mut self) {
+        FOREGROUND_PROGRAM.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Mantém um processo do Git contado como em execução (veja `track_child`).
+pub struct ChildGuard {
+    _private: (),
+}
+
+impl Drop for ChildGuard {
+    fn drop(&mut self) {
+        RUNNING_CHILDREN.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// O manipulador do Ctrl-C, executado em uma thread própria do `ctrlc`.
+fn handle_interrupt() {
+    // O Ctrl-C pertence ao programa interativo em execução (ex: o `less`),
+    // que decide o que fazer com ele.
+    if FOREGROUND_PROGRAM.load(Ordering::SeqCst) {
+        return;
+    }
+
+    // Um segundo Ctrl-C (ex: o usuário impaciente) encerra imediatamente.
+    if INTERRUPTED.swap(true, Ordering::SeqCst) {
+        std::process::exit(INTERRUPTED_EXIT_CODE);
+    }
+
+    // As esperas em andamento veem a marcação e encerram seus processos.
+    let deadline = Instant::now() + CHILD_SHUTDOWN_GRACE;
+    while RUNNING_CHILDREN.load(Ordering::SeqCst) > 0 && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    if let Ok(bars) = ACTIVE_PROGRESS.lock() {
+        for bar in bars.iter().filter_map(WeakProgressBar::upgrade) {
+            bar.finish_and_clear();
+        }
+    }
+
+    let _ = Term::stdout().show_cursor();
+    let _ = Term::stderr().show_cursor();
+    eprintln!("\n{}", style("Operação interrompida.").yellow());
+    std::process::exit(INTERRUPTED_EXIT_CODE);
+}
\ No newline at end of file
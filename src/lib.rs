@@ -0,0 +1,38 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Raiz da Biblioteca 'gitph'
+//
+// Todos os módulos da aplicação vivem nesta biblioteca, e o binário
+// (`main.rs`) apenas a utiliza. A separação existe para que os testes de
+// integração em `tests/` possam exercitar os módulos (em especial, o
+// `git_wrapper`) contra repositórios Git reais, como um usuário externo.
+// ==============================================================================
+
+/// Clientes assíncronos das APIs do GitHub e do GitLab.
+pub mod api_client;
+
+/// A interface de linha de comando não-interativa (`clap`).
+pub mod cli;
+
+/// A configuração persistente do `gitph`.
+pub mod config;
+
+/// A única interface autorizada com o executável do Git.
+pub mod git_wrapper;
+
+/// A geração de arquivos `.gitignore`.
+pub mod gitignore;
+
+/// O tratamento do Ctrl-C, que restaura o terminal antes de encerrar.
+pub mod interrupt;
+
+/// A fronteira FFI com o módulo nativo C++.
+pub mod native_bindings;
+
+/// Os menus e prompts interativos do terminal.
+pub mod ui;
\ No newline at end of file
@@ -18,18 +18,11 @@
 //    o painel de menu interativo, guiando o usuário pelas funcionalidades.
 // ==============================================================================
 
-// --- Declaração de Módulos ---
-// A estrutura de módulos da nossa aplicação.
-mod api_client;
-mod cli;
-mod config;
-mod git_wrapper;
-mod native_bindings;
-mod ui;
-
 // --- Importações (`use`) ---
-use crate::cli::Cli; // Importamos a struct principal da nossa definição de CLI.
+// Os módulos da aplicação vivem na biblioteca `gitph` (veja `lib.rs`).
 use anyhow::Result;
+use gitph::cli::{self, Cli}; // Importamos a struct principal da nossa definição de CLI.
+use gitph::{config, git_wrapper, interrupt, ui};
 use clap::Parser; // Importamos o trait `Parser` para ter acesso ao método `.parse()`.
 
 /// Função principal que é executada quando o programa inicia.
@@ -44,7 +37,86 @@ fn main() -> Result<()> {
     // com isso e encerra a aplicação automaticamente.
     let cli_args = Cli::parse();
 
-    // --- PASSO 2: Decidir o Fluxo de Execução ---
+    // --- PASSO 1.1: Ajustar a Saída (Cores e Modo Silencioso) ---
+    // Seguindo a convenção de https://no-color.org, qualquer valor não vazio em
+    // `NO_COLOR` desativa as cores, assim como a flag `--no-color`. Todo o
+    // `console::style` da aplicação (e os temas do `dialoguer`) passam a
+    // produzir texto puro.
+    let no_color_env = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    let colors_enabled = !cli_args.no_color && !no_color_env;
+    if !colors_enabled {
+        console::set_colors_enabled(false);
+        console::set_colors_enabled_stderr(false);
+    }
+
+    // Com `--quiet`, as mensagens decorativas (cabeçalhos, sucessos, spinners)
+    // são descartadas pelo `ui::output`; erros e dados continuam na saída.
+    ui::output::set_quiet(cli_args.quiet);
+
+    // --- PASSO 1.2: Inicializar o Logging ---
+    // Por padrão, apenas avisos e erros são registrados (ou o que a variável
+    // `RUST_LOG` definir). A flag `--verbose` sobrepõe o nível para os módulos
+    // do `gitph`: `-v` habilita `debug` e `-vv` habilita `trace`.
+    init_logging(cli_args.verbose, colors_enabled);
+
+    // --- PASSO 1.3: Definir o Diretório de Trabalho ---
+    // Deve vir antes de qualquer leitura de configuração, já que o
+    // `.gitph.toml` do repositório é procurado a partir deste diretório.
+    if let Some(dir) = &cli_args.directory {
+        git_wrapper::set_working_dir(dir)?;
+    }
+
+    // --- PASSO 1.4: Aplicar o Tempo Limite das Operações de Rede ---
+    // Uma configuração inválida não impede a inicialização aqui: o erro será
+    // reportado pelo comando que precisar dela (ou por `gitph config validate`).
+    if let Some(secs) = config::load().ok().and_then(|c| c.network_timeout_secs) {
+        if secs > 0 {
+            git_wrapper::set_network_timeout(std::time::Duration::from_secs(secs));
+        }
+    }
+
+    // --- PASSO 1.5: Ativar o Modo Offline ---
+    // A flag `--offline` ou o campo `offline` da configuração fazem as
+    // operações de rede falharem antes de tentar a conexão.
+    if cli_args.offline || config::load().is_ok_and(|c| c.offline) {
+        config::set_offline(true);
+    }
+
+    // --- PASSO 1.6: Verificar a Versão do Git ---
+    // Um Git antigo não impede o uso, mas algumas ações falhariam com erros
+    // obscuros; avisamos logo no início. Se o Git nem puder ser executado, o
+    // erro aparecerá (com mais contexto) na primeira ação que precisar dele.
+    match git_wrapper::git_version() {
+        Ok(version) if version < git_wrapper::MIN_GIT_VERSION => {
+            let format = |(major, minor, patch): git_wrapper::GitVersion| format!("{}.{}.{}", major, minor, patch);
+            eprintln!(
+                "{}",
+                console::style(format!(
+                    "Aviso: o Git {} instalado é mais antigo que o mínimo suportado ({}). Algumas ações podem falhar.",
+                    format(version),
+                    format(git_wrapper::MIN_GIT_VERSION)
+                ))
+                .yellow()
+            );
+        }
+        Ok(_) => {}
+        Err(e) => ::log::warn!("Não foi possível determinar a versão do Git: {}", e),
+    }
+
+    // --- PASSO 1.7: Tratar o Ctrl-C ---
+    // Uma interrupção limpa os spinners, encerra o processo do Git em
+    // andamento e devolve o cursor antes de sair (veja `interrupt.rs`).
+    interrupt::install_handler()?;
+
+    // --- PASSO 2: Inicializar o Runtime Assíncrono ---
+    // Os clientes de API (`api_client`) são assíncronos. Criamos um único runtime
+    // Tokio para toda a aplicação e "entramos" nele, para que as chamadas a
+    // `api_client::block_on` em qualquer ponto da UI encontrem este runtime.
+    // O `_runtime_guard` deve viver até o fim de `main`.
+    let runtime = tokio::runtime::Runtime::new()?;
+    let _runtime_guard = runtime.enter();
+
+    // --- PASSO 3: Decidir o Fluxo de Execução ---
     // Verificamos se o campo `command` da nossa struct `Cli` contém `Some(comando)`.
     // `if let` é a maneira idiomática e limpa em Rust para fazer isso.
     if let Some(command) = cli_args.command {
@@ -62,4 +134,29 @@ fn main() -> Result<()> {
     // interativa) foi concluída sem erros fatais. Retornamos `Ok(())` para
     // indicar um encerramento bem-sucedido para o sistema operacional.
     Ok(())
+}
+
+/// Configura o `env_logger` de acordo com o nível de verbosidade solicitado.
+///
+/// # Arguments
+/// * `verbosity` - Quantas vezes a flag `-v` foi informada.
+/// * `colors_enabled` - Se `false`, os níveis de log são exibidos sem cores.
+fn init_logging(verbosity: u8, colors_enabled: bool) {
+    let mut builder =
+        env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn"));
+
+    let level = match verbosity {
+        0 => None,
+        1 => Some(log::LevelFilter::Debug),
+        _ => Some(log::LevelFilter::Trace),
+    };
+    if let Some(level) = level {
+        builder.filter_module(env!("CARGO_CRATE_NAME"), level);
+    }
+
+    if !colors_enabled {
+        builder.write_style(env_logger::WriteStyle::Never);
+    }
+
+    builder.format_timestamp(None).init();
 }
\ No newline at end of file
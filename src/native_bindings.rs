@@ -0,0 +1,217 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Ligações (Bindings) FFI para o Módulo Nativo 'git_optim'
+//
+// Este módulo é a ÚNICA fronteira entre o código Rust e a biblioteca C++
+// compilada pelo `build.rs`. Ele tem duas camadas:
+//
+// 1. Declarações `extern "C"` cruas, que espelham exatamente `optim.h`.
+//    Chamá-las é `unsafe`, pois o compilador Rust não pode verificar o que
+//    acontece do outro lado da fronteira.
+// 2. Funções seguras (`pub fn`) que validam as entradas, encapsulam o bloco
+//    `unsafe` e convertem os resultados em tipos idiomáticos de Rust.
+//
+// O resto da aplicação deve usar SOMENTE a camada segura.
+// ==============================================================================
+
+use anyhow::{anyhow, Result};
+use std::ffi::CString;
+use std::os::raw::c_char;
+
+/// Espelho, em Rust, da struct `DiffStatsResult` declarada em `optim.h`.
+///
+/// `#[repr(C)]` garante que o layout em memória (ordem e alinhamento dos
+/// campos) seja idêntico ao da struct C++. Os tipos correspondem um a um:
+/// `uint64_t` ↔ `u64`.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+struct RawDiffStats {
+    files_changed: u64,
+    lines_added: u64,
+    lines_removed: u64,
+}
+
+extern "C" {
+    /// `void hello_from_cpp();`
+    fn hello_from_cpp();
+
+    /// `int32_t perform_complex_calculation(int32_t input);`
+    fn perform_complex_calculation(input: i32) -> i32;
+
+    /// `int32_t get_string_length_from_cpp(const char* text);`
+    ///
+    /// Retorna -1 se o ponteiro for nulo.
+    fn get_string_length_from_cpp(text: *const c_char) -> i32;
+
+    /// `int32_t compute_diff_stats(const char* data, size_t length, DiffStatsResult* out);`
+    ///
+    /// Retorna 0 em caso de sucesso, ou -1 se um dos ponteiros for nulo.
+    fn compute_diff_stats(data: *const c_char, length: usize, out: *mut RawDiffStats) -> i32;
+
+    /// `int32_t compute_numstat_totals(const char* data, size_t length, DiffStatsResult* out);`
+    ///
+    /// Retorna 0 em caso de sucesso, ou -1 se um dos ponteiros for nulo.
+    fn compute_numstat_totals(data: *const c_char, length: usize, out: *mut RawDiffStats) -> i32;
+}
+
+/// Estatísticas de um diff: arquivos alterados e linhas adicionadas/removidas.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DiffStats {
+    pub files_changed: u64,
+    pub lines_added: u64,
+    pub lines_removed: u64,
+}
+
+/// Calcula as estatísticas de um diff no formato unificado (saída de `git diff`).
+///
+/// A contagem é feita pelo módulo nativo C++ em uma única passagem pelo buffer,
+/// o que é significativamente mais rápido que uma análise linha a linha em
+/// Rust para diffs grandes.
+///
+/// # Arguments
+/// * `diff` - O texto completo do diff.
+///
+/// # Returns
+/// `Ok(DiffStats)` com as contagens, ou `Err` se a função nativa reportar falha.
+pub fn diff_stats(diff: &str) -> Result<DiffStats> {
+    let mut raw = RawDiffStats::default();
+
+    // SEGURANÇA:
+    // - Passamos o ponteiro e o tamanho em BYTES do `&str`. A função C++ não
+    //   depende de um terminador nulo, então não precisamos de um `CString`
+    //   (que falharia se o diff contivesse um byte nulo).
+    // - Como o tamanho é explícito, a função lê exatamente o buffer da string,
+    //   e os marcadores que ela procura são ASCII, portanto nunca há leitura
+    //   "no meio" de um caractere UTF-8.
+    // - `&mut raw` é um ponteiro válido e alinhado para uma `RawDiffStats`,
+    //   que vive até o fim desta função.
+    // - A função não retém nenhum dos ponteiros após retornar.
+    let code = unsafe { compute_diff_stats(diff.as_ptr() as *const c_char, diff.len(), &mut raw) };
+
+    if code != 0 {
+        return Err(anyhow!(
+            "O módulo nativo falhou ao calcular as estatísticas do diff (código {}).",
+            code
+        ));
+    }
+
+    Ok(DiffStats {
+        files_changed: raw.files_changed,
+        lines_added: raw.lines_added,
+        lines_removed: raw.lines_removed,
+    })
+}
+
+/// Soma as colunas de uma saída `--numstat` (ex: de `git log --numstat`).
+///
+/// Assim como `diff_stats`, a contagem é feita pelo módulo nativo em uma única
+/// passagem, o que importa em históricos com milhares de commits. Cada linha
+/// do `--numstat` conta como um arquivo alterado.
+///
+/// # Arguments
+/// * `numstat` - A saída completa do comando.
+///
+/// # Returns
+/// `Ok(DiffStats)` com as somas, ou `Err` se a função nativa reportar falha.
+pub fn numstat_totals(numstat: &str) -> Result<DiffStats> {
+    let mut raw = RawDiffStats::default();
+
+    // SEGURANÇA: as mesmas garantias de `diff_stats` — ponteiro e tamanho em
+    // bytes de um `&str` válido, `&mut raw` válido e alinhado, e nenhum
+    // ponteiro retido pela função após retornar.
+    let code = unsafe { compute_numstat_totals(numstat.as_ptr() as *const c_char, numstat.len(), &mut raw) };
+
+    if code != 0 {
+        return Err(anyhow!(
+            "O módulo nativo falhou ao somar as estatísticas do histórico (código {}).",
+            code
+        ));
+    }
+
+    Ok(DiffStats {
+        files_changed: raw.files_changed,
+        lines_added: raw.lines_added,
+        lines_removed: raw.lines_removed,
+    })
+}
+
+
+/// Executa o autoteste da integração FFI com o módulo nativo.
+///
+/// Chama cada função exportada por `optim.h` com entradas conhecidas e compara
+/// os resultados com os valores esperados. Este teste é uma ferramenta de
+/// desenvolvimento, exposta pelo subcomando oculto `gitph selftest`.
+///
+/// # Returns
+/// `Ok(())` se todas as chamadas retornarem o esperado, ou `Err` descrevendo
+/// a primeira verificação que falhou.
+pub fn run_self_test() -> Result<()> {
+    // SEGURANÇA: `hello_from_cpp` não recebe argumentos e apenas escreve no stdout.
+    unsafe { hello_from_cpp() };
+
+    // SEGURANÇA: a função recebe e retorna inteiros por valor, sem ponteiros.
+    let calculated = unsafe { perform_complex_calculation(16) };
+    if calculated != 42 {
+        return Err(anyhow!(
+            "perform_complex_calculation(16) retornou {}, esperado 42.",
+            calculated
+        ));
+    }
+
+    let text = "Olá, FFI!";
+    let c_text = CString::new(text)?;
+    // SEGURANÇA: `c_text` é uma string válida terminada em nulo que vive até o
+    // fim desta função, e a função C++ não retém o ponteiro.
+    let length = unsafe { get_string_length_from_cpp(c_text.as_ptr()) };
+    if length != text.len() as i32 {
+        return Err(anyhow!(
+            "get_string_length_from_cpp retornou {}, esperado {}.",
+            length,
+            text.len()
+        ));
+    }
+
+    // SEGURANÇA: a função C++ verifica explicitamente o ponteiro nulo.
+    let null_length = unsafe { get_string_length_from_cpp(std::ptr::null()) };
+    if null_length != -1 {
+        return Err(anyhow!(
+            "get_string_length_from_cpp(NULL) retornou {}, esperado -1.",
+            null_length
+        ));
+    }
+
+    let stats = diff_stats("diff --git a/x b/x\n--- a/x\n+++ b/x\n@@ -1 +1 @@\n-antigo\n+novo\n")?;
+    let expected = DiffStats {
+        files_changed: 1,
+        lines_added: 1,
+        lines_removed: 1,
+    };
+    if stats != expected {
+        return Err(anyhow!(
+            "compute_diff_stats retornou {:?}, esperado {:?}.",
+            stats,
+            expected
+        ));
+    }
+
+    let totals = numstat_totals("3\t1\tsrc/a.rs\n-\t-\tlogo.png\n\n2\t0\tREADME.md\n")?;
+    let expected = DiffStats {
+        files_changed: 3,
+        lines_added: 5,
+        lines_removed: 1,
+    };
+    if totals != expected {
+        return Err(anyhow!(
+            "compute_numstat_totals retornou {:?}, esperado {:?}.",
+            totals,
+            expected
+        ));
+    }
+
+    Ok(())
+}
\ No newline at end of file
@@ -15,12 +15,16 @@
 // --- Importações ---
 // Trazemos todos os módulos e tipos que usaremos para o escopo local.
 use crate::api_client;
-use crate::git_wrapper::{branch, clone, commit, push, remote, status::{self, ChangeType, GitStatus}, tag};
-use crate::ui::prompts;
+use crate::config;
+use crate::gitignore::{self, TemplateSource, WriteMode};
+use crate::git_wrapper::{self, blame, branch, cherry_pick, clone, commit, config as git_config, diff, fetch, init, log, pull, rebase, worktree, push, remote, reset, restore, stash, stats, status::{self, ChangeType, GitStatus, RepoSummary}, submodule, tag, VerificationStatus};
+use crate::native_bindings;
+use crate::ui::{output, pager, plan, prompts};
 use anyhow::Result;
-use console::{style, Term};
+use console::{style, truncate_str, Color, Key, Term};
 use dialoguer::{theme::ColorfulTheme, Select};
 use std::io::BufRead;
+use std::path::{Path, PathBuf};
 
 /// Exibe o menu principal da aplicação em um loop contínuo.
 ///
@@ -30,7 +34,7 @@ use std::io::BufRead;
 pub fn show_main_menu() -> Result<()> {
     let term = Term::stdout();
     let options = &[
-        "[1] Setar Repositório por link (srp)", // Ainda não implementado
+        "[1] Setar Repositório por link (srp)",
         "[2] Adicionar, Commitar, Pushar (snd)",
         "[3] SND e Criar Tag/Release (rls)",
         "[4] Push para branch específica (psor)", // Ainda não implementado
@@ -39,20 +43,56 @@ pub fn show_main_menu() -> Result<()> {
         "[7] Mudar de Branch (cb)",
         "[8] Clonar Repositório (clone)",
         "[9] Gerenciar Workflow (cwf)", // Ainda não implementado
-        "[10] Sair",
+        "[10] Corrigir Último Commit (amend)",
+        "[11] Gerenciar Releases do GitHub (releases)",
+        "[12] Buscar Atualizações do Remoto (fetch)",
+        "[13] Gerenciar Remotos (remotes)",
+        "[14] Inicializar Repositório (init)",
+        "[15] Gerar .gitignore (gitignore)",
+        "[16] Aplicar Commit de Outra Branch (cherry-pick)",
+        "[17] Rebase da Branch Atual (rebase)",
+        "[18] Gerenciar Worktrees (worktree)",
+        "[19] Incluir Arquivo Esquecido no Último Commit (fixup)",
+        "[20] Ver Grafo do Histórico (graph)",
+        "[21] Ver Autores das Linhas de um Arquivo (blame)",
+        "[22] Gerenciar Tags (tags)",
+        "[23] Issues do GitHub (issues)",
+        "[24] Limpar Branches Já Integradas (cleanup)",
+        "[25] Reformular Mensagem do Último Commit (reword)",
+        "[26] Sincronizar com o Remoto (sync)",
+        "[27] Desfazer Último Commit (undo)",
+        "[28] Estatísticas do Repositório (stats)",
+        "[29] Gerenciar Stashes (stash)",
+        "[30] Verificar Autenticação no GitHub (whoami)",
+        "[31] Adicionar ao Stage por Trechos (add -p)",
+        "[32] Gerenciar Submódulos (submodule)",
+        "[33] Ativar/Desativar Modo Plano (plan)",
+        "[34] Recuperar Commits pelo Reflog (reflog)",
+        "[35] Puxar Alterações do Remoto (pull)",
+        "[36] Excluir Branch Local (delete-branch)",
+        "[37] Renomear Branch Local (rename)",
+        "[38] Sair",
     ];
 
     loop {
         term.clear_screen()?;
         println!("==============================================");
         println!("  gitph - Seu Assistente de Git Inteligente");
-        println!("==============================================\n");
+        println!("==============================================");
+        if config::is_offline() {
+            println!("{}", style("  ⚠ Modo offline: push, fetch, clone e APIs desativados.").yellow().bold());
+        }
+        if plan::is_plan_mode() {
+            println!("{}", style("  ⚠ Modo plano: snd e rls apenas exibem os comandos, sem executá-los.").yellow().bold());
+        }
+        display_repository_context();
+        println!();
 
-        let selection = Select::with_theme(&ColorfulTheme::default())
-            .items(options)
-            .with_prompt("Navegue com as setas e pressione Enter para selecionar uma ação:")
-            .default(0)
-            .interact_on_opt(&term)?;
+        let selection = select_with_hotkeys(
+            &term,
+            "Navegue com as setas (ou digite o número) e pressione Enter para selecionar uma ação:",
+            options,
+        )?;
 
         match selection {
             Some(index) => {
@@ -71,18 +111,197 @@ pub fn show_main_menu() -> Result<()> {
     Ok(())
 }
 
+/// Exibe uma lista de seleção que aceita, além das setas, o número da opção.
+///
+/// Os números vêm dos próprios rótulos (o `N` de "[N] ..."), então o atalho
+/// sempre corresponde ao que está na tela. Dígitos digitados em sequência
+/// formam números de dois algarismos: "1" destaca a opção [1] e espera, pois
+/// "10" a "19" também começam com ele; "12" seleciona a [12] imediatamente.
+/// Um número sem outras opções que comecem com ele (ex: "4") é selecionado
+/// na hora, e Enter confirma a opção destacada.
+///
+/// Se as opções não couberem no terminal, apenas uma janela delas é exibida,
+/// rolando para manter a opção destacada visível.
+///
+/// # Returns
+/// `Ok(Some(índice))` com a opção escolhida, ou `Ok(None)` se o usuário
+/// pressionar Esc.
+fn select_with_hotkeys(term: &Term, prompt: &str, options: &[&str]) -> Result<Option<usize>> {
+    let hotkeys: Vec<Option<&str>> = options
+        .iter()
+        .map(|label| label.strip_prefix('[').and_then(|rest| rest.split_once(']')).map(|(n, _)| n))
+        .collect();
+    let mut selected = 0;
+    let mut first = 0;
+    let mut typed = String::new();
+
+    term.hide_cursor()?;
+    let selection = loop {
+        // Cabem na tela o título, a linha de ajuda e as opções, deixando a
+        // última linha para o cursor. As linhas são cortadas na largura do
+        // terminal: uma linha quebrada desalinharia o `clear_last_lines`.
+        let (rows, columns) = term.size();
+        let visible = (rows as usize).saturating_sub(3).clamp(1, options.len());
+        if selected < first {
+            first = selected;
+        } else if selected >= first + visible {
+            first = selected + 1 - visible;
+        }
+        first = first.min(options.len() - visible);
+        let width = (columns as usize).saturating_sub(2).max(1);
+
+        term.write_line(&truncate_str(&format!("{} {}", style("?").yellow(), style(prompt).bold()), width + 2, "…"))?;
+        for (index, label) in options.iter().enumerate().skip(first).take(visible) {
+            let label = truncate_str(label, width, "…");
+            if index == selected {
+                term.write_line(&format!("{} {}", style("❯").green(), style(label).cyan()))?;
+            } else {
+                term.write_line(&format!("  {}", label))?;
+            }
+        }
+        let typed_hint = if typed.is_empty() { String::new() } else { format!(" · número: {}", typed) };
+        let position = if visible < options.len() {
+            format!("{}/{} · ", selected + 1, options.len())
+        } else {
+            String::new()
+        };
+        let help = format!(
+            "{}↑/↓ navegar · 1-{} ir direto · Enter selecionar · Esc sair{}",
+            position,
+            options.len(),
+            typed_hint
+        );
+        term.write_line(&style(truncate_str(&help, width + 2, "…")).dim().to_string())?;
+
+        let key = match term.read_key() {
+            Ok(key) => key,
+            Err(e) => {
+                let _ = term.show_cursor();
+                return Err(e.into());
+            }
+        };
+        term.clear_last_lines(visible + 2)?;
+
+        match key {
+            Key::ArrowUp => {
+                selected = (selected + options.len() - 1) % options.len();
+                typed.clear();
+            }
+            Key::ArrowDown => {
+                selected = (selected + 1) % options.len();
+                typed.clear();
+            }
+            Key::Home => selected = 0,
+            Key::End => selected = options.len() - 1,
+            Key::Enter => break Some(selected),
+            Key::Escape => break None,
+            Key::Backspace => {
+                typed.pop();
+            }
+            Key::Char(c) if c.is_ascii_digit() => {
+                typed.push(c);
+                let starts_with = |prefix: &str| {
+                    (0..options.len())
+                        .filter(|&i| hotkeys[i].is_some_and(|n| n.starts_with(prefix)))
+                        .collect::<Vec<_>>()
+                };
+                let mut candidates = starts_with(&typed);
+                if candidates.is_empty() {
+                    // Nenhuma opção continua a sequência: o dígito começa um novo número.
+                    typed = c.to_string();
+                    candidates = starts_with(&typed);
+                }
+                let exact = (0..options.len()).find(|&i| hotkeys[i] == Some(typed.as_str()));
+                match (exact, candidates.len()) {
+                    (Some(index), 1) => break Some(index),
+                    (Some(index), _) => selected = index,
+                    (None, 0) => typed.clear(),
+                    (None, _) => selected = candidates[0],
+                }
+            }
+            _ => {}
+        }
+    };
+    term.show_cursor()?;
+    Ok(selection)
+}
+
+/// Exibe, no cabeçalho do menu, a raiz do repositório, a branch atual e sua
+/// sincronia com o upstream.
+///
+/// O contexto é recalculado a cada exibição do menu, pois as ações (trocar de
+/// branch, commitar, fazer push) o alteram.
+fn display_repository_context() {
+    if let Ok(root) = git_wrapper::repo_root() {
+        println!("  Repo: {}", style(root.display()).dim());
+    }
+    match branch::current_branch() {
+        Ok(Some(name)) => {
+            // O cabeçalho só precisa do ahead/behind: não há por que guardar
+            // todos os arquivos de um diretório com milhares de alterações.
+            let (ahead, behind) = status::get_status_limited(status::DEFAULT_MAX_FILES)
+                .map(|s| s.ahead_behind())
+                .unwrap_or((0, 0));
+            let mut sync = Vec::new();
+            if ahead > 0 {
+                sync.push(style(format!("↑{}", ahead)).green().to_string());
+            }
+            if behind > 0 {
+                sync.push(style(format!("↓{}", behind)).yellow().to_string());
+            }
+            println!("  Branch: {} {}", style(name).cyan().bold(), sync.join(" "));
+        }
+        Ok(None) => {
+            // Commits feitos em detached HEAD não pertencem a nenhuma branch e
+            // são fáceis de perder, então o aviso é bem visível.
+            println!("{}", style("  ⚠ Você está em detached HEAD (fora de qualquer branch).").red().bold());
+            println!("{}", style("  Use \"Ver Status\" para criar uma branch neste ponto.").red());
+        }
+        Err(_) => println!("  {}", style("(nenhum repositório)").dim()),
+    }
+}
+
 /// Despacha a ação selecionada no menu para a função correspondente.
 /// Retorna `Ok(true)` para continuar o loop ou `Ok(false)` para sair.
 fn handle_menu_action(index: usize) -> Result<bool> {
     // O `match` usa o índice do array `options` para decidir qual ação tomar.
     match index {
+        0 => handle_srp_action()?,
         1 => handle_snd_action()?,
         2 => handle_rls_action()?,
         4 => handle_status_action()?,
         5 => handle_create_branch_action()?,
         6 => handle_switch_branch_action()?,
         7 => handle_clone_action()?,
-        9 => {
+        9 => handle_amend_action()?,
+        10 => handle_releases_action()?,
+        11 => handle_fetch_action()?,
+        12 => handle_remotes_action()?,
+        13 => handle_init_action()?,
+        14 => handle_gitignore_action()?,
+        15 => handle_cherry_pick_action()?,
+        16 => handle_rebase_action()?,
+        17 => handle_worktree_action()?,
+        18 => handle_forgotten_file_action()?,
+        19 => handle_graph_action()?,
+        20 => handle_blame_action()?,
+        21 => handle_tags_action()?,
+        22 => handle_issues_action()?,
+        23 => handle_cleanup_branches_action()?,
+        24 => handle_reword_action()?,
+        25 => handle_sync_action()?,
+        26 => handle_undo_commit_action()?,
+        27 => handle_stats_action()?,
+        28 => handle_stash_action()?,
+        29 => handle_whoami_action()?,
+        30 => handle_add_patch_action()?,
+        31 => handle_submodule_action()?,
+        32 => handle_toggle_plan_mode(),
+        33 => handle_reflog_action()?,
+        34 => handle_pull_action()?,
+        35 => handle_delete_branch_action()?,
+        36 => handle_rename_branch_action()?,
+        37 => {
             println!("Obrigado por usar o gitph. Até logo!");
             return Ok(false); // Sinaliza para sair do loop.
         }
@@ -99,99 +318,419 @@ fn handle_menu_action(index: usize) -> Result<bool> {
 // --- Implementações dos Manipuladores de Ação ---
 
 /// Lida com a ação "Adicionar, Commitar, Pushar".
-fn handle_snd_action() -> Result<()> {
-    println!("{}", style("Iniciando fluxo de trabalho: Adicionar, Commitar, Pushar").bold().cyan());
-    println!("----------------------------------------------------------");
-    let _ = run_snd_flow()?;
+///
+/// Também é usada pelo subcomando `snd`. No modo plano, apenas exibe o plano.
+pub fn handle_snd_action() -> Result<()> {
+    if plan::is_plan_mode() {
+        return show_plan(plan::snd_plan());
+    }
+    output::header("Iniciando fluxo de trabalho: Adicionar, Commitar, Pushar");
+    output::info("----------------------------------------------------------");
+    if run_snd_flow()? {
+        offer_pull_request()?;
+    }
+    Ok(())
+}
+
+/// Após um push bem-sucedido em uma branch que não é a padrão, oferece abrir
+/// um Pull Request no GitHub.
+fn offer_pull_request() -> Result<()> {
+    let head = match branch::list_branches()?.into_iter().find(|b| b.is_current) {
+        Some(b) => b.name,
+        None => return Ok(()),
+    };
+    let base = resolve_default_branch();
+    if head == base {
+        return Ok(());
+    }
+
+    output::blank_line();
+    let prompt = format!("Deseja abrir um Pull Request a partir de '{}'?", head);
+    if !prompts::confirm(&prompt, false)? {
+        return Ok(());
+    }
+
+    let (owner, repo) = match detect_github_repo() {
+        Some(data) => data,
+        None => return Ok(()),
+    };
+
+    // A branch padrão é apenas uma sugestão: o PR pode mirar outra base.
+    let base = match prompts::get_text("Branch de destino (base) do Pull Request", &base)? {
+        Some(b) if !b.trim().is_empty() => b.trim().to_string(),
+        _ => {
+            output::warning("Operação cancelada.");
+            return Ok(());
+        }
+    };
+
+    // Sugerimos como título o assunto do último commit e, como descrição,
+    // a lista de commits que a branch traz em relação à base.
+    let base_ref = format!("origin/{}", base);
+    let commits = log::commits_since(Some(base_ref.as_str())).unwrap_or_default();
+    let suggested_title = commits.first().map(|c| c.subject.clone()).unwrap_or_default();
+    let title = match prompts::get_text("Título do Pull Request", &suggested_title)? {
+        Some(t) if !t.trim().is_empty() => t,
+        _ => {
+            output::warning("Título inválido ou operação cancelada.");
+            return Ok(());
+        }
+    };
+    let body: String = commits.iter().map(|c| format!("- {}\n", c.subject)).collect();
+
+    match api_client::block_on(api_client::github::create_pull_request(&owner, &repo, &head, &base, &title, &body)) {
+        Ok(pr) => {
+            output::success(style(format!("✔ Pull Request #{} aberto com sucesso!", pr.number)).bold());
+            output::data(format!("Acesse em: {}", pr.html_url));
+        }
+        Err(e) => {
+            output::error(format!("Falha ao abrir o Pull Request: {}", e));
+        }
+    }
     Ok(())
 }
 
+/// Descobre a branch padrão do remoto 'origin', sem assumir "main".
+///
+/// Primeiro consulta a referência local `origin/HEAD` (sem rede). Se ela não
+/// estiver configurada e o remoto for do GitHub, pergunta à API (com cache
+/// por sessão). Só então recorre a `init::DEFAULT_INITIAL_BRANCH`.
+fn resolve_default_branch() -> String {
+    if let Ok(Some(branch)) = remote::default_branch() {
+        return branch;
+    }
+
+    let from_api = remote::origin_github_repo()
+        .ok()
+        .filter(|_| config::load().map(|c| c.github_token.is_some()).unwrap_or(false))
+        .and_then(|(owner, repo)| {
+            api_client::block_on(api_client::github::default_remote_branch(&owner, &repo)).ok()
+        });
+
+    from_api.unwrap_or_else(|| init::DEFAULT_INITIAL_BRANCH.to_string())
+}
+
 /// Orquestra o fluxo de trabalho "SND e Criar Release".
-fn handle_rls_action() -> Result<()> {
-    println!("{}", style("Iniciando fluxo de trabalho: Criar Nova Release").bold().cyan());
-    println!("----------------------------------------------------------");
+///
+/// Também é usada pelo subcomando `rls`. No modo plano, apenas exibe o plano.
+pub fn handle_rls_action() -> Result<()> {
+    if plan::is_plan_mode() {
+        return show_plan(plan::rls_plan());
+    }
+    output::header("Iniciando fluxo de trabalho: Criar Nova Release");
+    output::info("----------------------------------------------------------");
 
     if !run_snd_flow()? {
-        println!("\n{}", style("Fluxo de trabalho de release abortado pois a sincronização inicial não foi concluída.").yellow());
+        output::blank_line();
+        output::warning("Fluxo de trabalho de release abortado pois a sincronização inicial não foi concluída.");
         return Ok(());
     }
-    println!("----------------------------------------------------------");
-    println!("✔ Sincronização inicial concluída.");
+    output::info("----------------------------------------------------------");
+    output::success("✔ Sincronização inicial concluída.");
 
-    println!("\n2. Obtendo informações do repositório remoto...");
-    let (owner, repo) = match remote::get_origin_url().and_then(|url| remote::parse_github_owner_and_repo(&url)) {
+    output::info("\n2. Obtendo informações do repositório remoto...");
+    let remote_url = match remote::origin_remote() {
         Ok(data) => data,
         Err(e) => {
-            println!("{}", style("Erro:").red().bold());
-            println!("{}", style(e).red());
+            output::error(e);
             return Ok(());
         }
     };
-    println!("✔ Repositório detectado: {}/{}", owner, repo);
+    // O cliente de API usado para criar a release depende de onde o repositório está hospedado.
+    let forge = match api_client::forge_for_host(&remote_url.host) {
+        Some(forge) => forge,
+        None => {
+            output::error(format!("Host '{}' não suportado. Apenas GitHub e GitLab são suportados.", remote_url.host));
+            return Ok(());
+        }
+    };
+    output::success(format!("✔ Repositório detectado: {} ({})", remote_url.full_path(), remote_url.host));
+
+    // Verificamos o token antes de criar a tag, para não deixar uma tag
+    // publicada sem a release correspondente por falta de configuração.
+    if !ensure_api_token(forge)? {
+        return Ok(());
+    }
 
     let tag_name = match prompts::get_commit_message()? {
         Some(name) if !name.trim().is_empty() => name,
         _ => {
-            println!("{}", style("Nome da tag inválido ou operação cancelada.").yellow());
+            output::warning("Nome da tag inválido ou operação cancelada.");
             return Ok(());
         }
     };
 
     let release_title = tag_name.clone();
-    let release_notes = match prompts::get_release_notes()? {
+    let release_notes = match prompts::get_release_notes(&release_notes_template())? {
         Some(notes) if !notes.trim().is_empty() => notes,
         _ => {
-            println!("{}", style("Notas da release vazias ou operação cancelada.").yellow());
+            output::warning("Notas da release vazias ou operação cancelada.");
             return Ok(());
         }
     };
 
-    println!("\n3. Criando e enviando a tag Git...");
-    if let Err(e) = tag::create_annotated_tag(&tag_name, &release_title) {
-        println!("{}", style("Erro ao criar a tag local:").red().bold());
-        println!("{}", style(e).red());
+    // Opcionalmente, a tag aponta para um commit dedicado à release, em vez
+    // do último commit sincronizado, que pode não ter relação com a versão.
+    let config = config::load()?;
+    let release_commit_prompt = format!("Criar um commit 'chore: release {}' antes da tag?", tag_name);
+    if prompts::confirm(&release_commit_prompt, false)? {
+        output::info("\nCriando o commit de release...");
+        if let Err(e) = create_release_commit(&tag_name, &config) {
+            output::error(format!("Falha ao criar o commit de release: {}", e));
+            return Ok(());
+        }
+        output::success("✔ Commit de release criado e enviado.");
+    }
+
+    output::info("\n3. Criando e enviando a tag Git...");
+    let sign = config.sign_commits;
+    if let Err(e) = tag::create_annotated_tag(&tag_name, &release_title, sign) {
+        output::error(format!("Falha ao criar a tag local: {}", e));
         return Ok(());
     }
-    if let Err(e) = tag::push_tag(&tag_name) {
-        println!("{}", style("Erro ao enviar a tag para o remoto:").red().bold());
-        println!("{}", style(e).red());
+    if let Err(e) = tag::push_tag(&tag_name, false) {
+        output::error(format!("Falha ao enviar a tag para o remoto: {}", e));
         return Ok(());
     }
-    println!("✔ Tag '{}' criada e enviada com sucesso.", tag_name);
+    output::success(format!("✔ Tag '{}' criada e enviada com sucesso.", tag_name));
 
-    println!("\n4. Criando a Release no GitHub...");
-    match api_client::github::create_release(&owner, &repo, &tag_name, &release_title, &release_notes) {
-        Ok(()) => {
-            println!("{}", style("✔ Release criada com sucesso no GitHub!").green().bold());
-            println!("Acesse em: https://github.com/{}/{}/releases/tag/{}", owner, repo, tag_name);
+    let (owner, repo) = (&remote_url.owner, &remote_url.repo);
+    let result = match forge {
+        api_client::Forge::GitHub => {
+            output::info("\n4. Criando a Release no GitHub...");
+            api_client::block_on(api_client::github::create_release(owner, repo, &tag_name, &release_title, &release_notes))
+                .map(|()| format!("https://github.com/{}/{}/releases/tag/{}", owner, repo, tag_name))
+        }
+        api_client::Forge::GitLab => {
+            output::info("\n4. Criando a Release no GitLab...");
+            api_client::block_on(api_client::gitlab::create_release(&remote_url.host, &remote_url.full_path(), &tag_name, &release_title, &release_notes))
+                .map(|()| format!("https://{}/{}/-/releases/{}", remote_url.host, remote_url.full_path(), tag_name))
+        }
+    };
+    match result {
+        Ok(release_url) => {
+            output::success(style("✔ Release criada com sucesso!").bold());
+            output::data(format!("Acesse em: {}", release_url));
         }
         Err(e) => {
-            println!("{}", style("Erro ao criar a release no GitHub:").red().bold());
-            println!("{}", style(e).red());
+            output::error(format!("Falha ao criar a release: {}", e));
         }
     }
 
     Ok(())
 }
 
+/// Cria e envia o commit `chore: release <tag>` usado como alvo da tag.
+///
+/// Alterações pendentes (ex: o aumento da versão em um manifesto) entram no
+/// commit. Sem alterações, o commit é criado vazio, apenas para marcar a release.
+/// A assinatura e o sign-off seguem a configuração do usuário.
+fn create_release_commit(tag_name: &str, config: &config::Config) -> Result<()> {
+    commit::add_all()?;
+    let options = commit::CommitOptions {
+        sign: config.sign_commits,
+        signoff: config.signoff_by_default,
+        allow_empty: true,
+        ..Default::default()
+    };
+    commit::commit(&format!("chore: release {}", tag_name.trim()), options)?;
+    push::push(false)?;
+    Ok(())
+}
+
 /// Lida com a ação "Ver Status".
 fn handle_status_action() -> Result<()> {
     println!("Obtendo status do repositório Git...\n");
-    match status::get_status() {
-        Ok(status) => display_git_status(&status),
+    if let Ok(summary) = status::repo_summary() {
+        display_repo_summary(&summary);
+    }
+    let status = match status::get_status_limited(status::DEFAULT_MAX_FILES) {
+        Ok(status) => status,
         Err(e) => {
             println!("{}", style("Erro ao obter status:").red().bold());
             println!("{}", style(e).red());
+            return Ok(());
+        }
+    };
+    display_git_status(&status);
+    display_diff_stats();
+    display_outdated_submodules();
+
+    let has_changes = status.files.iter().any(|f| {
+        [&f.staged_status, &f.unstaged_status]
+            .into_iter()
+            .any(|c| c.is_some() && *c != Some(ChangeType::Untracked))
+    });
+    if has_changes && prompts::confirm("Deseja ver as diferenças completas (diff)?", false)? {
+        display_full_diff();
+    }
+
+    if let Ok(None) = branch::current_branch() {
+        offer_branch_from_detached_head()?;
+    }
+
+    offer_unstage_files(&status)?;
+    offer_discard_changes()
+}
+
+/// Oferece remover arquivos do stage, a partir da tela de status.
+///
+/// Fecha o ciclo com a dica "use 'git reset HEAD <arquivo>...'" exibida pelo status.
+fn offer_unstage_files(status: &GitStatus) -> Result<()> {
+    let staged_files: Vec<&status::FileStatus> = status
+        .files
+        .iter()
+        .filter(|f| f.staged_status.is_some() && f.staged_status != Some(ChangeType::Untracked))
+        .collect();
+    if staged_files.is_empty() {
+        return Ok(());
+    }
+
+    println!();
+    if !prompts::confirm("Deseja remover arquivos do stage?", false)? {
+        return Ok(());
+    }
+
+    let labels: Vec<String> = staged_files.iter().map(|f| f.display_path()).collect();
+    let selected = match prompts::multi_select("Marque com Espaço os arquivos a remover do stage:", &labels)? {
+        Some(indices) if !indices.is_empty() => indices,
+        _ => {
+            println!("{}", style("Nenhum arquivo selecionado. Operação cancelada.").yellow());
+            return Ok(());
+        }
+    };
+
+    let result = if selected.len() == staged_files.len() {
+        reset::reset_all()
+    } else {
+        let paths: Vec<String> = selected.iter().flat_map(|&i| staged_files[i].affected_paths()).collect();
+        reset::reset_paths(&paths)
+    };
+    match result {
+        Ok(()) => println!("✔ {} arquivo(s) removido(s) do stage.", selected.len()),
+        Err(e) => {
+            println!("{}", style("Erro ao remover arquivos do stage:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Oferece descartar as alterações fora do stage de arquivos escolhidos.
+///
+/// O status é lido novamente, pois a etapa anterior pode ter removido
+/// arquivos do stage. Arquivos novos (não rastreados) e em conflito não
+/// entram na lista: o `git restore` não se aplica a eles.
+fn offer_discard_changes() -> Result<()> {
+    let Ok(status) = status::get_status() else {
+        return Ok(());
+    };
+    let modified_files: Vec<&status::FileStatus> = status
+        .files
+        .iter()
+        .filter(|f| {
+            f.unstaged_status.is_some()
+                && f.unstaged_status != Some(ChangeType::Untracked)
+                && f.unstaged_status != Some(ChangeType::Unmerged)
+        })
+        .collect();
+    if modified_files.is_empty() {
+        return Ok(());
+    }
+
+    println!();
+    if !prompts::confirm("Deseja descartar alterações de arquivos (fora do stage)?", false)? {
+        return Ok(());
+    }
+
+    let labels: Vec<String> = modified_files.iter().map(|f| f.display_path()).collect();
+    let selected = match prompts::multi_select("Marque com Espaço os arquivos cujas alterações serão descartadas:", &labels)? {
+        Some(indices) if !indices.is_empty() => indices,
+        _ => {
+            println!("{}", style("Nenhum arquivo selecionado. Operação cancelada.").yellow());
+            return Ok(());
+        }
+    };
+
+    println!();
+    println!(
+        "{}",
+        style("ATENÇÃO: as alterações fora do stage destes arquivos serão PERDIDAS. Isto não pode ser desfeito.")
+            .red()
+            .bold()
+    );
+    for &i in &selected {
+        println!("  {}", style(&labels[i]).red());
+    }
+    if !prompts::confirm("Descartar as alterações permanentemente?", false)? {
+        println!("{}", style("Operação cancelada.").yellow());
+        return Ok(());
+    }
+
+    let paths: Vec<String> = selected.iter().map(|&i| modified_files[i].path.clone()).collect();
+    match restore::restore_paths(&paths) {
+        Ok(()) => println!("✔ Alterações de {} arquivo(s) descartadas.", selected.len()),
+        Err(e) => {
+            println!("{}", style("Erro ao descartar as alterações:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Avisa sobre o detached HEAD e oferece criar uma branch no commit atual.
+///
+/// Usa `branch::create_and_switch`, para que os commits feitos a partir daqui
+/// passem a pertencer à nova branch.
+fn offer_branch_from_detached_head() -> Result<()> {
+    println!();
+    println!("{}", style("⚠ Você está em detached HEAD.").red().bold());
+    println!("Commits feitos agora não pertencem a nenhuma branch e podem ser perdidos.");
+    if !prompts::confirm("Deseja criar uma branch aqui?", true)? {
+        return Ok(());
+    }
+
+    let branch_name = match prompts::get_branch_name()? {
+        Some(name) => name,
+        None => {
+            println!("{}", style("Nome de branch inválido ou operação cancelada.").yellow());
+            return Ok(());
         }
     };
+    match branch::create_and_switch(&branch_name) {
+        Ok(()) => println!("{}", style(format!("✔ Agora você está na branch '{}'.", branch_name.trim())).green()),
+        Err(e) => {
+            println!("{}", style("Erro ao criar a branch:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
     Ok(())
 }
 
+/// Descreve a sincronia de uma branch com seu upstream (ex: "origin/x: ahead 3").
+fn tracking_label(branch: &branch::BranchInfo, upstream: &str) -> String {
+    if branch.upstream_gone {
+        return format!("{}: removida do remoto", upstream);
+    }
+    let mut parts = Vec::new();
+    if branch.ahead > 0 {
+        parts.push(format!("ahead {}", branch.ahead));
+    }
+    if branch.behind > 0 {
+        parts.push(format!("behind {}", branch.behind));
+    }
+    if parts.is_empty() {
+        upstream.to_string()
+    } else {
+        format!("{}: {}", upstream, parts.join(", "))
+    }
+}
+
 /// Lida com a ação "Criar Nova Branch".
 fn handle_create_branch_action() -> Result<()> {
     println!("{}", style("Criar Nova Branch").bold().cyan());
-    let branch_name = match prompts::get_commit_message()? { // Reutiliza o prompt de linha única
-        Some(name) if !name.trim().is_empty() => name,
+    let branch_name = match prompts::get_branch_name()? {
+        Some(name) => name,
         _ => {
             println!("{}", style("Nome de branch inválido ou operação cancelada.").yellow());
             return Ok(());
@@ -225,28 +764,35 @@ fn handle_switch_branch_action() -> Result<()> {
         return Ok(());
     }
 
-    // Formata os nomes das branches para o menu, destacando a atual.
+    // Formata os nomes das branches para o menu, destacando a atual e
+    // mostrando a sincronia com o upstream (ex: "[origin/x: ahead 3]").
     let branch_names: Vec<String> = branches
         .iter()
         .map(|b| {
-            if b.is_current {
-                format!("* {}", b.name)
-            } else {
-                format!("  {}", b.name)
+            let marker = if b.is_current { "*" } else { " " };
+            match &b.upstream {
+                Some(upstream) => format!("{} {} [{}]", marker, b.name, tracking_label(b, upstream)),
+                None => format!("{} {}", marker, b.name),
             }
         })
         .collect();
 
-    let selection = Select::with_theme(&ColorfulTheme::default())
-        .items(&branch_names)
-        .with_prompt("Selecione a branch para a qual deseja mudar:")
-        .default(0)
-        .interact_on_opt(&Term::stdout())?;
+    // A branch atual começa destacada; digitar filtra a lista.
+    let current_index = branches.iter().position(|b| b.is_current).unwrap_or(0);
+    let selection = prompts::fuzzy_select(
+        "Digite para filtrar e selecione a branch para a qual deseja mudar:",
+        &branch_names,
+        current_index,
+    )?;
 
     if let Some(index) = selection {
         let target_branch = &branches[index].name;
         match branch::switch_branch(target_branch) {
             Ok(()) => println!("✔ Mudou para a branch '{}' com sucesso.", style(target_branch).cyan()),
+            Err(e) if e.downcast_ref::<branch::UncommittedChangesError>().is_some() => {
+                println!("{}", style(&e).yellow());
+                stash_and_switch(target_branch)?;
+            }
             Err(e) => {
                 println!("{}", style("Erro ao mudar de branch:").red().bold());
                 println!("{}", style(e).red());
@@ -259,6 +805,34 @@ fn handle_switch_branch_action() -> Result<()> {
     Ok(())
 }
 
+/// Oferece guardar as alterações locais no stash e então mudar de branch.
+///
+/// Usado quando alterações não commitadas impedem a mudança. As alterações
+/// ficam no stash, e não na branch de destino, até o usuário recuperá-las.
+fn stash_and_switch(target_branch: &str) -> Result<()> {
+    if !prompts::confirm("Guardar as alterações no stash e mudar de branch?", false)? {
+        println!("{}", style("Operação cancelada.").yellow());
+        return Ok(());
+    }
+
+    let result = stash::stash_push(&format!("gitph: antes de mudar para '{}'", target_branch))
+        .and_then(|_| branch::switch_branch(target_branch));
+    match result {
+        Ok(()) => {
+            println!("✔ Mudou para a branch '{}' com sucesso.", style(target_branch).cyan());
+            println!(
+                "{}",
+                style("Suas alterações estão guardadas no stash; recupere-as com 'git stash pop'.").dim()
+            );
+        }
+        Err(e) => {
+            println!("{}", style("Erro ao mudar de branch:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
 /// Lida com a ação "Clonar Repositório".
 fn handle_clone_action() -> Result<()> {
     println!("{}", style("Clonar Repositório Remoto").bold().cyan());
@@ -278,73 +852,2423 @@ fn handle_clone_action() -> Result<()> {
     Ok(())
 }
 
-// --- Funções Auxiliares e Lógica Reutilizável ---
+/// Lida com a ação "Inicializar Repositório".
+///
+/// Após criar o repositório no diretório atual, oferece configurar o remoto
+/// 'origin' com o mesmo fluxo da ação "srp".
+fn handle_init_action() -> Result<()> {
+    println!("{}", style("Inicializar Novo Repositório").bold().cyan());
+    let path = match prompts::get_text("Diretório do novo repositório", ".")? {
+        Some(p) if !p.trim().is_empty() => PathBuf::from(p.trim()),
+        _ => {
+            println!("{}", style("Operação cancelada.").yellow());
+            return Ok(());
+        }
+    };
+    let branch_name = match prompts::get_text("Nome da branch inicial", init::DEFAULT_INITIAL_BRANCH)? {
+        Some(b) => b,
+        None => {
+            println!("{}", style("Operação cancelada.").yellow());
+            return Ok(());
+        }
+    };
 
-/// Executa a lógica principal de Adicionar, Commitar e Pushar.
-fn run_snd_flow() -> Result<bool> {
-    commit::add_all().map_err(|e| {
-        println!("{}", style("Erro ao adicionar arquivos:").red().bold());
-        println!("{}", style(&e).red());
-        e
-    })?;
-    println!("✔ Arquivos adicionados ao stage.");
+    if let Err(e) = init::init(&path, Some(&branch_name)) {
+        println!("{}", style("Erro ao inicializar o repositório:").red().bold());
+        println!("{}", style(e).red());
+        return Ok(());
+    }
+    println!("{}", style(format!("✔ Repositório criado em '{}'.", path.display())).green());
 
-    let status = status::get_status()?;
-    if !status.files.iter().any(|f| f.staged_status.is_some()) {
-        println!("{}", style("Nenhuma alteração no stage para commitar.").yellow());
-        return Ok(true);
+    // Os comandos do Git rodam no diretório atual, então só podemos configurar
+    // o remoto do novo repositório se ele tiver sido criado aqui.
+    if path != Path::new(".") {
+        println!(
+            "Para configurar o remoto, execute o gitph dentro de '{}'.",
+            path.display()
+        );
+        return Ok(());
+    }
+    let choices = [
+        "Criar um novo repositório no GitHub",
+        "Informar a URL de um repositório existente",
+        "Agora não",
+    ];
+    let selection = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt("Deseja configurar o remoto 'origin' agora?")
+        .items(&choices)
+        .default(0)
+        .interact_on_opt(&Term::stdout())?;
+    match selection {
+        Some(0) => create_github_repo_flow()?,
+        Some(1) => handle_srp_action()?,
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Cria um repositório no GitHub e o configura como o remoto 'origin'.
+///
+/// Completa o `init` de um projeto novo: ao final, basta commitar e fazer push.
+fn create_github_repo_flow() -> Result<()> {
+    if !ensure_api_token(api_client::Forge::GitHub)? {
+        return Ok(());
     }
 
-    let commit_message = match prompts::get_commit_message()? {
-        Some(message) if !message.trim().is_empty() => message,
+    // O nome do diretório atual é a sugestão natural para o repositório.
+    let suggested = git_wrapper::working_dir()
+        .map(Path::to_path_buf)
+        .or_else(|| std::env::current_dir().ok())
+        .and_then(|dir| dir.file_name().map(|n| n.to_string_lossy().to_string()))
+        .unwrap_or_default();
+    let name = match prompts::get_text("Nome do repositório no GitHub", &suggested)? {
+        Some(n) if !n.trim().is_empty() => n.trim().to_string(),
         _ => {
-            println!("{}", style("Commit cancelado.").yellow());
-            return Ok(false);
+            println!("{}", style("Operação cancelada.").yellow());
+            return Ok(());
         }
     };
-    commit::commit(&commit_message).map_err(|e| {
-        println!("{}", style("Erro ao criar o commit:").red().bold());
-        println!("{}", style(&e).red());
-        e
-    })?;
-    println!("✔ Commit criado com sucesso.");
+    let description = prompts::get_text("Descrição (opcional)", "")?.unwrap_or_default();
+    let private = prompts::confirm("Repositório privado?", true)?;
 
-    match push::push() {
-        Ok(msg) => {
-            println!("{}", style("✔ Push realizado com sucesso.").green());
-            if !msg.is_empty() {
-                println!("{}", style(msg).dim());
-            }
+    println!("Criando o repositório '{}' no GitHub...", name);
+    let repo = match api_client::block_on(api_client::github::create_repo(&name, private, description.trim())) {
+        Ok(repo) => repo,
+        Err(e) => {
+            println!("{}", style("Erro ao criar o repositório:").red().bold());
+            println!("{}", style(e).red());
+            return Ok(());
+        }
+    };
+    println!("{}", style(format!("✔ Repositório {} criado.", repo.full_name)).green());
+    println!("{}", style(&repo.html_url).dim());
+
+    match remote::add_remote("origin", &repo.clone_url) {
+        Ok(()) => {
+            println!("{}", style(format!("✔ Remoto 'origin' configurado para {}", repo.clone_url)).green());
+            println!(
+                "{}",
+                style(format!("Prefere SSH? Use \"Setar Repositório por Link\" com {}", repo.ssh_url)).dim()
+            );
         }
         Err(e) => {
-            println!("{}", style("Erro ao realizar o push:").red().bold());
-            println!("{}", style(&e).red());
-            return Err(e);
+            println!("{}", style("Erro ao configurar o remoto:").red().bold());
+            println!("{}", style(format!("{:#}", e)).red());
         }
     }
-    Ok(true)
+    Ok(())
 }
 
-/// Exibe a estrutura `GitStatus` de forma formatada e colorida.
-fn display_git_status(status: &GitStatus) {
-    println!("{}", style(&status.branch_info).yellow());
-    if status.files.is_empty() {
-        println!("\n{}", style("Repositório limpo. Nada a commitar.").green());
-        return;
+/// Lida com a ação "Gerar .gitignore".
+///
+/// Se já existir um `.gitignore`, pergunta se o conteúdo novo deve
+/// substituí-lo ou ser acrescentado ao final.
+fn handle_gitignore_action() -> Result<()> {
+    println!("{}", style("Gerar .gitignore").bold().cyan());
+    println!(
+        "{}",
+        style(format!(
+            "Informe as tecnologias separadas por vírgula (offline: {}).",
+            gitignore::bundled_languages().join(", ")
+        ))
+        .dim()
+    );
+    let langs: Vec<String> = match prompts::get_text("Tecnologias", "")? {
+        Some(input) => input
+            .split(',')
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .collect(),
+        None => Vec::new(),
+    };
+    if langs.is_empty() {
+        println!("{}", style("Nenhuma tecnologia informada. Operação cancelada.").yellow());
+        return Ok(());
     }
-    let mut staged = Vec::new();
+
+    let mode = if gitignore::exists() {
+        println!("{}", style("Já existe um .gitignore neste diretório.").yellow());
+        let choices = &["Acrescentar ao final", "Substituir", "Cancelar"];
+        match Select::with_theme(&ColorfulTheme::default())
+            .items(choices)
+            .with_prompt("O que deseja fazer?")
+            .default(0)
+            .interact_on_opt(&Term::stdout())?
+        {
+            Some(0) => WriteMode::Append,
+            Some(1) => WriteMode::Overwrite,
+            _ => {
+                println!("{}", style("Operação cancelada.").yellow());
+                return Ok(());
+            }
+        }
+    } else {
+        WriteMode::Overwrite
+    };
+
+    println!("Gerando o template...");
+    match gitignore::generate(&langs).and_then(|(content, source)| {
+        gitignore::write(&content, mode).map(|()| source)
+    }) {
+        Ok(source) => {
+            if source == TemplateSource::Bundled {
+                println!("{}", style("gitignore.io indisponível; usando os templates embutidos.").yellow());
+            }
+            println!("{}", style("✔ .gitignore gerado com sucesso.").green());
+        }
+        Err(e) => {
+            println!("{}", style("Erro ao gerar o .gitignore:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Aplicar Commit de Outra Branch".
+///
+/// O usuário escolhe a branch de origem e, em seguida, um dos commits dela que
+/// ainda não estão na branch atual.
+fn handle_cherry_pick_action() -> Result<()> {
+    println!("{}", style("Aplicar Commit de Outra Branch (cherry-pick)").bold().cyan());
+    let branches: Vec<String> = match branch::list_branches() {
+        Ok(b) => b.into_iter().filter(|b| !b.is_current).map(|b| b.name).collect(),
+        Err(e) => {
+            println!("{}", style("Erro ao listar as branches:").red().bold());
+            println!("{}", style(e).red());
+            return Ok(());
+        }
+    };
+    if branches.is_empty() {
+        println!("{}", style("Não há outras branches de onde aplicar commits.").yellow());
+        return Ok(());
+    }
+
+    let source = match prompts::fuzzy_select("Branch de origem:", &branches, 0)? {
+        Some(i) => &branches[i],
+        None => {
+            println!("{}", style("Operação cancelada.").yellow());
+            return Ok(());
+        }
+    };
+
+    let commits = match log::commits_not_in_head(source, 30) {
+        Ok(c) => c,
+        Err(e) => {
+            println!("{}", style("Erro ao ler o histórico:").red().bold());
+            println!("{}", style(e).red());
+            return Ok(());
+        }
+    };
+    if commits.is_empty() {
+        println!("{}", style(format!("Todos os commits de '{}' já estão na branch atual.", source)).yellow());
+        return Ok(());
+    }
+
+    let labels: Vec<String> = commits
+        .iter()
+        .map(|c| format!("{} {}", &c.hash[..c.hash.len().min(7)], c.subject))
+        .collect();
+    let chosen = match prompts::fuzzy_select("Commit a aplicar:", &labels, 0)? {
+        Some(i) => &commits[i],
+        None => {
+            println!("{}", style("Operação cancelada.").yellow());
+            return Ok(());
+        }
+    };
+
+    match cherry_pick::cherry_pick(&chosen.hash) {
+        Ok(()) => println!("{}", style(format!("✔ Commit '{}' aplicado na branch atual.", chosen.subject)).green()),
+        Err(e) if e.downcast_ref::<git_wrapper::ConflictError>().is_some() => {
+            println!("{}", style("⚠ Conflitos ao aplicar o commit.").yellow().bold());
+            println!("{}", style(e).yellow());
+        }
+        Err(e) => {
+            println!("{}", style("Erro ao aplicar o commit:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Rebase da Branch Atual".
+///
+/// Se já houver um rebase em andamento, oferece continuá-lo ou abortá-lo.
+/// Caso contrário, inicia um novo rebase sobre a branch escolhida.
+fn handle_rebase_action() -> Result<()> {
+    println!("{}", style("Rebase da Branch Atual").bold().cyan());
+    println!("{}", style("Apenas o rebase não interativo é suportado.").dim());
+
+    let result = if rebase::rebase_in_progress().unwrap_or(false) {
+        println!("{}", style("Há um rebase em andamento.").yellow());
+        if let Ok(files) = git_wrapper::conflicted_files() {
+            for file in &files {
+                println!("  {} {}", style("conflito:").red(), file);
+            }
+        }
+        let choices = &["Continuar (já resolvi os conflitos)", "Abortar e voltar ao estado anterior", "Voltar"];
+        match Select::with_theme(&ColorfulTheme::default())
+            .items(choices)
+            .with_prompt("O que deseja fazer?")
+            .default(0)
+            .interact_on_opt(&Term::stdout())?
+        {
+            Some(0) => rebase::rebase_continue().map(|()| "Rebase concluído.".to_string()),
+            Some(1) => rebase::rebase_abort().map(|()| "Rebase abortado.".to_string()),
+            _ => return Ok(()),
+        }
+    } else {
+        let branches: Vec<String> = match branch::list_branches() {
+            Ok(b) => b.into_iter().filter(|b| !b.is_current).map(|b| b.name).collect(),
+            Err(e) => {
+                println!("{}", style("Erro ao listar as branches:").red().bold());
+                println!("{}", style(e).red());
+                return Ok(());
+            }
+        };
+        if branches.is_empty() {
+            println!("{}", style("Não há outras branches para usar como base.").yellow());
+            return Ok(());
+        }
+        let onto = match prompts::fuzzy_select("Reaplicar os commits sobre qual branch?", &branches, 0)? {
+            Some(i) => &branches[i],
+            None => {
+                println!("{}", style("Operação cancelada.").yellow());
+                return Ok(());
+            }
+        };
+        // O rebase reescreve os commits locais; se já tiverem sido enviados,
+        // será necessário um push forçado.
+        if !prompts::confirm(&format!("Reescrever os commits da branch atual sobre '{}'?", onto), false)? {
+            println!("{}", style("Operação cancelada.").yellow());
+            return Ok(());
+        }
+        rebase::rebase(onto).map(|()| format!("Branch atual reaplicada sobre '{}'.", onto))
+    };
+
+    match result {
+        Ok(message) => {
+            println!("{}", style(format!("✔ {}", message)).green());
+            offer_force_push()?;
+        }
+        Err(e) if e.downcast_ref::<git_wrapper::ConflictError>().is_some() => {
+            println!("{}", style("⚠ O rebase parou por causa de conflitos.").yellow().bold());
+            println!("{}", style(e).yellow());
+            println!("Depois de resolvê-los, volte a esta opção para continuar.");
+        }
+        Err(e) => {
+            println!("{}", style("Erro no rebase:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Oferece publicar a branch atual com um push forçado, se ela divergiu do
+/// upstream (o que acontece quando commits já enviados são reescritos).
+///
+/// O push usa `--force-with-lease` e só é feito após uma confirmação que
+/// mostra a referência remota que será sobrescrita.
+fn offer_force_push() -> Result<()> {
+    let Ok(Some(name)) = branch::current_branch() else {
+        return Ok(());
+    };
+    let Some(info) = branch::list_branches()?.into_iter().find(|b| b.name == name) else {
+        return Ok(());
+    };
+    if info.upstream.is_none() || info.upstream_gone || info.ahead == 0 || info.behind == 0 {
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        style("A branch divergiu da remota: os commits já enviados foram reescritos.").yellow()
+    );
+    let remote = branch::tracking_remote(&name);
+    if !confirm_force_push(&remote, &name)? {
+        println!("{}", style("Push forçado não realizado.").dim());
+        return Ok(());
+    }
+
+    match push::force_push(&remote, &name) {
+        Ok(output) => {
+            println!("{}", style("✔ Push forçado realizado com sucesso.").green());
+            display_push_summary(&output);
+        }
+        Err(e) if e.downcast_ref::<push::StaleInfoError>().is_some() => {
+            println!("{}", style("⚠ O push forçado foi recusado.").yellow().bold());
+            println!("{}", style(e).yellow());
+        }
+        Err(e) => {
+            println!("{}", style("Erro no push forçado:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Pede confirmação explícita para um push forçado, mostrando qual
+/// referência remota será sobrescrita e para qual commit ela aponta hoje.
+///
+/// # Returns
+/// `Ok(true)` somente se o usuário confirmar; o padrão é não forçar.
+fn confirm_force_push(remote: &str, branch: &str) -> Result<bool> {
+    if check_branch_protection(remote, branch) {
+        println!(
+            "{}",
+            style(format!("✘ A branch '{}' é protegida no GitHub; o push forçado foi bloqueado.", branch))
+                .red()
+                .bold()
+        );
+        return Ok(false);
+    }
+    let target = match push::remote_tracking_commit(remote, branch)? {
+        Some(commit) => format!("'{}/{}' (hoje em {})", remote, branch, commit),
+        None => format!("'{}/{}'", remote, branch),
+    };
+    println!(
+        "{} {}",
+        style("O push forçado vai sobrescrever no remoto:").red().bold(),
+        style(target).cyan()
+    );
+    prompts::confirm("Deseja forçar o push (--force-with-lease)?", false)
+}
+
+/// Verifica, pela API do GitHub, se `branch` é protegida no repositório de
+/// `remote`.
+///
+/// Se o remoto não for do GitHub ou a consulta falhar (sem token, modo
+/// offline, erro de rede), a operação é permitida com um aviso discreto: a
+/// verificação é uma proteção extra, não um requisito.
+///
+/// # Returns
+/// `true` apenas se a API confirmar que a branch é protegida.
+fn check_branch_protection(remote: &str, branch: &str) -> bool {
+    let url = remote::list_remotes()
+        .ok()
+        .and_then(|remotes| remotes.into_iter().find(|r| r.name == remote))
+        .map(|r| r.push_url);
+    let Some((owner, repo)) = url.and_then(|url| remote::parse_github_owner_and_repo(&url).ok()) else {
+        println!(
+            "{}",
+            style(format!("(O remoto '{}' não é do GitHub; a proteção da branch não foi verificada.)", remote)).dim()
+        );
+        return false;
+    };
+    match api_client::block_on(api_client::github::is_branch_protected(&owner, &repo, branch)) {
+        Ok(protected) => protected,
+        Err(e) => {
+            println!(
+                "{}",
+                style(format!("(Não foi possível verificar se a branch '{}' é protegida: {})", branch, e)).dim()
+            );
+            false
+        }
+    }
+}
+
+/// Lida com a ação "Gerenciar Worktrees".
+///
+/// Lista os worktrees existentes e oferece criar um novo para uma branch.
+fn handle_worktree_action() -> Result<()> {
+    println!("{}", style("Gerenciar Worktrees").bold().cyan());
+    match worktree::list_worktrees() {
+        Ok(worktrees) => {
+            for w in &worktrees {
+                let label = if w.is_bare {
+                    style("(bare)".to_string()).dim()
+                } else if let Some(name) = &w.branch {
+                    style(name.clone()).cyan()
+                } else {
+                    style(format!("(detached em {})", &w.head[..w.head.len().min(7)])).yellow()
+                };
+                println!("  {} {}", label, w.path);
+            }
+        }
+        Err(e) => {
+            println!("{}", style("Erro ao listar os worktrees:").red().bold());
+            println!("{}", style(e).red());
+            return Ok(());
+        }
+    }
+    println!();
+
+    if !prompts::confirm("Deseja criar um novo worktree?", false)? {
+        return Ok(());
+    }
+    let branch_name = match prompts::get_branch_name()? {
+        Some(name) => name,
+        None => {
+            println!("{}", style("Nome de branch inválido ou operação cancelada.").yellow());
+            return Ok(());
+        }
+    };
+    // Sugerimos uma pasta irmã do repositório, nomeada a partir da branch.
+    let suggestion = format!("../{}", branch_name.trim().replace('/', "-"));
+    let path = match prompts::get_text("Diretório do worktree", &suggestion)? {
+        Some(p) if !p.trim().is_empty() => PathBuf::from(p.trim()),
+        _ => {
+            println!("{}", style("Operação cancelada.").yellow());
+            return Ok(());
+        }
+    };
+
+    match worktree::add_worktree(&path, &branch_name) {
+        Ok(()) => println!(
+            "{}",
+            style(format!("✔ Worktree da branch '{}' criado em '{}'.", branch_name.trim(), path.display())).green()
+        ),
+        Err(e) => {
+            println!("{}", style("Erro ao criar o worktree:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Setar Repositório por link".
+///
+/// Define a URL do remoto 'origin', criando-o se ainda não existir.
+fn handle_srp_action() -> Result<()> {
+    println!("{}", style("Setar Repositório por Link").bold().cyan());
+    let current_url = remote::get_origin_url().ok();
+    let url = match prompt_remote_url(current_url.as_deref().unwrap_or(""))? {
+        Some(u) => u,
+        None => return Ok(()),
+    };
+
+    let result = if current_url.is_some() {
+        remote::set_remote_url("origin", &url)
+    } else {
+        remote::add_remote("origin", &url)
+    };
+    match result {
+        Ok(()) => println!("{}", style(format!("✔ Remoto 'origin' configurado para {}", url)).green()),
+        Err(e) => {
+            println!("{}", style("Erro:").red().bold());
+            println!("{}", style(format!("{:#}", e)).red());
+        }
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Limpar Branches Já Integradas".
+///
+/// Lista as branches locais já integradas na branch padrão e exclui as que o
+/// usuário marcar. A branch atual nunca aparece na lista.
+fn handle_cleanup_branches_action() -> Result<()> {
+    println!("{}", style("Limpar Branches Já Integradas").bold().cyan());
+
+    let default = resolve_default_branch();
+    let base = match prompts::get_text("Branch de integração (base)", &default)? {
+        Some(b) if !b.trim().is_empty() => b.trim().to_string(),
+        _ => {
+            println!("{}", style("Operação cancelada.").yellow());
+            return Ok(());
+        }
+    };
+
+    let merged = match branch::merged_branches(&base) {
+        Ok(branches) => branches,
+        Err(e) => {
+            println!("{}", style("Erro ao listar as branches:").red().bold());
+            println!("{}", style(e).red());
+            return Ok(());
+        }
+    };
+    if merged.is_empty() {
+        println!("{}", style(format!("Nenhuma branch integrada em '{}' para limpar.", base)).green());
+        return Ok(());
+    }
+
+    let selected = match prompts::multi_select("Marque com Espaço as branches a excluir (Enter para confirmar)", &merged)? {
+        Some(indices) if !indices.is_empty() => indices,
+        _ => {
+            println!("{}", style("Nenhuma branch selecionada.").yellow());
+            return Ok(());
+        }
+    };
+
+    for index in selected {
+        let name = &merged[index];
+        // Sem `force`: se a branch não estiver de fato integrada à branch atual,
+        // o Git recusa a exclusão e nenhum commit é perdido.
+        match branch::delete_branch(name, false) {
+            Ok(()) => println!("{}", style(format!("✔ Branch '{}' excluída.", name)).green()),
+            Err(e) => println!("{} {}", style("✘").red().bold(), style(e).red()),
+        }
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Excluir Branch Local".
+///
+/// Lista as branches locais, exceto a atual, e exclui a escolhida após uma
+/// confirmação. Se a branch tiver commits não integrados, oferece a exclusão
+/// forçada, nunca por padrão.
+fn handle_delete_branch_action() -> Result<()> {
+    output::header("Excluir Branch Local (delete-branch)");
+
+    let branches: Vec<String> = match branch::list_branches() {
+        Ok(branches) => branches.into_iter().filter(|b| !b.is_current).map(|b| b.name).collect(),
+        Err(e) => {
+            output::error(format!("Falha ao listar as branches: {}", e));
+            return Ok(());
+        }
+    };
+    if branches.is_empty() {
+        output::warning("Não há outras branches locais além da atual.");
+        return Ok(());
+    }
+
+    let Some(selected) = prompts::fuzzy_select("Qual branch deseja excluir?", &branches, 0)? else {
+        output::warning("Operação cancelada.");
+        return Ok(());
+    };
+    let name = &branches[selected];
+
+    if !prompts::confirm(&format!("Excluir a branch '{}'?", name), false)? {
+        output::warning("Operação cancelada.");
+        return Ok(());
+    }
+
+    let result = match branch::delete_branch(name, false) {
+        Err(e) if e.downcast_ref::<branch::UnmergedBranchError>().is_some() => {
+            output::warning(style("⚠ A exclusão foi recusada.").bold());
+            output::warning(e);
+            let prompt = "Excluir mesmo assim (git branch -D)? Os commits só poderão ser recuperados pelo reflog.";
+            if !prompts::confirm(prompt, false)? {
+                output::info(style(format!("Branch '{}' mantida.", name)).dim());
+                return Ok(());
+            }
+            branch::delete_branch(name, true)
+        }
+        result => result,
+    };
+    match result {
+        Ok(()) => output::success(format!("✔ Branch '{}' excluída.", name)),
+        Err(e) => {
+            output::error(format!("Falha ao excluir a branch: {}", e));
+        }
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Renomear Branch Local".
+///
+/// A branch atual aparece primeiro, já selecionada, pois é o caso mais comum
+/// (ex: corrigir um nome digitado errado logo após criá-la).
+fn handle_rename_branch_action() -> Result<()> {
+    output::header("Renomear Branch Local (rename)");
+
+    let mut branches = match branch::list_branches() {
+        Ok(branches) => branches,
+        Err(e) => {
+            output::error(format!("Falha ao listar as branches: {}", e));
+            return Ok(());
+        }
+    };
+    if branches.is_empty() {
+        // Em um repositório sem commits, a branch atual ainda não é listada,
+        // mas pode ser renomeada.
+        return rename_branch_flow(None, "");
+    }
+    branches.sort_by_key(|b| !b.is_current);
+
+    let labels: Vec<String> = branches
+        .iter()
+        .map(|b| if b.is_current { format!("{} (atual)", b.name) } else { b.name.clone() })
+        .collect();
+    let Some(selected) = prompts::fuzzy_select("Qual branch deseja renomear?", &labels, 0)? else {
+        output::warning("Operação cancelada.");
+        return Ok(());
+    };
+    let chosen = &branches[selected];
+    if let Some(upstream) = &chosen.upstream {
+        output::info(
+            style(format!("A branch remota '{}' não será renomeada; envie a branch com o novo nome se precisar.", upstream)).dim(),
+        );
+    }
+    let old = if chosen.is_current { None } else { Some(chosen.name.as_str()) };
+    rename_branch_flow(old, &chosen.name)
+}
+
+/// Pede o novo nome e renomeia `old` (ou a branch atual, se `None`).
+fn rename_branch_flow(old: Option<&str>, initial: &str) -> Result<()> {
+    let new = match prompts::get_text("Novo nome da branch (deixe vazio para cancelar)", initial)? {
+        Some(name) if !name.trim().is_empty() && name.trim() != initial => name,
+        _ => {
+            output::warning("Operação cancelada.");
+            return Ok(());
+        }
+    };
+    let result = match old {
+        Some(old) => branch::rename_branch(old, &new),
+        None => branch::rename_current_branch(&new),
+    };
+    match result {
+        Ok(()) => output::success(format!("✔ Branch renomeada para '{}'.", new.trim())),
+        Err(e) => {
+            output::error(format!("Falha ao renomear a branch: {}", e));
+        }
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Corrigir Último Commit".
+fn handle_amend_action() -> Result<()> {
+    println!("{}", style("Corrigir Último Commit (amend)").bold().cyan());
+
+    if !confirm_amend_if_pushed()? {
+        return Ok(());
+    }
+
+    let new_message = if prompts::confirm("Deseja alterar a mensagem do commit?", true)? {
+        match prompts::get_commit_message()? {
+            Some(message) if !message.trim().is_empty() => Some(message),
+            _ => {
+                println!("{}", style("Mensagem inválida ou operação cancelada.").yellow());
+                return Ok(());
+            }
+        }
+    } else {
+        None
+    };
+
+    let sign = config::load()?.sign_commits;
+    match commit::amend(new_message.as_deref(), sign) {
+        Ok(()) => println!("{}", style("✔ Último commit corrigido com sucesso.").green()),
+        Err(e) => {
+            println!("{}", style("Erro ao corrigir o commit:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Reformular Mensagem do Último Commit".
+///
+/// Diferente do amend, apenas a mensagem muda: o que estiver no stage não é
+/// incluído no commit.
+fn handle_reword_action() -> Result<()> {
+    println!("{}", style("Reformular Mensagem do Último Commit (reword)").bold().cyan());
+
+    let current = match log::last_commit_message() {
+        Ok(message) => message,
+        Err(e) => {
+            println!("{}", style("Erro ao obter o último commit:").red().bold());
+            println!("{}", style(e).red());
+            return Ok(());
+        }
+    };
+
+    if !confirm_amend_if_pushed()? {
+        return Ok(());
+    }
+
+    // Mensagens de uma linha são editadas no próprio terminal; mensagens com
+    // corpo abrem o editor, para não perder as linhas seguintes.
+    let edited = if current.contains('\n') {
+        prompts::get_long_text("a mensagem do commit", &current)?
+    } else {
+        prompts::get_text("Nova mensagem do commit (deixe vazio para cancelar)", &current)?
+    };
+    let new_message = match edited {
+        Some(message) if !message.trim().is_empty() => message.trim().to_string(),
+        _ => {
+            println!("{}", style("Mensagem inválida ou operação cancelada.").yellow());
+            return Ok(());
+        }
+    };
+    if new_message == current {
+        println!("{}", style("A mensagem não foi alterada.").yellow());
+        return Ok(());
+    }
+
+    let sign = config::load()?.sign_commits;
+    match commit::reword_last(&new_message, sign) {
+        Ok(()) => println!("{}", style("✔ Mensagem do último commit atualizada.").green()),
+        Err(e) => {
+            println!("{}", style("Erro ao reformular o commit:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Sincronizar com o Remoto".
+///
+/// Busca o remoto, integra o upstream (merge ou rebase, conforme
+/// `pull_mode`) e envia os commits locais, se houver.
+fn handle_sync_action() -> Result<()> {
+    println!("{}", style("Sincronizar com o Remoto (sync)").bold().cyan());
+    let mode = config::load()?.pull_mode;
+
+    // O fetch, a integração e o push exibem seu próprio progresso.
+    match pull::sync(mode) {
+        Ok(report) => display_sync_report(&report),
+        Err(e) => {
+            println!("\n{}", style("Erro ao sincronizar:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Puxar Alterações do Remoto".
+///
+/// Também é usada pelo subcomando `pull`. A integração usa merge ou rebase
+/// conforme `pull_mode` na configuração.
+pub fn handle_pull_action() -> Result<()> {
+    output::header("Puxar Alterações do Remoto (pull)");
+    let mode = config::load()?.pull_mode;
+
+    match pull::pull(mode) {
+        Ok(summary) => {
+            if !summary.is_empty() {
+                output::info(style(summary).dim());
+            }
+            output::success("✔ Branch atualizada com o remoto.");
+        }
+        Err(e) => match e.downcast_ref::<git_wrapper::ConflictError>() {
+            Some(conflict) => {
+                eprintln!("{}", style("⚠ As alterações do remoto conflitam com as suas.").yellow().bold());
+                for file in &conflict.files {
+                    eprintln!("  {}", style(file).red());
+                }
+                eprintln!(
+                    "Resolva os conflitos, adicione os arquivos ao stage e conclua com 'git {op} --continue', \
+                     ou desista com 'git {op} --abort'.",
+                    op = conflict.operation
+                );
+            }
+            None => output::error(e),
+        },
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Desfazer Último Commit".
+///
+/// O commit é removido da branch, mas suas alterações são mantidas: por
+/// padrão no stage (`--soft`), prontas para um novo commit.
+fn handle_undo_commit_action() -> Result<()> {
+    println!("{}", style("Desfazer Último Commit (undo)").bold().cyan());
+
+    let last = match log::recent_commits(1) {
+        Ok(commits) => match commits.into_iter().next() {
+            Some(last) => last,
+            None => {
+                println!("{}", style("A branch atual ainda não tem commits.").yellow());
+                return Ok(());
+            }
+        },
+        Err(e) => {
+            println!("{}", style("Erro ao obter o último commit:").red().bold());
+            println!("{}", style(e).red());
+            return Ok(());
+        }
+    };
+    println!(
+        "Último commit: {} {}",
+        style(&last.hash[..last.hash.len().min(7)]).yellow(),
+        last.subject
+    );
+
+    // Desfazer um commit já enviado diverge a branch do remoto: o próximo
+    // push precisará ser forçado e pode afetar quem já baixou o commit.
+    if commit::last_commit_is_pushed()? {
+        println!(
+            "{}",
+            style("ATENÇÃO: este commit já foi enviado ao remoto. Desfazê-lo reescreve o histórico e exigirá um push forçado.")
+                .red()
+                .bold()
+        );
+        if !prompts::confirm("Deseja desfazê-lo mesmo assim?", false)? {
+            println!("{}", style("Operação cancelada.").yellow());
+            return Ok(());
+        }
+    }
+
+    let modes = &[
+        "Manter as alterações no stage (--soft)",
+        "Manter as alterações fora do stage (--mixed)",
+    ];
+    let keep_changes = match Select::with_theme(&ColorfulTheme::default())
+        .items(modes)
+        .with_prompt("O que fazer com as alterações do commit?")
+        .default(0)
+        .interact_on_opt(&Term::stdout())?
+    {
+        Some(index) => index == 0,
+        None => {
+            println!("{}", style("Operação cancelada.").yellow());
+            return Ok(());
+        }
+    };
+
+    if !prompts::confirm("Desfazer o último commit?", true)? {
+        println!("{}", style("Operação cancelada.").yellow());
+        return Ok(());
+    }
+
+    match reset::undo_last_commit(keep_changes) {
+        Ok(()) => println!("{}", style("✔ Commit desfeito. Suas alterações foram mantidas.").green()),
+        Err(e) => {
+            println!("{}", style("Erro ao desfazer o commit:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Estatísticas do Repositório".
+fn handle_stats_action() -> Result<()> {
+    println!("{}", style("Estatísticas do Repositório (stats)").bold().cyan());
+
+    println!("{}", style("Analisando o histórico...").dim());
+    match stats::repo_stats(stats::DEFAULT_COMMIT_LIMIT) {
+        Ok(stats) => display_repo_stats(&stats),
+        Err(e) => {
+            println!("{}", style("Erro ao calcular as estatísticas:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Gerenciar Stashes".
+///
+/// Exibe o diff do stash escolhido antes de qualquer ação, para que o usuário
+/// não reaplique o stash errado quando houver vários.
+fn handle_stash_action() -> Result<()> {
+    println!("{}", style("Gerenciar Stashes (stash)").bold().cyan());
+
+    loop {
+        let stashes = match stash::list_stashes() {
+            Ok(stashes) => stashes,
+            Err(e) => {
+                println!("{}", style("Erro ao listar os stashes:").red().bold());
+                println!("{}", style(e).red());
+                return Ok(());
+            }
+        };
+        if stashes.is_empty() {
+            println!("{}", style("Não há stashes guardados.").yellow());
+            return Ok(());
+        }
+
+        let labels: Vec<String> = stashes
+            .iter()
+            .map(|s| format!("stash@{{{}}}  {} ({})", s.index, s.description, s.created))
+            .collect();
+        let Some(selected) = prompts::fuzzy_select("Qual stash deseja ver?", &labels, 0)? else {
+            return Ok(());
+        };
+        let entry = &stashes[selected];
+
+        match stash::stash_show(entry.index) {
+            Ok(diff) if diff.trim().is_empty() => {
+                println!("{}", style("O stash não tem alterações em arquivos rastreados.").dim());
+            }
+            Ok(diff) => {
+                let mut out = vec![style(&labels[selected]).bold().to_string()];
+                out.extend(diff.lines().map(colorize_diff_line));
+                pager::page(&out.join("\n"));
+            }
+            Err(e) => {
+                println!("{}", style("Erro ao exibir o stash:").red().bold());
+                println!("{}", style(e).red());
+                continue;
+            }
+        }
+
+        let choices = [
+            "Aplicar (manter o stash na lista)",
+            "Aplicar e remover da lista (pop)",
+            "Escolher outro stash",
+            "Voltar",
+        ];
+        let action = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("O que deseja fazer com este stash?")
+            .items(&choices)
+            .default(0)
+            .interact_on_opt(&Term::stdout())?;
+        let result = match action {
+            Some(0) => stash::stash_apply(entry.index).map(|()| "Stash aplicado; ele continua na lista."),
+            Some(1) => stash::stash_pop(entry.index).map(|()| "Stash aplicado e removido da lista."),
+            Some(2) => continue,
+            _ => return Ok(()),
+        };
+        match result {
+            Ok(message) => println!("{}", style(format!("✔ {}", message)).green()),
+            Err(e) => {
+                println!("{}", style("Erro ao aplicar o stash:").red().bold());
+                println!("{}", style(e).red());
+            }
+        }
+        return Ok(());
+    }
+}
+
+/// Lida com a ação "Verificar Autenticação no GitHub".
+fn handle_whoami_action() -> Result<()> {
+    println!("{}", style("Verificar Autenticação no GitHub (whoami)").bold().cyan());
+    if !ensure_api_token(api_client::Forge::GitHub)? {
+        return Ok(());
+    }
+    match api_client::block_on(api_client::github::get_authenticated_user()) {
+        Ok(user) => display_authenticated_user(&user),
+        Err(e) => {
+            println!("{}", style("Erro ao verificar o token do GitHub:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Adicionar ao Stage por Trechos".
+///
+/// Entrega o terminal ao `git add -p` e, quando ele termina, restaura o
+/// cursor (o seletor de ações o esconde) e mostra o que ficou no stage.
+fn handle_add_patch_action() -> Result<()> {
+    println!("{}", style("Adicionar ao Stage por Trechos (add -p)").bold().cyan());
+    println!(
+        "{}",
+        style("Responda a cada trecho: y (adicionar), n (pular), s (dividir), e (editar), q (sair), ? (ajuda).").dim()
+    );
+    println!();
+
+    let term = Term::stdout();
+    term.show_cursor()?;
+    let result = commit::add_patch();
+    // O Git pode terminar no meio de uma linha; garantimos uma linha nova e o
+    // cursor visível antes de voltar a desenhar a interface.
+    println!();
+    term.show_cursor()?;
+
+    match result {
+        Ok(()) => match status::get_status() {
+            Ok(status) => {
+                let staged = status
+                    .files
+                    .iter()
+                    .filter(|f| f.staged_status.is_some() && f.staged_status != Some(ChangeType::Untracked))
+                    .count();
+                println!("{}", style(format!("✔ {} arquivo(s) com alterações no stage.", staged)).green());
+            }
+            Err(_) => println!("{}", style("✔ Seleção de trechos concluída.").green()),
+        },
+        Err(e) => {
+            println!("{}", style("Erro ao adicionar trechos ao stage:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Gerenciar Submódulos".
+///
+/// Lista os submódulos com seu estado e, se algum estiver desatualizado ou
+/// não inicializado, oferece atualizá-los.
+fn handle_submodule_action() -> Result<()> {
+    println!("{}", style("Gerenciar Submódulos (submodule)").bold().cyan());
+
+    let submodules = match submodule::list_submodules() {
+        Ok(submodules) => submodules,
+        Err(e) => {
+            println!("{}", style("Erro ao listar os submódulos:").red().bold());
+            println!("{}", style(e).red());
+            return Ok(());
+        }
+    };
+    if submodules.is_empty() {
+        println!("{}", style("Este repositório não tem submódulos.").yellow());
+        return Ok(());
+    }
+    display_submodules(&submodules);
+
+    let choices = [
+        "Inicializar e atualizar todos (--init --recursive)",
+        "Atualizar apenas os já inicializados",
+        "Voltar",
+    ];
+    let default = if submodules.iter().any(submodule::Submodule::needs_update) { 0 } else { 2 };
+    let action = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt("O que deseja fazer?")
+        .items(&choices)
+        .default(default)
+        .interact_on_opt(&Term::stdout())?;
+    let result = match action {
+        Some(0) => submodule::update_submodules(true, true),
+        Some(1) => submodule::update_submodules(false, false),
+        _ => return Ok(()),
+    };
+    match result {
+        Ok(()) => {
+            println!("{}", style("✔ Submódulos atualizados.").green());
+            if let Ok(submodules) = submodule::list_submodules() {
+                display_submodules(&submodules);
+            }
+        }
+        Err(e) => {
+            println!("{}", style("Erro ao atualizar os submódulos:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Ativar/Desativar Modo Plano".
+fn handle_toggle_plan_mode() {
+    let enabled = !plan::is_plan_mode();
+    plan::set_plan_mode(enabled);
+    if enabled {
+        println!("{}", style("✔ Modo plano ativado: snd e rls apenas exibirão os comandos.").green());
+    } else {
+        println!("{}", style("✔ Modo plano desativado.").green());
+    }
+}
+
+/// Exibe o plano de um fluxo, ou o erro ao montá-lo.
+fn show_plan(result: Result<plan::Plan>) -> Result<()> {
+    match result {
+        Ok(steps) => plan::display_plan(&steps),
+        Err(e) => {
+            println!("{}", style("Erro ao montar o plano:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Quantas entradas do reflog são oferecidas para a recuperação de commits.
+const REFLOG_LIMIT: usize = 50;
+
+/// Lida com a ação "Recuperar Commits pelo Reflog".
+///
+/// Lista as entradas recentes do reflog de `HEAD` e cria uma branch na
+/// entrada escolhida, recuperando commits perdidos após um reset ou rebase
+/// sem alterar a branch atual.
+fn handle_reflog_action() -> Result<()> {
+    output::header("Recuperar Commits pelo Reflog (reflog)");
+
+    let entries = match log::reflog(REFLOG_LIMIT) {
+        Ok(entries) => entries,
+        Err(e) => {
+            output::error(format!("Falha ao ler o reflog: {}", e));
+            return Ok(());
+        }
+    };
+    if entries.is_empty() {
+        output::warning("O reflog está vazio: ainda não há movimentações de HEAD registradas.");
+        return Ok(());
+    }
+
+    let labels: Vec<String> = entries
+        .iter()
+        .map(|entry| {
+            let short: String = entry.sha.chars().take(7).collect();
+            let description = if entry.subject.is_empty() {
+                entry.action.clone()
+            } else {
+                format!("{}: {}", entry.action, entry.subject)
+            };
+            format!("{}  {}  {}", short, entry.selector, description)
+        })
+        .collect();
+    let Some(selected) = prompts::fuzzy_select("Qual ponto do histórico deseja recuperar?", &labels, 0)? else {
+        return Ok(());
+    };
+    let entry = &entries[selected];
+    let short: String = entry.sha.chars().take(7).collect();
+
+    let suggested = format!("recuperado-{}", short);
+    let name = match prompts::get_text("Nome da nova branch (deixe vazio para cancelar)", &suggested)? {
+        Some(name) if !name.trim().is_empty() => name,
+        _ => {
+            output::warning("Operação cancelada.");
+            return Ok(());
+        }
+    };
+
+    match branch::create_branch_from(&name, &entry.sha) {
+        Ok(()) => output::success(format!(
+            "✔ Branch '{}' criada em {}. Use \"Mudar de Branch\" para acessá-la.",
+            name.trim(),
+            short
+        )),
+        Err(e) => {
+            output::error(format!("Falha ao criar a branch: {}", e));
+        }
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Incluir Arquivo Esquecido no Último Commit".
+///
+/// Adiciona os arquivos escolhidos ao stage e os incorpora ao último commit
+/// com `git commit --amend --no-edit`, sem alterar a mensagem.
+fn handle_forgotten_file_action() -> Result<()> {
+    println!("{}", style("Incluir Arquivo Esquecido no Último Commit").bold().cyan());
+
+    // Candidatos: arquivos com alterações fora do stage, incluindo os novos.
+    let candidates: Vec<status::FileStatus> = match status::get_status() {
+        Ok(s) => s.files.into_iter().filter(|f| f.unstaged_status.is_some()).collect(),
+        Err(e) => {
+            println!("{}", style("Erro ao obter status:").red().bold());
+            println!("{}", style(e).red());
+            return Ok(());
+        }
+    };
+    if candidates.is_empty() {
+        println!("{}", style("Não há arquivos alterados para incluir no commit.").yellow());
+        return Ok(());
+    }
+
+    let labels: Vec<String> = candidates.iter().map(|f| f.display_path()).collect();
+    let selected = match prompts::multi_select("Marque com Espaço os arquivos esquecidos:", &labels)? {
+        Some(indices) if !indices.is_empty() => indices,
+        _ => {
+            println!("{}", style("Nenhum arquivo selecionado. Operação cancelada.").yellow());
+            return Ok(());
+        }
+    };
+
+    if !confirm_amend_if_pushed()? {
+        return Ok(());
+    }
+
+    let paths: Vec<String> = selected.iter().map(|&i| candidates[i].path.clone()).collect();
+    let sign = config::load()?.sign_commits;
+    match commit::add_paths(&paths).and_then(|()| commit::amend_no_edit(sign)) {
+        Ok(()) => println!("{}", style(format!("✔ {} arquivo(s) incluído(s) no último commit.", paths.len())).green()),
+        Err(e) => {
+            println!("{}", style("Erro ao corrigir o commit:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Quantos commits o grafo do histórico exibe no menu.
+const GRAPH_LIMIT: usize = 30;
+
+/// Lida com a ação "Ver Grafo do Histórico".
+fn handle_graph_action() -> Result<()> {
+    println!("{}", style("Grafo do Histórico").bold().cyan());
+    println!("----------------------------------------------------------");
+
+    match log::commit_graph(GRAPH_LIMIT) {
+        Ok(lines) if lines.is_empty() => {
+            println!("{}", style("A branch atual ainda não tem commits.").yellow());
+            return Ok(());
+        }
+        Ok(lines) => display_commit_graph(&lines),
+        Err(e) => {
+            println!("{}", style("Erro ao obter o histórico:").red().bold());
+            println!("{}", style(e).red());
+            return Ok(());
+        }
+    }
+
+    println!();
+    let choices = &[
+        "Ver os detalhes de um commit",
+        "Corrigir um commit com o que está no stage (fixup)",
+        "Voltar",
+    ];
+    let fixup = match Select::with_theme(&ColorfulTheme::default())
+        .with_prompt("O que deseja fazer?")
+        .items(choices)
+        .default(0)
+        .interact_on_opt(&Term::stdout())?
+    {
+        Some(0) => false,
+        Some(1) => true,
+        _ => return Ok(()),
+    };
+    let commits = log::recent_commits(GRAPH_LIMIT)?;
+    let labels: Vec<String> = commits
+        .iter()
+        .map(|c| format!("{} {}", &c.hash[..c.hash.len().min(7)], c.subject))
+        .collect();
+    let Some(index) = prompts::fuzzy_select("Escolha o commit (digite para filtrar)", &labels, 0)? else {
+        println!("{}", style("Operação cancelada.").yellow());
+        return Ok(());
+    };
+
+    if fixup {
+        return run_fixup_flow(&commits[index]);
+    }
+
+    println!();
+    match log::show_commit(&commits[index].hash) {
+        Ok(details) => display_commit_details(&details),
+        Err(e) => {
+            println!("{}", style("Erro ao obter os detalhes do commit:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Cria um commit de fixup para `target` e oferece incorporá-lo em seguida.
+///
+/// O commit de fixup só é mesclado ao alvo por um rebase com `--autosquash`;
+/// se o usuário preferir esperar, a correção fica como um commit
+/// `fixup! ...` até o próximo rebase desse tipo.
+fn run_fixup_flow(target: &log::CommitEntry) -> Result<()> {
+    let short = &target.hash[..target.hash.len().min(7)];
+    let sign = config::load()?.sign_commits;
+    if let Err(e) = commit::commit_fixup(&target.hash, sign) {
+        println!("{}", style("Erro ao criar o commit de fixup:").red().bold());
+        println!("{}", style(e).red());
+        return Ok(());
+    }
+    println!("{}", style(format!("✔ Commit de fixup criado para {} {}.", short, target.subject)).green());
+
+    if !prompts::confirm("Incorporar a correção agora (rebase --autosquash)?", true)? {
+        println!("{}", style("A correção será incorporada no próximo rebase com --autosquash.").dim());
+        return Ok(());
+    }
+    match rebase::autosquash(&target.hash, sign) {
+        Ok(()) => {
+            println!("{}", style(format!("✔ Correção incorporada ao commit '{}'.", target.subject)).green());
+            offer_force_push()?;
+        }
+        Err(e) if e.downcast_ref::<git_wrapper::ConflictError>().is_some() => {
+            println!("{}", style("⚠ O rebase parou por causa de conflitos.").yellow().bold());
+            println!("{}", style(e).yellow());
+            println!("Depois de resolvê-los, continue pela opção de rebase.");
+        }
+        Err(e) => {
+            println!("{}", style("Erro no rebase:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Exibe os metadados, a mensagem e os arquivos alterados de um commit.
+pub fn display_commit_details(details: &log::CommitDetails) {
+    let mut out = Vec::new();
+    out.push(format!("{} {}", style("commit").yellow(), style(&details.hash).yellow()));
+    out.push(format!("{} {}", style("Autor:    ").bold(), details.author));
+    out.push(format!("{} {}", style("Data:     ").bold(), details.author_date));
+    // O committer só é relevante quando difere do autor (ex: após um rebase).
+    if details.committer != details.author || details.commit_date != details.author_date {
+        out.push(format!("{} {}", style("Committer:").bold(), details.committer));
+        out.push(format!("{} {}", style("Data:     ").bold(), details.commit_date));
+    }
+    if let Ok(status) = commit::verify_commit(&details.hash) {
+        let badge = style(verification_badge(&status));
+        let badge = match status {
+            VerificationStatus::Good { .. } => badge.green(),
+            VerificationStatus::Bad => badge.red().bold(),
+            VerificationStatus::NoKey => badge.yellow(),
+            VerificationStatus::Unsigned => badge,
+        };
+        if status != VerificationStatus::Unsigned {
+            out.push(format!("{} {}", style("Assinatura:").bold(), badge));
+        }
+    }
+
+    out.push(String::new());
+    for line in details.message.lines() {
+        out.push(format!("    {}", line));
+    }
+
+    if !details.files.is_empty() {
+        out.push(String::new());
+        let width = details.files.iter().map(|f| f.path.chars().count()).max().unwrap_or(0);
+        for file in &details.files {
+            let changes: String = file
+                .changes
+                .chars()
+                .map(|c| match c {
+                    '+' => style(c).green().to_string(),
+                    '-' => style(c).red().to_string(),
+                    _ => c.to_string(),
+                })
+                .collect();
+            out.push(format!(" {:<width$} | {}", file.path, changes, width = width));
+        }
+    }
+    if let Some(summary) = &details.summary {
+        out.push(format!(" {}", style(summary).dim()));
+    }
+    pager::page(&out.join("\n"));
+}
+
+/// Exibe as linhas de `log::commit_graph`, colorindo o grafo e as referências.
+///
+/// As cores seguem as do próprio Git: hash em amarelo, `HEAD` em ciano,
+/// branches locais em verde, remotas em vermelho e tags em amarelo. Linhas
+/// apenas com o grafo (as ligações entre commits) são exibidas em ciano.
+pub fn display_commit_graph(lines: &[String]) {
+    let mut out = Vec::new();
+    for line in lines {
+        // O grafo é o prefixo formado apenas por seus caracteres e espaços.
+        let graph_end = line
+            .find(|c: char| !matches!(c, '*' | '|' | '/' | '\\' | '_' | '-' | '.' | ' '))
+            .unwrap_or(line.len());
+        let (graph, commit) = line.split_at(graph_end);
+        let graph: String = graph
+            .chars()
+            .map(|c| match c {
+                '*' => style(c).yellow().bold().to_string(),
+                ' ' => c.to_string(),
+                _ => style(c).cyan().to_string(),
+            })
+            .collect();
+
+        if commit.is_empty() {
+            out.push(graph);
+            continue;
+        }
+
+        let (hash, rest) = commit.split_once(' ').unwrap_or((commit, ""));
+        // As referências, quando existem, vêm logo após o hash, entre parênteses.
+        let (refs, subject) = match rest.strip_prefix('(').and_then(|r| r.split_once(')')) {
+            Some((refs, subject)) => {
+                let refs: Vec<String> = refs.split(", ").map(format_ref).collect();
+                (format!("({})", refs.join(", ")), subject)
+            }
+            None => (String::new(), rest),
+        };
+
+        let separator = if refs.is_empty() { "" } else { " " };
+        out.push(format!("{}{} {}{}{}", graph, style(hash).yellow(), refs, separator, subject.trim_start()));
+    }
+    pager::page(&out.join("\n"));
+}
+
+/// Encurta e colore uma referência da decoração do `git log --decorate=full`
+/// (ex: "HEAD -> refs/heads/main", "tag: refs/tags/v1.0").
+fn format_ref(reference: &str) -> String {
+    if let Some(target) = reference.strip_prefix("HEAD -> ") {
+        return format!("{} {}", style("HEAD ->").cyan().bold(), format_ref(target));
+    }
+    if let Some(name) = reference.strip_prefix("tag: refs/tags/") {
+        return style(format!("tag: {}", name)).yellow().bold().to_string();
+    }
+    if let Some(name) = reference.strip_prefix("refs/heads/") {
+        return style(name).green().bold().to_string();
+    }
+    if let Some(name) = reference.strip_prefix("refs/remotes/") {
+        return style(name).red().bold().to_string();
+    }
+    if reference == "HEAD" {
+        return style(reference).cyan().bold().to_string();
+    }
+    reference.to_string()
+}
+
+/// As cores atribuídas, em ordem de aparição, aos autores no blame.
+const AUTHOR_COLORS: [Color; 6] = [Color::Cyan, Color::Green, Color::Yellow, Color::Magenta, Color::Blue, Color::Red];
+
+/// Lida com a ação "Ver Autores das Linhas de um Arquivo".
+///
+/// Mostra, para cada linha, o commit e o autor da última alteração. Cada autor
+/// recebe uma cor própria, para que blocos de linhas do mesmo autor se destaquem.
+fn handle_blame_action() -> Result<()> {
+    println!("{}", style("Autores das Linhas de um Arquivo (blame)").bold().cyan());
+    println!("----------------------------------------------------------");
+
+    let path = match prompts::get_text("Caminho do arquivo (deixe vazio para cancelar)", "")? {
+        Some(p) if !p.trim().is_empty() => p.trim().to_string(),
+        _ => {
+            println!("{}", style("Operação cancelada.").yellow());
+            return Ok(());
+        }
+    };
+
+    let lines = match blame::blame(&path) {
+        Ok(lines) => lines,
+        Err(e) => {
+            println!("{}", style("Erro ao obter o blame:").red().bold());
+            println!("{}", style(e).red());
+            return Ok(());
+        }
+    };
+
+    let mut out = Vec::new();
+    let mut authors: Vec<&str> = Vec::new();
+    let author_width = lines.iter().map(|l| l.author.chars().count()).max().unwrap_or(0);
+    let number_width = lines.len().to_string().len();
+
+    for line in &lines {
+        let index = match authors.iter().position(|a| *a == line.author) {
+            Some(i) => i,
+            None => {
+                authors.push(&line.author);
+                authors.len() - 1
+            }
+        };
+        let color = AUTHOR_COLORS[index % AUTHOR_COLORS.len()];
+        out.push(format!(
+            "{} {} {} │ {}",
+            style(&line.commit[..line.commit.len().min(8)]).dim(),
+            style(format!("{:<width$}", line.author, width = author_width)).fg(color),
+            style(format!("{:>width$}", line.line_no, width = number_width)).dim(),
+            line.content
+        ));
+    }
+    pager::page(&out.join("\n"));
+    Ok(())
+}
+
+/// Lida com a ação "Gerenciar Tags".
+///
+/// Lista as tags locais e permite inspecionar o código de uma delas.
+fn handle_tags_action() -> Result<()> {
+    println!("{}", style("Gerenciar Tags").bold().cyan());
+    println!("----------------------------------------------------------");
+
+    let tags = match tag::list_tags() {
+        Ok(tags) if tags.is_empty() => {
+            println!("{}", style("Nenhuma tag encontrada neste repositório.").yellow());
+            return Ok(());
+        }
+        Ok(tags) => tags,
+        Err(e) => {
+            println!("{}", style("Erro ao listar as tags:").red().bold());
+            println!("{}", style(e).red());
+            return Ok(());
+        }
+    };
+
+    // A verificação de cada tag é local (não usa a rede), então pode ser
+    // feita para a lista inteira.
+    let labels: Vec<String> = tags
+        .iter()
+        .map(|name| match tag::verify_tag(name) {
+            Ok(status) => format!("{} {}", name, verification_badge(&status)).trim_end().to_string(),
+            Err(_) => name.clone(),
+        })
+        .collect();
+
+    let Some(index) = prompts::fuzzy_select("Escolha uma tag (digite para filtrar)", &labels, 0)? else {
+        println!("{}", style("Operação cancelada.").yellow());
+        return Ok(());
+    };
+    let tag_name = &tags[index];
+
+    let actions = ["Fazer checkout (inspecionar o código desta versão)", "Voltar"];
+    let selection = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt(format!("O que fazer com a tag '{}'?", tag_name))
+        .items(&actions)
+        .default(0)
+        .interact_on_opt(&Term::stdout())?;
+
+    if selection == Some(0) {
+        checkout_tag_flow(tag_name)?;
+    }
+    Ok(())
+}
+
+/// Retorna um selo curto para o estado da assinatura de um objeto.
+///
+/// O texto é puro (sem cores), pois é usado em listas com filtro, onde códigos
+/// de cor atrapalhariam a busca.
+fn verification_badge(status: &VerificationStatus) -> String {
+    match status {
+        VerificationStatus::Good { signer } if signer.is_empty() => "[✔ assinada]".to_string(),
+        VerificationStatus::Good { signer } => format!("[✔ assinada por {}]", signer),
+        VerificationStatus::Bad => "[✘ assinatura INVÁLIDA]".to_string(),
+        VerificationStatus::NoKey => "[? assinada, chave desconhecida]".to_string(),
+        VerificationStatus::Unsigned => String::new(),
+    }
+}
+
+/// Faz o checkout de uma tag, após avisar que o resultado é um detached HEAD.
+fn checkout_tag_flow(tag_name: &str) -> Result<()> {
+    println!(
+        "{}",
+        style("O checkout de uma tag deixa o repositório em detached HEAD (fora de qualquer branch).").yellow()
+    );
+    if !prompts::confirm(&format!("Fazer checkout de '{}'?", tag_name), true)? {
+        println!("{}", style("Operação cancelada.").yellow());
+        return Ok(());
+    }
+
+    match tag::checkout_tag(tag_name) {
+        Ok(()) => {
+            println!("{}", style(format!("✔ Código da tag '{}' disponível para inspeção.", tag_name)).green());
+            // Para fazer alterações a partir da tag, é preciso uma branch.
+            offer_branch_from_detached_head()?;
+        }
+        Err(e) => {
+            println!("{}", style("Erro ao fazer checkout da tag:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Avisa, antes de um amend, se o último commit já foi enviado ao remoto.
+///
+/// O amend reescreve o histórico. Se o commit já está no remoto, o usuário
+/// precisa saber que um push forçado será necessário depois.
+///
+/// # Returns
+/// `Ok(true)` se o amend pode prosseguir, ou `Ok(false)` se o usuário desistiu.
+fn confirm_amend_if_pushed() -> Result<bool> {
+    if !commit::last_commit_is_pushed()? {
+        return Ok(true);
+    }
+
+    println!(
+        "{}",
+        style("ATENÇÃO: o último commit já foi enviado ao remoto. Corrigi-lo reescreve o histórico e exigirá um push forçado.")
+            .red()
+            .bold()
+    );
+    if !prompts::confirm("Deseja continuar mesmo assim?", false)? {
+        println!("{}", style("Operação cancelada.").yellow());
+        return Ok(false);
+    }
+    Ok(true)
+}
+
+/// Lida com a ação "Gerenciar Releases do GitHub".
+///
+/// Lista as releases existentes e permite excluir uma delas (tipicamente um
+/// rascunho criado por engano).
+fn handle_releases_action() -> Result<()> {
+    println!("{}", style("Gerenciar Releases do GitHub").bold().cyan());
+    let (owner, repo) = match detect_github_repo() {
+        Some(data) => data,
+        None => return Ok(()),
+    };
+
+    if !ensure_api_token(api_client::Forge::GitHub)? {
+        return Ok(());
+    }
+
+    println!("Buscando releases de {}/{}...", owner, repo);
+    let releases = match api_client::block_on(api_client::github::list_releases(&owner, &repo)) {
+        Ok(r) => r,
+        Err(e) => {
+            println!("{}", style("Erro ao listar as releases:").red().bold());
+            println!("{}", style(e).red());
+            return Ok(());
+        }
+    };
+
+    if releases.is_empty() {
+        println!("{}", style("Nenhuma release encontrada neste repositório.").yellow());
+        return Ok(());
+    }
+
+    let labels: Vec<String> = releases
+        .iter()
+        .map(|r| {
+            let mut label = format!("{} - {}", r.tag_name, r.name.as_deref().unwrap_or("(sem título)"));
+            if r.draft {
+                label.push_str(" [rascunho]");
+            }
+            if r.prerelease {
+                label.push_str(" [pré-release]");
+            }
+            label
+        })
+        .collect();
+
+    let selection = Select::with_theme(&ColorfulTheme::default())
+        .items(&labels)
+        .with_prompt("Selecione uma release para excluir (ESC para voltar):")
+        .default(0)
+        .interact_on_opt(&Term::stdout())?;
+
+    let release = match selection {
+        Some(index) => &releases[index],
+        None => {
+            println!("{}", style("Operação cancelada.").yellow());
+            return Ok(());
+        }
+    };
+
+    println!("{}", style(&release.html_url).dim());
+    let prompt = format!("Excluir a release '{}'? A tag Git será mantida.", release.tag_name);
+    if !prompts::confirm(&prompt, false)? {
+        println!("{}", style("Operação cancelada.").yellow());
+        return Ok(());
+    }
+
+    match api_client::block_on(api_client::github::delete_release(&owner, &repo, release.id)) {
+        Ok(()) => println!("{}", style("✔ Release excluída com sucesso.").green()),
+        Err(e) => {
+            println!("{}", style("Erro ao excluir a release:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Issues do GitHub".
+///
+/// Lista as issues abertas do repositório e permite abrir uma nova.
+fn handle_issues_action() -> Result<()> {
+    println!("{}", style("Issues do GitHub").bold().cyan());
+    let (owner, repo) = match detect_github_repo() {
+        Some(data) => data,
+        None => return Ok(()),
+    };
+
+    if !ensure_api_token(api_client::Forge::GitHub)? {
+        return Ok(());
+    }
+
+    println!("Buscando issues abertas de {}/{}...", owner, repo);
+    let issues = match api_client::block_on(api_client::github::list_issues(&owner, &repo, api_client::github::IssueState::Open)) {
+        Ok(issues) => issues,
+        Err(e) => {
+            println!("{}", style("Erro ao listar as issues:").red().bold());
+            println!("{}", style(e).red());
+            return Ok(());
+        }
+    };
+
+    if issues.is_empty() {
+        println!("{}", style("Nenhuma issue aberta.").green());
+    }
+    for issue in &issues {
+        let labels: Vec<&str> = issue.labels.iter().map(|l| l.name.as_str()).collect();
+        println!(
+            "{} {} {} {}",
+            style(format!("#{:<5}", issue.number)).yellow(),
+            style(format!("[{}]", issue.state)).green(),
+            issue.title,
+            style(if labels.is_empty() { String::new() } else { format!("({})", labels.join(", ")) }).dim()
+        );
+    }
+
+    println!();
+    if !prompts::confirm("Deseja abrir uma nova issue?", false)? {
+        return Ok(());
+    }
+
+    let title = match prompts::get_text("Título da issue (deixe vazio para cancelar)", "")? {
+        Some(t) if !t.trim().is_empty() => t.trim().to_string(),
+        _ => {
+            println!("{}", style("Operação cancelada.").yellow());
+            return Ok(());
+        }
+    };
+    let body = prompts::get_long_text("a descrição da issue", "")?.unwrap_or_default();
+    let labels: Vec<String> = prompts::get_text("Labels, separadas por vírgula (opcional)", "")?
+        .unwrap_or_default()
+        .split(',')
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty())
+        .collect();
+
+    match api_client::block_on(api_client::github::create_issue(&owner, &repo, &title, body.trim(), &labels)) {
+        Ok(issue) => {
+            println!("{}", style(format!("✔ Issue #{} criada com sucesso!", issue.number)).green());
+            println!("{}", style(&issue.html_url).dim());
+        }
+        Err(e) => {
+            println!("{}", style("Erro ao criar a issue:").red().bold());
+            println!("{}", style(e).red());
+        }
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Buscar Atualizações do Remoto".
+fn handle_fetch_action() -> Result<()> {
+    println!("{}", style("Buscar Atualizações do Remoto").bold().cyan());
+    let prune = prompts::confirm("Remover referências de branches apagadas no remoto (--prune)?", false)?;
+
+    match fetch::fetch("origin", prune) {
+        Ok(summary) => display_fetch_summary(&summary),
+        Err(e) => eprintln!("\n{} {}", style("Erro:").red().bold(), style(e).red()),
+    }
+    Ok(())
+}
+
+/// Lida com a ação "Gerenciar Remotos".
+///
+/// Lista os remotos configurados e oferece adicionar, remover ou alterar a
+/// URL de um deles.
+fn handle_remotes_action() -> Result<()> {
+    println!("{}", style("Gerenciar Remotos").bold().cyan());
+    let remotes = match remote::list_remotes() {
+        Ok(r) => r,
+        Err(e) => {
+            println!("{}", style("Erro ao listar os remotos:").red().bold());
+            println!("{}", style(e).red());
+            return Ok(());
+        }
+    };
+
+    if remotes.is_empty() {
+        println!("{}", style("Nenhum remoto configurado neste repositório.").yellow());
+    } else {
+        for r in &remotes {
+            println!("  {} {}", style(&r.name).cyan().bold(), r.fetch_url);
+            if r.push_url != r.fetch_url {
+                println!("  {} {} (push)", " ".repeat(r.name.len()), r.push_url);
+            }
+        }
+    }
+    println!();
+
+    let actions = &["Adicionar remoto", "Remover remoto", "Alterar URL de um remoto", "Voltar"];
+    let action = Select::with_theme(&ColorfulTheme::default())
+        .items(actions)
+        .with_prompt("O que deseja fazer?")
+        .default(0)
+        .interact_on_opt(&Term::stdout())?;
+
+    let result = match action {
+        Some(0) => {
+            let name = match prompts::get_text("Nome do novo remoto", "")? {
+                Some(n) if !n.trim().is_empty() => n,
+                _ => {
+                    println!("{}", style("Nome inválido ou operação cancelada.").yellow());
+                    return Ok(());
+                }
+            };
+            let url = match prompt_remote_url("")? {
+                Some(u) => u,
+                None => return Ok(()),
+            };
+            remote::add_remote(&name, &url).map(|()| format!("Remoto '{}' adicionado.", name.trim()))
+        }
+        Some(1) | Some(2) => {
+            let names: Vec<&str> = remotes.iter().map(|r| r.name.as_str()).collect();
+            if names.is_empty() {
+                println!("{}", style("Não há remotos para alterar.").yellow());
+                return Ok(());
+            }
+            let index = match Select::with_theme(&ColorfulTheme::default())
+                .items(&names)
+                .with_prompt("Selecione o remoto:")
+                .default(0)
+                .interact_on_opt(&Term::stdout())?
+            {
+                Some(i) => i,
+                None => {
+                    println!("{}", style("Operação cancelada.").yellow());
+                    return Ok(());
+                }
+            };
+            let target = &remotes[index];
+
+            if action == Some(1) {
+                // Remover um remoto apaga todas as referências remotas associadas,
+                // então pedimos confirmação explícita.
+                let prompt = format!("Remover o remoto '{}' ({})?", target.name, target.fetch_url);
+                if !prompts::confirm(&prompt, false)? {
+                    println!("{}", style("Operação cancelada.").yellow());
+                    return Ok(());
+                }
+                remote::remove_remote(&target.name).map(|()| format!("Remoto '{}' removido.", target.name))
+            } else {
+                let url = match prompt_remote_url(&target.fetch_url)? {
+                    Some(u) => u,
+                    None => return Ok(()),
+                };
+                remote::set_remote_url(&target.name, &url).map(|()| format!("URL do remoto '{}' atualizada.", target.name))
+            }
+        }
+        _ => return Ok(()),
+    };
+
+    match result {
+        Ok(message) => println!("{}", style(format!("✔ {}", message)).green()),
+        Err(e) => {
+            println!("{}", style("Erro:").red().bold());
+            println!("{}", style(format!("{:#}", e)).red());
+        }
+    }
+    Ok(())
+}
+
+/// Solicita uma URL de remoto e a valida com `remote::parse_remote_url`.
+///
+/// Retorna `Ok(None)` (após informar o usuário) se a entrada for cancelada
+/// ou não for uma URL de repositório reconhecida.
+fn prompt_remote_url(initial: &str) -> Result<Option<String>> {
+    let url = match prompts::get_text("URL do remoto", initial)? {
+        Some(u) if !u.trim().is_empty() => u.trim().to_string(),
+        _ => {
+            println!("{}", style("URL inválida ou operação cancelada.").yellow());
+            return Ok(None);
+        }
+    };
+    if let Err(e) = remote::parse_remote_url(&url) {
+        println!("{}", style(e).red());
+        return Ok(None);
+    }
+    Ok(Some(url))
+}
+
+// --- Funções Auxiliares e Lógica Reutilizável ---
+
+/// Garante que há um token configurado para a API da plataforma informada.
+///
+/// Se o token não estiver no arquivo de configuração, oferece ao usuário a
+/// opção de digitá-lo agora (sem eco na tela) e o salva na configuração
+/// global com `config::save`.
+///
+/// # Returns
+/// `Ok(true)` se houver um token disponível ao final, ou `Ok(false)` se o
+/// usuário recusar ou cancelar a configuração.
+fn ensure_api_token(forge: api_client::Forge) -> Result<bool> {
+    // O token pode vir de qualquer camada (repositório, ambiente ou global)...
+    let effective = config::load()?;
+    let configured = match forge {
+        api_client::Forge::GitHub => effective.github_token.is_some(),
+        api_client::Forge::GitLab => effective.gitlab_token.is_some(),
+    };
+    if configured {
+        return Ok(true);
+    }
+
+    // ...mas um token novo é sempre salvo apenas no arquivo global.
+    let mut config = config::load_global()?;
+    let (platform, token_slot) = match forge {
+        api_client::Forge::GitHub => ("GitHub", &mut config.github_token),
+        api_client::Forge::GitLab => ("GitLab", &mut config.gitlab_token),
+    };
+
+    output::warning(format!("Nenhum token da API do {} foi configurado.", platform));
+    if !prompts::confirm("Deseja informá-lo agora?", true)? {
+        return Ok(false);
+    }
+
+    let token = match prompts::get_secret(&format!("Token de Acesso Pessoal do {}", platform))? {
+        Some(t) => t,
+        None => {
+            output::warning("Operação cancelada.");
+            return Ok(false);
+        }
+    };
+    *token_slot = Some(token);
+
+    config::save(&config)?;
+    output::success(format!("✔ Token salvo em {}", config::get_config_path()?.display()));
+    Ok(true)
+}
+
+/// Obtém o dono e o nome do repositório GitHub a partir do remoto 'origin'.
+///
+/// Em caso de falha, imprime o erro e retorna `None`, para que o chamador
+/// possa simplesmente encerrar sua ação.
+fn detect_github_repo() -> Option<(String, String)> {
+    match remote::origin_github_repo() {
+        Ok(data) => Some(data),
+        Err(e) => {
+            output::error(e);
+            None
+        }
+    }
+}
+
+/// Executa a lógica principal de Adicionar, Commitar e Pushar.
+fn run_snd_flow() -> Result<bool> {
+    let status = status::get_status()?;
+    if status.files.is_empty() {
+        output::warning("Nenhuma alteração para commitar.");
+        return Ok(true);
+    }
+
+    // O usuário escolhe entre adicionar tudo, apenas os arquivos rastreados ou
+    // selecionar arquivos específicos.
+    let modes = &[
+        "Todas as alterações (git add .)",
+        "Apenas arquivos rastreados (git add -u)",
+        "Escolher arquivos",
+    ];
+    let mode = Select::with_theme(&ColorfulTheme::default())
+        .items(modes)
+        .with_prompt("O que deseja incluir no commit?")
+        .default(0)
+        .interact_on_opt(&Term::stdout())?;
+
+    let stage_result = match mode {
+        Some(0) => commit::add_all(),
+        Some(1) => commit::add_tracked(),
+        Some(2) => {
+            let selected = prompts::select_files(&status.files)?;
+            if selected.is_empty() {
+                output::warning("Nenhum arquivo selecionado. Commit cancelado.");
+                return Ok(false);
+            }
+            // Arquivos que estavam no stage mas foram desmarcados são removidos dele,
+            // para que o commit contenha exatamente a seleção do usuário.
+            let deselected: Vec<String> = status
+                .files
+                .iter()
+                .filter(|f| f.staged_status.is_some() && f.staged_status != Some(ChangeType::Untracked))
+                .filter(|f| !selected.contains(&f.path))
+                .flat_map(|f| f.affected_paths())
+                .collect();
+            if deselected.is_empty() {
+                commit::add_paths(&selected)
+            } else {
+                reset::reset_paths(&deselected).and_then(|()| commit::add_paths(&selected))
+            }
+        }
+        _ => {
+            output::warning("Commit cancelado.");
+            return Ok(false);
+        }
+    };
+    stage_result.inspect_err(|e| {
+        output::error(format!("Falha ao adicionar arquivos: {}", e));
+    })?;
+    output::success("✔ Arquivos adicionados ao stage.");
+
+    let status = status::get_status()?;
+    if !status
+        .files
+        .iter()
+        .any(|f| f.staged_status.is_some() && f.staged_status != Some(ChangeType::Untracked))
+    {
+        // Acontece, por exemplo, no modo "apenas rastreados" quando só há
+        // arquivos novos. Em vez de encerrar sem nada, oferecemos incluí-los.
+        let pending: Vec<String> = status.files.iter().map(|f| f.path.clone()).collect();
+        output::warning("Nenhuma alteração no stage para commitar.");
+        if pending.is_empty() {
+            return Ok(false);
+        }
+        output::info("Ainda há alterações fora do stage:");
+        for path in &pending {
+            output::info(format!("  {}", style(path).red()));
+        }
+        if !prompts::confirm("Deseja adicioná-las ao stage e continuar?", true)? {
+            output::warning("Commit cancelado.");
+            return Ok(false);
+        }
+        if let Err(e) = commit::add_paths(&pending) {
+            output::error(format!("Falha ao adicionar arquivos: {}", e));
+            return Err(e);
+        }
+        output::success("✔ Arquivos adicionados ao stage.");
+    }
+
+    let config = config::load()?;
+    // O prompt guiado do Conventional Commits é opt-in. Fora dele, com um
+    // `commit.template` configurado, a mensagem é escrita no editor a partir
+    // do template, respeitando o padrão de mensagens do time.
+    let message = if config.conventional_commits {
+        prompts::get_conventional_commit()
+    } else {
+        match git_config::commit_template() {
+            Ok(Some(_)) => prompts::get_commit_message_from_editor(),
+            Ok(None) => prompts::get_commit_message(),
+            Err(e) => Err(e),
+        }
+    };
+    let commit_message = match message {
+        Err(e) => {
+            output::error(format!("Falha ao preparar a mensagem de commit: {}", e));
+            return Err(e);
+        }
+        Ok(Some(message)) if !message.trim().is_empty() => message,
+        _ => {
+            output::warning("Commit cancelado.");
+            return Ok(false);
+        }
+    };
+    let mut options = commit::CommitOptions {
+        sign: config.sign_commits,
+        signoff: config.signoff_by_default,
+        ..Default::default()
+    };
+
+    // Com o sign-off ativo, mostramos o trailer antes de commitar para que o
+    // usuário confirme que a identidade do Git é a que ele espera certificar.
+    if options.signoff {
+        let trailer = commit::signoff_trailer()?;
+        output::info(format!("O commit receberá o trailer: {}", style(&trailer).cyan()));
+        if !prompts::confirm("Esta identidade está correta?", true)? {
+            output::warning("Commit cancelado. Ajuste user.name/user.email no Git e tente novamente.");
+            return Ok(false);
+        }
+    }
+    if let Err(e) = commit::commit(&commit_message, options) {
+        output::error(format!("Falha ao criar o commit: {}", e));
+
+        if e.downcast_ref::<commit::MissingIdentityError>().is_some() {
+            // Sem identidade, nenhum commit é possível: guiamos a configuração
+            // e tentamos novamente com as mesmas opções.
+            if !setup_identity_flow()? {
+                return Err(e);
+            }
+        } else if e.downcast_ref::<commit::HookRejectedError>().is_none()
+            || !prompts::confirm("Um hook rejeitou o commit. Tentar novamente ignorando os hooks (--no-verify)?", false)?
+        {
+            // Um hook quebrado não deve impedir o usuário de commitar. Oferecemos
+            // uma nova tentativa ignorando os hooks, mas nunca por padrão.
+            return Err(e);
+        } else {
+            options.no_verify = true;
+        }
+        commit::commit(&commit_message, options).inspect_err(|e| {
+            output::error(format!("Falha ao criar o commit: {}", e));
+        })?;
+    }
+    output::success("✔ Commit criado com sucesso.");
+
+    // O push é confirmado para que o usuário possa revisar o commit antes de
+    // publicá-lo. A confirmação pode ser desativada na configuração.
+    if config.confirm_before_push && !prompts::confirm("Enviar o commit para o remoto agora?", true)? {
+        output::warning("Push não realizado. O commit existe apenas localmente.");
+        return Ok(false);
+    }
+
+    match push::push(false) {
+        Ok(msg) => {
+            output::success("✔ Push realizado com sucesso.");
+            display_push_summary(&msg);
+        }
+        Err(e) => {
+            output::error(format!("Falha ao realizar o push: {}", e));
+            return Err(e);
+        }
+    }
+    Ok(true)
+}
+
+/// Guia o usuário na configuração da identidade do Git (user.name/user.email).
+///
+/// Os campos vêm pré-preenchidos com o que já estiver configurado, e o
+/// usuário escolhe se a identidade vale para todos os repositórios ou apenas
+/// para o atual.
+///
+/// # Returns
+/// `Ok(true)` se a identidade foi gravada, ou `Ok(false)` se o usuário
+/// cancelou ou a gravação falhou (o erro já foi exibido).
+fn setup_identity_flow() -> Result<bool> {
+    if !prompts::confirm("Deseja configurar seu nome e email no Git agora?", true)? {
+        return Ok(false);
+    }
+    let (current_name, current_email) = git_wrapper::config::get_user_identity().unwrap_or((None, None));
+
+    let name = match prompts::get_text("Seu nome (deixe vazio para cancelar)", current_name.as_deref().unwrap_or(""))? {
+        Some(name) if !name.trim().is_empty() => name,
+        _ => {
+            output::warning("Operação cancelada.");
+            return Ok(false);
+        }
+    };
+    let email = match prompts::get_text("Seu email (deixe vazio para cancelar)", current_email.as_deref().unwrap_or(""))? {
+        Some(email) if !email.trim().is_empty() => email,
+        _ => {
+            output::warning("Operação cancelada.");
+            return Ok(false);
+        }
+    };
+
+    let scopes = &["Para todos os repositórios (--global)", "Apenas para este repositório"];
+    let global = match Select::with_theme(&ColorfulTheme::default())
+        .items(scopes)
+        .with_prompt("Onde gravar a identidade?")
+        .default(0)
+        .interact_on_opt(&Term::stdout())?
+    {
+        Some(index) => index == 0,
+        None => {
+            output::warning("Operação cancelada.");
+            return Ok(false);
+        }
+    };
+
+    match git_wrapper::config::set_user_identity(&name, &email, global) {
+        Ok(()) => {
+            output::success("✔ Identidade do Git configurada.");
+            Ok(true)
+        }
+        Err(e) => {
+            output::error(format!("Falha ao configurar a identidade: {}", e));
+            Ok(false)
+        }
+    }
+}
+
+/// Exibe o resultado de um `sync`: os commits integrados e os enviados.
+///
+/// É pública para ser reaproveitada pelo comando `gitph sync`.
+pub fn display_sync_report(report: &pull::SyncReport) {
+    if report.integrated > 0 {
+        output::success(format!("✔ {} commit(s) de '{}' integrado(s).", report.integrated, report.upstream));
+    }
+    match &report.push_output {
+        Some(push_output) => {
+            output::success("✔ Push realizado com sucesso.");
+            display_push_summary(push_output);
+        }
+        None if report.integrated == 0 => output::success(format!(
+            "A branch '{}' já está sincronizada com '{}'.",
+            report.branch, report.upstream
+        )),
+        None => output::info(style("Não há commits locais para enviar.").dim()),
+    }
+}
+
+/// Exibe os submódulos com o estado de cada um.
+pub fn display_submodules(submodules: &[submodule::Submodule]) {
+    for sub in submodules {
+        let (mark, state) = match sub.state {
+            submodule::SubmoduleState::UpToDate => (style("✔").green(), style("atualizado").green()),
+            submodule::SubmoduleState::Uninitialized => (style("-").yellow(), style("não inicializado").yellow()),
+            submodule::SubmoduleState::OutOfDate => (style("+").yellow(), style("em outro commit").yellow()),
+            submodule::SubmoduleState::Conflicted => (style("U").red(), style("em conflito").red()),
+        };
+        let short: String = sub.commit.chars().take(7).collect();
+        let describe = sub.describe.as_deref().map(|d| format!(" ({})", d)).unwrap_or_default();
+        println!("  {} {}  {}{}  {}", mark, style(&sub.path).bold(), style(short).dim(), describe, state);
+    }
+}
+
+/// Avisa, na tela de status, sobre submódulos desatualizados ou não
+/// inicializados. Não exibe nada se todos estiverem em dia.
+fn display_outdated_submodules() {
+    let Ok(submodules) = submodule::list_submodules() else {
+        return;
+    };
+    let outdated: Vec<submodule::Submodule> = submodules.into_iter().filter(|s| s.needs_update()).collect();
+    if outdated.is_empty() {
+        return;
+    }
+    println!(
+        "\n{}",
+        style("Submódulos que precisam de atualização (use \"Gerenciar Submódulos\"):").yellow().bold()
+    );
+    display_submodules(&outdated);
+}
+
+/// Exibe o usuário do GitHub autenticado pelo token configurado.
+pub fn display_authenticated_user(user: &api_client::github::GitHubUser) {
+    let name = user.name.as_deref().map(|n| format!(" ({})", n)).unwrap_or_default();
+    output::data(style(format!("✔ Autenticado como {}{}", user.login, name)).green());
+}
+
+/// Exibe as estatísticas do repositório: totais de linhas e uma tabela com os
+/// commits de cada autor.
+pub fn display_repo_stats(stats: &stats::RepoStats) {
+    if stats.total_commits == 0 {
+        println!("{}", style("A branch atual ainda não tem commits.").yellow());
+        return;
+    }
+
+    let mut out = Vec::new();
+    if stats.is_truncated() {
+        out.push(
+            style(format!(
+                "Analisando os {} commits mais recentes de {}.",
+                stats.analyzed_commits, stats.total_commits
+            ))
+            .dim()
+            .to_string(),
+        );
+    }
+    out.push(format!(
+        "{} commit(s), {} alteração(ões) em arquivos, {} {}",
+        stats.analyzed_commits,
+        stats.files_changed,
+        style(format!("+{}", stats.lines_added)).green(),
+        style(format!("-{}", stats.lines_removed)).red()
+    ));
+    out.push(String::new());
+    out.push(style(format!("{:>8}  {:>6}  {}", "Commits", "%", "Autor")).bold().to_string());
+    for author in &stats.authors {
+        let share = author.commits as f64 * 100.0 / stats.analyzed_commits.max(1) as f64;
+        out.push(format!(
+            "{}  {:>5.1}%  {}",
+            style(format!("{:>8}", author.commits)).yellow(),
+            share,
+            author.name
+        ));
+    }
+    pager::page(&out.join("\n"));
+}
+
+/// Exibe as referências atualizadas por um push, como "main: abc1234 → def5678".
+///
+/// Se a saída do Git não puder ser analisada, ela é exibida sem formatação.
+fn display_push_summary(git_output: &str) {
+    let updates = push::parse_push_summary(git_output);
+    if updates.is_empty() {
+        if !git_output.is_empty() {
+            output::info(style(git_output).dim());
+        }
+        return;
+    }
+
+    for update in updates {
+        let target = match &update.local_ref {
+            Some(local_ref) if *local_ref != update.remote_ref => format!("{} → {}", local_ref, update.remote_ref),
+            _ => update.remote_ref.clone(),
+        };
+        let range = match (&update.old_sha, &update.new_sha) {
+            (Some(old), Some(new)) => format!(": {} → {}", style(old).dim(), style(new).green()),
+            _ => String::new(),
+        };
+        let line = match update.kind {
+            push::PushedRefKind::New => {
+                format!("  Enviado {} {}", style(target).cyan(), style("(nova referência)").green())
+            }
+            push::PushedRefKind::Updated => format!("  Enviado {}{}", style(target).cyan(), range),
+            push::PushedRefKind::Forced => format!(
+                "  Enviado {} {}{}",
+                style(target).cyan(),
+                style("(forçado)").yellow(),
+                range
+            ),
+            push::PushedRefKind::Deleted => format!("  {} {}", style("Removido").red(), style(target).cyan()),
+            push::PushedRefKind::UpToDate => format!("  {} {}", style(target).cyan(), style("(já atualizado)").dim()),
+            push::PushedRefKind::Rejected => format!("  {} {}", style("Recusado").red().bold(), style(target).cyan()),
+        };
+        output::info(line);
+    }
+}
+
+/// Exibe as referências criadas, atualizadas ou removidas por um fetch.
+///
+/// É pública para ser reaproveitada pelo comando `gitph fetch`.
+pub fn display_fetch_summary(summary: &str) {
+    let refs = fetch::parse_fetch_summary(summary);
+    if refs.is_empty() {
+        output::success("✔ As referências remotas já estavam atualizadas.");
+        return;
+    }
+
+    for fetched in &refs {
+        let reference = style(&fetched.reference).cyan();
+        let line = match fetched.kind {
+            fetch::FetchedRefKind::New => format!("  {} {}", style("Nova").green(), reference),
+            fetch::FetchedRefKind::Updated => format!("  Atualizada {}: {}", reference, style(&fetched.summary).dim()),
+            fetch::FetchedRefKind::Forced => format!(
+                "  {} {}: {}",
+                style("Reescrita (forçada)").yellow(),
+                reference,
+                style(&fetched.summary).dim()
+            ),
+            fetch::FetchedRefKind::Pruned => format!("  {} {}", style("Removida").red(), reference),
+            fetch::FetchedRefKind::Rejected => format!("  {} {} {}", style("Recusada").red().bold(), reference, fetched.summary),
+        };
+        output::data(line);
+    }
+    output::success(format!("✔ {} referência(s) remota(s) alterada(s).", refs.len()));
+}
+
+/// Monta o texto inicial das notas de release.
+///
+/// Gera um changelog a partir dos commits feitos desde a última tag. Se não
+/// houver commits novos ou o histórico não puder ser lido, recorre ao
+/// esqueleto estático para que o usuário escreva as notas manualmente.
+fn release_notes_template() -> String {
+    let since = tag::latest_tag().ok().flatten();
+    match log::commits_since(since.as_deref()) {
+        Ok(commits) if !commits.is_empty() => log::format_changelog(&commits),
+        _ => prompts::RELEASE_NOTES_SKELETON.to_string(),
+    }
+}
+
+/// Exibe a branch atual e sua sincronia com o upstream.
+fn display_branch_tracking(status: &GitStatus) {
+    let tracking = &status.tracking;
+    if tracking.branch.is_empty() {
+        // Sem linha de branch para analisar: exibe o que o Git informou.
+        println!("{}", style(&status.branch_info).yellow());
+        return;
+    }
+    if tracking.branch == "HEAD (no branch)" {
+        println!("{}", style("HEAD destacado (fora de qualquer branch).").yellow());
+        return;
+    }
+    println!("Na branch {}", style(&tracking.branch).yellow().bold());
+
+    let Some(upstream) = &tracking.upstream else {
+        println!("{}", style("A branch não rastreia nenhuma branch remota.").dim());
+        return;
+    };
+    if tracking.gone {
+        println!(
+            "{}",
+            style(format!("O upstream '{}' não existe mais no remoto.", upstream)).red()
+        );
+        return;
+    }
+    match (tracking.ahead, tracking.behind) {
+        (Some(ahead), Some(behind)) => println!(
+            "{}",
+            style(format!(
+                "Sua branch e '{}' divergiram: {} commit(s) à frente e {} atrás.",
+                upstream, ahead, behind
+            ))
+            .magenta()
+        ),
+        (Some(ahead), None) => println!(
+            "{}",
+            style(format!(
+                "Sua branch está à frente de '{}' por {} commit(s).",
+                upstream, ahead
+            ))
+            .green()
+        ),
+        (None, Some(behind)) => println!(
+            "{}",
+            style(format!(
+                "Sua branch está atrás de '{}' por {} commit(s).",
+                upstream, behind
+            ))
+            .yellow()
+        ),
+        (None, None) => println!(
+            "{}",
+            style(format!("Sua branch está em dia com '{}'.", upstream)).dim()
+        ),
+    }
+}
+
+/// Exibe o resumo do repositório no topo da visualização de status.
+fn display_repo_summary(summary: &RepoSummary) {
+    match &summary.last_commit {
+        Some(last) => println!(
+            "{} commit(s) · último: {} {} ({})",
+            style(summary.commit_count).bold(),
+            style(&last.hash).yellow(),
+            last.subject,
+            style(&last.relative_date).dim()
+        ),
+        None => println!("{}", style("Nenhum commit ainda.").dim()),
+    }
+    if summary.is_dirty {
+        println!("{}", style("Há alterações não commitadas.").yellow());
+    } else {
+        println!("{}", style("Diretório de trabalho limpo.").green());
+    }
+    println!();
+}
+
+/// Exibe a estrutura `GitStatus` de forma formatada e colorida.
+fn display_git_status(status: &GitStatus) {
+    display_branch_tracking(status);
+    if status.files.is_empty() {
+        println!("\n{}", style("Repositório limpo. Nada a commitar.").green());
+        return;
+    }
+    let mut staged = Vec::new();
     let mut unstaged = Vec::new();
     let mut untracked = Vec::new();
     for file in &status.files {
         if file.staged_status == Some(ChangeType::Untracked) {
-            untracked.push(format!("  {}", file.path));
+            untracked.push(format!("  {}", file.display_path()));
             continue;
         }
         if let Some(change) = &file.staged_status {
-            staged.push(format!("  {}: {}", format_change_type(change), file.path));
+            staged.push(format!("  {}: {}", format_change_type(change), file.display_path()));
         }
         if let Some(change) = &file.unstaged_status {
-            unstaged.push(format!("  {}: {}", format_change_type(change), file.path));
+            unstaged.push(format!("  {}: {}", format_change_type(change), file.display_path()));
         }
     }
     if !staged.is_empty() {
@@ -362,6 +3286,67 @@ fn display_git_status(status: &GitStatus) {
         println!("{}", style("(use 'git add <arquivo>...' para rastrear)").dim());
         println!("{}", untracked.join("\n"));
     }
+    if status.omitted > 0 {
+        println!("\n{}", style(format!("+{} arquivo(s) alterado(s) não exibidos.", status.omitted)).yellow());
+    }
+}
+
+/// Exibe um resumo das linhas adicionadas/removidas no stage e no diretório
+/// de trabalho, calculado pelo módulo nativo.
+///
+/// Esta é uma informação complementar: se o diff não puder ser obtido, o
+/// resumo é simplesmente omitido.
+fn display_diff_stats() {
+    for (staged, label) in [(true, "No stage"), (false, "Fora do stage")] {
+        let stats = match diff::diff(staged).and_then(|d| native_bindings::diff_stats(&d)) {
+            Ok(stats) if stats.files_changed > 0 => stats,
+            _ => continue,
+        };
+        println!(
+            "{}: {} arquivo(s), {} {}",
+            style(label).bold(),
+            stats.files_changed,
+            style(format!("+{}", stats.lines_added)).green(),
+            style(format!("-{}", stats.lines_removed)).red()
+        );
+    }
+}
+
+/// Exibe, pelo paginador, o diff completo do stage e do diretório de
+/// trabalho, com as cores usuais do Git.
+fn display_full_diff() {
+    let mut out = Vec::new();
+    for (staged, label) in [(true, "No stage"), (false, "Fora do stage")] {
+        let content = match diff::diff(staged) {
+            Ok(content) if !content.trim().is_empty() => content,
+            Ok(_) => continue,
+            Err(e) => {
+                out.push(style(format!("Erro ao obter o diff: {}", e)).red().to_string());
+                continue;
+            }
+        };
+        out.push(style(format!("=== {} ===", label)).bold().to_string());
+        out.extend(content.lines().map(colorize_diff_line));
+        out.push(String::new());
+    }
+    pager::page(&out.join("\n"));
+}
+
+/// Colore uma linha de um diff unificado: cabeçalhos em negrito, hunks em
+/// ciano, adições em verde e remoções em vermelho.
+fn colorize_diff_line(line: &str) -> String {
+    let styled = if line.starts_with("diff ") || line.starts_with("index ") || line.starts_with("+++") || line.starts_with("---") {
+        style(line).bold()
+    } else if line.starts_with("@@") {
+        style(line).cyan()
+    } else if line.starts_with('+') {
+        style(line).green()
+    } else if line.starts_with('-') {
+        style(line).red()
+    } else {
+        style(line)
+    };
+    styled.to_string()
 }
 
 /// Formata um `ChangeType` em uma string colorida para exibição.
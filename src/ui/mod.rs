@@ -32,6 +32,15 @@ pub mod menus;
 /// Módulo para solicitar entradas de texto, senhas e confirmações do usuário.
 pub mod prompts;
 
+/// Módulo para exibir saídas longas (histórico, diff, blame) por um paginador.
+pub mod pager;
+
+/// Módulo para exibir o plano dos fluxos SND e RLS sem executá-los.
+pub mod plan;
+
+/// Módulo que centraliza as mensagens ao usuário e respeita o modo silencioso.
+pub mod output;
+
 // NOTA DE ARQUITETURA:
 // Mantemos os namespaces explícitos (ex: `ui::menus::show_main_menu()` em vez de
 // `ui::show_main_menu()`) para maior clareza sobre de onde cada funcionalidade
@@ -0,0 +1,81 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo de Saída para o Usuário
+//
+// Centraliza as mensagens exibidas pelos fluxos, separando-as pelo papel que
+// cumprem. Assim, o modo silencioso (`--quiet`) pode descartar o que é apenas
+// decorativo (cabeçalhos, mensagens de progresso e de sucesso, avisos) sem
+// que cada fluxo precise verificá-lo.
+//
+// O que um script precisa consumir (`data`) e os erros (`error`) são sempre
+// exibidos.
+// ==============================================================================
+
+use console::style;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Se o modo silencioso está ativo (veja `set_quiet`).
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Ativa ou desativa o modo silencioso: apenas erros e a saída essencial
+/// dos comandos são exibidos.
+pub fn set_quiet(enabled: bool) {
+    QUIET.store(enabled, Ordering::Relaxed);
+}
+
+/// Retorna se o modo silencioso está ativo.
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Exibe o título de um fluxo (ex: "Iniciando fluxo de trabalho: ...").
+pub fn header(message: impl Display) {
+    if !is_quiet() {
+        println!("{}", style(message).bold().cyan());
+    }
+}
+
+/// Exibe uma mensagem de progresso ou de contexto (ex: "Criando a tag...").
+pub fn info(message: impl Display) {
+    if !is_quiet() {
+        println!("{}", message);
+    }
+}
+
+/// Exibe uma linha em branco, para separar blocos de saída.
+pub fn blank_line() {
+    if !is_quiet() {
+        println!();
+    }
+}
+
+/// Exibe a confirmação de que uma etapa foi concluída (ex: "✔ Push realizado").
+pub fn success(message: impl Display) {
+    if !is_quiet() {
+        println!("{}", style(message).green());
+    }
+}
+
+/// Exibe um aviso que não impede a operação.
+pub fn warning(message: impl Display) {
+    if !is_quiet() {
+        println!("{}", style(message).yellow());
+    }
+}
+
+/// Exibe a saída essencial de um comando (ex: um caminho, uma lista), que um
+/// script pode consumir. Exibida mesmo no modo silencioso.
+pub fn data(message: impl Display) {
+    println!("{}", message);
+}
+
+/// Exibe um erro no `stderr`. Exibido mesmo no modo silencioso.
+pub fn error(error: impl Display) {
+    eprintln!("{} {}", style("Erro:").red().bold(), style(error).red());
+}
\ No newline at end of file
@@ -0,0 +1,123 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo de Paginação de Saídas Longas
+//
+// Históricos, diffs e blames podem ter centenas de linhas e inundar o
+// terminal. Este módulo envia essas saídas para o paginador do usuário
+// (`$PAGER`, ou `less -R` na falta dele), como o próprio Git faz. Se nenhum
+// paginador puder ser executado, uma paginação interna simples é usada.
+//
+// Saídas que cabem em uma tela, saídas redirecionadas (ex: para um arquivo) e
+// a opção `use_pager = false` da configuração dispensam o paginador.
+// ==============================================================================
+
+use crate::config;
+use console::{style, Key, Term};
+use std::io::{ErrorKind, IsTerminal, Write};
+use std::process::{Command, Stdio};
+
+/// Exibe um texto, paginando-o se ele não couber na tela.
+///
+/// As cores (códigos ANSI) são preservadas: o `less` recebe `-R`, tanto no
+/// padrão quanto pela variável `LESS`, quando o usuário não a definiu.
+///
+/// # Arguments
+/// * `content` - O texto completo a exibir, já formatado.
+pub fn page(content: &str) {
+    let term = Term::stdout();
+    let enabled = config::load().map(|c| c.use_pager).unwrap_or(true);
+    let (rows, _) = term.size();
+    let fits = content.lines().count() < usize::from(rows);
+
+    if !enabled || fits || !std::io::stdout().is_terminal() {
+        print!("{}", with_trailing_newline(content));
+        return;
+    }
+
+    if !run_external_pager(content) {
+        page_internally(&term, content, usize::from(rows).saturating_sub(1).max(1));
+    }
+}
+
+/// Envia o texto para o paginador externo.
+///
+/// # Returns
+/// `true` se o texto foi exibido (mesmo que o usuário tenha saído antes do
+/// fim), ou `false` se o paginador não pôde ser executado.
+fn run_external_pager(content: &str) -> bool {
+    let pager = std::env::var("PAGER").unwrap_or_else(|_| "less -R".to_string());
+    let mut parts = pager.split_whitespace();
+    let Some(program) = parts.next() else {
+        // `PAGER=""` desativa a paginação, como no Git.
+        print!("{}", with_trailing_newline(content));
+        return true;
+    };
+    if program == "cat" {
+        print!("{}", with_trailing_newline(content));
+        return true;
+    }
+
+    let mut command = Command::new(program);
+    command.args(parts).stdin(Stdio::piped());
+    // F: sai se couber em uma tela; R: interpreta as cores; X: não limpa a tela.
+    if std::env::var_os("LESS").is_none() {
+        command.env("LESS", "FRX");
+    }
+
+    // Enquanto o paginador estiver aberto, o Ctrl-C é dele (veja
+    // `interrupt::foreground_program`).
+    let _foreground = crate::interrupt::foreground_program();
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            ::log::debug!("Não foi possível executar o paginador '{}': {}", pager, e);
+            return false;
+        }
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // Se o usuário sair do paginador antes do fim, a escrita falha com
+        // `BrokenPipe`, o que é esperado.
+        if let Err(e) = stdin.write_all(with_trailing_newline(content).as_bytes()) {
+            if e.kind() != ErrorKind::BrokenPipe {
+                ::log::debug!("Falha ao escrever no paginador: {}", e);
+            }
+        }
+    }
+    let _ = child.wait();
+    true
+}
+
+/// Pagina o texto sem um programa externo, uma tela por vez.
+///
+/// Enter, Espaço ou seta para baixo avançam; `q` ou Esc encerram a exibição.
+fn page_internally(term: &Term, content: &str, page_height: usize) {
+    let lines: Vec<&str> = content.lines().collect();
+    for (index, chunk) in lines.chunks(page_height).enumerate() {
+        if index > 0 {
+            let _ = term.write_str(&style("-- Mais -- (Enter para continuar, q para sair)").reverse().to_string());
+            let key = term.read_key();
+            let _ = term.clear_line();
+            if matches!(key, Ok(Key::Char('q')) | Ok(Key::Escape) | Err(_)) {
+                return;
+            }
+        }
+        for line in chunk {
+            println!("{}", line);
+        }
+    }
+}
+
+/// Garante que o texto termine com uma quebra de linha.
+fn with_trailing_newline(content: &str) -> String {
+    if content.is_empty() || content.ends_with('\n') {
+        content.to_string()
+    } else {
+        format!("{}\n", content)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,244 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Módulo do Modo Plano
+//
+// Monta e exibe, sem executar nada, a sequência de comandos Git e chamadas de
+// API que os fluxos SND e RLS fariam no estado atual do repositório.
+//
+// Os comandos vêm das mesmas funções que os fluxos usam para montá-los (ex:
+// `commit::commit_args`, `tag::annotated_tag_args`), então o plano não se
+// desvia do que seria de fato executado. Valores que o fluxo pediria ao
+// usuário (a mensagem de commit, o nome da tag) aparecem como `<marcadores>`.
+// ==============================================================================
+
+use crate::api_client::{self, Forge};
+use crate::config::{self, Config};
+use crate::git_wrapper::{branch, commit, config as git_config, remote, status, tag};
+use anyhow::Result;
+use console::style;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Se o modo plano está ativo no menu (veja `set_plan_mode`).
+static PLAN_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Ativa ou desativa o modo plano do menu: com ele ativo, as ações SND e RLS
+/// apenas exibem o plano.
+pub fn set_plan_mode(enabled: bool) {
+    PLAN_MODE.store(enabled, Ordering::Relaxed);
+}
+
+/// Retorna se o modo plano do menu está ativo.
+pub fn is_plan_mode() -> bool {
+    PLAN_MODE.load(Ordering::Relaxed)
+}
+
+/// O que uma etapa do plano faria.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StepAction {
+    /// Um comando Git, com seus argumentos (sem o `git`).
+    Git(Vec<String>),
+    /// Uma chamada de API (ex: `POST https://api.github.com/...`).
+    Api { method: &'static str, url: String },
+    /// Uma pergunta ao usuário, que pode encerrar o fluxo.
+    Prompt,
+}
+
+/// Uma etapa do plano.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlanStep {
+    /// O que a etapa faria.
+    pub action: StepAction,
+    /// Uma explicação curta da etapa.
+    pub description: String,
+    /// Se a etapa só acontece em alguns casos (ex: depende de uma resposta).
+    pub optional: bool,
+}
+
+/// O plano de um fluxo: suas etapas, em ordem, e observações gerais.
+#[derive(Debug, Clone, Default)]
+pub struct Plan {
+    /// O nome do fluxo (ex: "SND").
+    pub title: String,
+    /// As etapas, na ordem em que seriam executadas.
+    pub steps: Vec<PlanStep>,
+    /// Avisos sobre o estado atual (ex: "não há alterações para commitar").
+    pub notes: Vec<String>,
+}
+
+impl Plan {
+    fn git(&mut self, args: &[&str], description: &str) {
+        self.push(StepAction::Git(args.iter().map(|a| a.to_string()).collect()), description, false);
+    }
+
+    fn optional_git(&mut self, args: &[&str], description: &str) {
+        self.push(StepAction::Git(args.iter().map(|a| a.to_string()).collect()), description, true);
+    }
+
+    fn push(&mut self, action: StepAction, description: &str, optional: bool) {
+        self.steps.push(PlanStep {
+            action,
+            description: description.to_string(),
+            optional,
+        });
+    }
+}
+
+/// Monta o plano do fluxo SND (adicionar, commitar e enviar).
+///
+/// # Returns
+/// `Ok(Plan)` com as etapas, ou `Err` se o status do repositório ou a
+/// configuração não puderem ser lidos.
+pub fn snd_plan() -> Result<Plan> {
+    let config = config::load()?;
+    let mut plan = Plan {
+        title: "SND (Adicionar, Commitar, Pushar)".to_string(),
+        ..Default::default()
+    };
+    if status::get_status()?.files.is_empty() {
+        plan.notes.push("Não há alterações para commitar: o fluxo terminaria sem executar nada.".to_string());
+        return Ok(plan);
+    }
+    add_snd_steps(&mut plan, &config);
+    add_pull_request_step(&mut plan);
+    Ok(plan)
+}
+
+/// Monta o plano do fluxo RLS (SND seguido da criação de tag e release).
+///
+/// # Returns
+/// `Ok(Plan)` com as etapas, ou `Err` se a configuração não puder ser lida.
+pub fn rls_plan() -> Result<Plan> {
+    let config = config::load()?;
+    let mut plan = Plan {
+        title: "RLS (SND e Criar Tag/Release)".to_string(),
+        ..Default::default()
+    };
+    if status::get_status()?.files.is_empty() {
+        plan.notes.push("Não há alterações para commitar: a etapa SND seria pulada.".to_string());
+    } else {
+        add_snd_steps(&mut plan, &config);
+    }
+
+    let remote_url = match remote::origin_remote() {
+        Ok(remote_url) => remote_url,
+        Err(e) => {
+            plan.notes.push(format!("O fluxo pararia ao detectar o repositório remoto: {}", e));
+            return Ok(plan);
+        }
+    };
+    let Some(forge) = api_client::forge_for_host(&remote_url.host) else {
+        plan.notes.push(format!(
+            "O fluxo pararia: o host '{}' não é suportado (apenas GitHub e GitLab).",
+            remote_url.host
+        ));
+        return Ok(plan);
+    };
+    let has_token = match forge {
+        Forge::GitHub => config.github_token.is_some(),
+        Forge::GitLab => config.gitlab_token.is_some(),
+    };
+    if !has_token {
+        plan.notes.push("Nenhum token de API configurado: o fluxo pediria um antes de criar a tag.".to_string());
+    }
+
+    plan.push(StepAction::Prompt, "Pedir o nome da tag e as notas da release", false);
+
+    let release_commit = "chore: release <tag>";
+    let options = commit::CommitOptions {
+        sign: config.sign_commits,
+        signoff: config.signoff_by_default,
+        allow_empty: true,
+        ..Default::default()
+    };
+    plan.optional_git(&["add", "."], "Incluir as alterações pendentes no commit de release");
+    plan.optional_git(&commit::commit_args(release_commit, options), "Criar o commit de release");
+    plan.optional_git(&["push"], "Enviar o commit de release");
+
+    plan.git(&tag::annotated_tag_args("<tag>", "<tag>", config.sign_commits), "Criar a tag anotada");
+    plan.git(&tag::push_tag_args("<tag>"), "Enviar a tag para o remoto");
+
+    let url = match forge {
+        Forge::GitHub => api_client::github::releases_url(&remote_url.owner, &remote_url.repo),
+        Forge::GitLab => api_client::gitlab::releases_url(&remote_url.host, &remote_url.full_path()),
+    };
+    plan.push(StepAction::Api { method: "POST", url }, "Criar a release para a tag", false);
+    Ok(plan)
+}
+
+/// Acrescenta as etapas comuns do SND: stage, mensagem, commit e push.
+fn add_snd_steps(plan: &mut Plan, config: &Config) {
+    plan.git(&["add", "."], "Adicionar as alterações ao stage (o menu também oferece 'add -u' ou escolher arquivos)");
+
+    let message_source = if config.conventional_commits {
+        "Pedir a mensagem de commit pelo prompt guiado do Conventional Commits"
+    } else if matches!(git_config::commit_template(), Ok(Some(_))) {
+        "Pedir a mensagem de commit no editor, a partir do commit.template"
+    } else {
+        "Pedir a mensagem de commit"
+    };
+    plan.push(StepAction::Prompt, message_source, false);
+
+    let options = commit::CommitOptions {
+        sign: config.sign_commits,
+        signoff: config.signoff_by_default,
+        ..Default::default()
+    };
+    plan.git(&commit::commit_args("<mensagem>", options), "Criar o commit");
+
+    if config.confirm_before_push {
+        plan.push(StepAction::Prompt, "Confirmar o envio do commit", false);
+    }
+    let push_description = match branch::current_branch() {
+        Ok(Some(name)) => format!("Enviar a branch '{}' para o upstream", name),
+        _ => "Enviar a branch atual para o upstream".to_string(),
+    };
+    plan.git(&["push"], &push_description);
+}
+
+/// Acrescenta a oferta de Pull Request que segue o SND em branches que não
+/// são a padrão de um repositório do GitHub.
+fn add_pull_request_step(plan: &mut Plan) {
+    let Ok((owner, repo)) = remote::origin_github_repo() else {
+        return;
+    };
+    let current = branch::current_branch().ok().flatten();
+    let default = remote::default_branch().ok().flatten();
+    if current.is_none() || current == default {
+        return;
+    }
+    plan.push(
+        StepAction::Api {
+            method: "POST",
+            url: api_client::github::pulls_url(&owner, &repo),
+        },
+        "Abrir um Pull Request, se o usuário aceitar",
+        true,
+    );
+}
+
+/// Exibe um plano, numerando as etapas.
+pub fn display_plan(plan: &Plan) {
+    println!("{}", style(format!("Plano: {}", plan.title)).bold().cyan());
+    println!("{}", style("Nada será executado.").dim());
+    println!();
+    for (index, step) in plan.steps.iter().enumerate() {
+        let command = match &step.action {
+            StepAction::Git(args) => format!("{} git {}", style("[git]").green(), args.join(" ")),
+            StepAction::Api { method, url } => format!("{} {} {}", style("[api]").magenta(), method, url),
+            StepAction::Prompt => format!("{} {}", style("[pergunta]").yellow(), step.description),
+        };
+        let optional = if step.optional { style(" (opcional)").dim().to_string() } else { String::new() };
+        println!("{:>3}. {}{}", index + 1, command, optional);
+        if step.action != StepAction::Prompt {
+            println!("     {}", style(&step.description).dim());
+        }
+    }
+    for note in &plan.notes {
+        println!("{} {}", style("⚠").yellow(), style(note).yellow());
+    }
+}
\ No newline at end of file
@@ -12,27 +12,322 @@
 // da biblioteca `dialoguer` para fornecer uma API simples e focada em tarefas.
 // ==============================================================================
 
+use crate::git_wrapper::config as git_config;
+use crate::git_wrapper::status::{ChangeType, FileStatus};
 use anyhow::Result;
 use console::Term;
-use dialoguer::{theme::ColorfulTheme, Editor, Input, Confirm}; // Adicionamos o `Editor` e `Confirm`
+use dialoguer::{theme::ColorfulTheme, Editor, FuzzySelect, Input, Confirm, MultiSelect, Password}; // Adicionamos o `Editor`, `FuzzySelect`, `Confirm`, `MultiSelect` e `Password`
+use std::io;
+
+/// Esqueleto padrão das notas de release, usado quando não há um changelog
+/// gerado a partir dos commits.
+pub const RELEASE_NOTES_SKELETON: &str = "## Novidades\n\n\n## Correções\n\n\n## Melhorias\n\n";
+
+/// Conteúdo inicial do editor de commit quando não há um `commit.template`
+/// configurado.
+const COMMIT_EDITOR_FALLBACK: &str = "\n\
+# Escreva a mensagem do commit: a primeira linha é o resumo e, após uma\n\
+# linha em branco, vem a descrição detalhada.\n\
+# Linhas iniciadas com '#' são ignoradas; uma mensagem vazia cancela o commit.\n";
+
+/// Os tipos do Conventional Commits oferecidos pelo prompt guiado, com uma
+/// descrição curta de cada um.
+pub const CONVENTIONAL_TYPES: &[(&str, &str)] = &[
+    ("feat", "Uma nova funcionalidade"),
+    ("fix", "Uma correção de bug"),
+    ("docs", "Apenas documentação"),
+    ("chore", "Manutenção (build, dependências, etc.)"),
+    ("refactor", "Mudança de código que não corrige bug nem adiciona funcionalidade"),
+    ("test", "Adição ou correção de testes"),
+];
+
+/// Tamanho máximo da primeira linha (`tipo(escopo): assunto`) de um commit
+/// guiado, para que ela não seja cortada no `git log --oneline` e na web.
+pub const MAX_COMMIT_HEADER_LENGTH: usize = 72;
 
 /// Solicita ao usuário uma mensagem de commit.
 ///
 /// Apresenta um prompt de entrada de texto. A função lida com vários casos:
 /// - O usuário digita uma mensagem e pressiona Enter.
 /// - O usuário não digita nada e pressiona Enter (permitido, mas pode ser validado).
-/// - O usuário cancela a operação (pressionando Esc).
+/// - O usuário interrompe a leitura (Ctrl-C).
 ///
 /// # Returns
-/// - `Ok(Some(String))` se o usuário fornecer uma mensagem.
-/// - `Ok(None)` se o usuário cancelar a operação.
+/// - `Ok(Some(String))` com o texto digitado, que pode ser vazio: cabe ao
+///   chamador tratar uma mensagem vazia como cancelamento.
+/// - `Ok(None)` se o usuário interromper a leitura.
 /// - `Err` se houver um problema ao interagir com o terminal.
 pub fn get_commit_message() -> Result<Option<String>> {
-    let input = Input::with_theme(&ColorfulTheme::default())
-        .with_prompt("Digite a mensagem de commit (pressione ESC para cancelar)")
-        .allow_empty(true)
-        .interact_text_on_opt(&Term::stdout())?;
-    Ok(input)
+    read_text(
+        Input::with_theme(&ColorfulTheme::default())
+            .with_prompt("Digite a mensagem de commit (deixe vazio para cancelar)")
+            .allow_empty(true),
+    )
+}
+
+/// Lê a resposta de um prompt de texto.
+///
+/// Os prompts de texto do `dialoguer` não têm cancelamento próprio (o Esc é
+/// ignorado), então o único cancelamento explícito é a interrupção da
+/// leitura (Ctrl-C, que chega como `ErrorKind::Interrupted` com o terminal em
+/// modo raw). Respostas vazias são devolvidas como estão: cada prompt decide
+/// se elas cancelam a operação.
+///
+/// # Returns
+/// - `Ok(Some(String))` com o texto digitado.
+/// - `Ok(None)` se a leitura for interrompida.
+/// - `Err` se houver um problema com o terminal.
+fn read_text(input: Input<String>) -> Result<Option<String>> {
+    match input.interact_text_on(&Term::stdout()) {
+        Ok(text) => Ok(Some(text)),
+        Err(dialoguer::Error::IO(e)) if e.kind() == io::ErrorKind::Interrupted => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Abre o editor de texto padrão do sistema para a mensagem de commit.
+///
+/// Se o repositório ou o usuário tiver um template configurado
+/// (`commit.template`, ex: um `.gitmessage` do time), o editor já abre com
+/// ele; caso contrário, com um esqueleto mínimo. Como no `git commit`, linhas
+/// iniciadas com `#` são descartadas.
+///
+/// # Returns
+/// - `Ok(Some(String))` com a mensagem final, sem os comentários.
+/// - `Ok(None)` se o usuário sair sem salvar, deixar a mensagem vazia ou não
+///   alterar o template.
+/// - `Err` se o template configurado não puder ser lido ou o editor não
+///   puder ser aberto.
+pub fn get_commit_message_from_editor() -> Result<Option<String>> {
+    let template = git_config::commit_template()?.unwrap_or_else(|| COMMIT_EDITOR_FALLBACK.to_string());
+    let Some(edited) = get_long_text("a mensagem do commit", &template)? else {
+        return Ok(None);
+    };
+
+    let message = strip_comment_lines(&edited);
+    // Assim como o Git, um template salvo sem alterações cancela o commit.
+    if message.is_empty() || message == strip_comment_lines(&template) {
+        return Ok(None);
+    }
+    Ok(Some(message))
+}
+
+/// Remove as linhas de comentário (iniciadas com `#`) e os espaços nas pontas.
+fn strip_comment_lines(text: &str) -> String {
+    text.lines()
+        .filter(|line| !line.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// Guia o usuário na escrita de uma mensagem no padrão Conventional Commits.
+///
+/// Pergunta, em ordem: o tipo, o escopo (opcional), se é uma mudança
+/// incompatível (breaking change), o assunto e o corpo (opcional). Uma
+/// mudança incompatível recebe o `!` no cabeçalho e exige a descrição que
+/// vai no rodapé `BREAKING CHANGE:`.
+///
+/// O assunto e a descrição da mudança incompatível são obrigatórios: deixá-los
+/// vazios cancela o commit.
+///
+/// # Returns
+/// - `Ok(Some(String))` com a mensagem montada (ex: `feat(api): adiciona login`).
+/// - `Ok(None)` se o usuário cancelar qualquer uma das etapas.
+/// - `Err` se houver um problema com o terminal ou com o editor.
+pub fn get_conventional_commit() -> Result<Option<String>> {
+    let labels: Vec<String> = CONVENTIONAL_TYPES
+        .iter()
+        .map(|(kind, description)| format!("{:<9} {}", kind, description))
+        .collect();
+    let Some(index) = fuzzy_select("Tipo do commit", &labels, 0)? else {
+        return Ok(None);
+    };
+    let kind = CONVENTIONAL_TYPES[index].0;
+
+    let Some(scope) = read_text(
+        Input::<String>::with_theme(&ColorfulTheme::default())
+            .with_prompt("Escopo (opcional, ex: api)")
+            .allow_empty(true)
+            .validate_with(|scope: &String| -> std::result::Result<(), String> {
+                match scope.trim().chars().find(|c| c.is_whitespace() || "():!".contains(*c)) {
+                    Some(c) => Err(format!("O escopo não pode conter '{}'.", c)),
+                    None => Ok(()),
+                }
+            }),
+    )?
+    else {
+        return Ok(None);
+    };
+    let scope = scope.trim().to_string();
+
+    let breaking = confirm("É uma mudança incompatível (breaking change)?", false)?;
+    let prefix = conventional_prefix(kind, &scope, breaking);
+
+    let subject = read_text(
+        Input::<String>::with_theme(&ColorfulTheme::default())
+            .with_prompt(format!("Assunto ({}<assunto>, vazio para cancelar)", prefix))
+            .allow_empty(true)
+            .validate_with(|subject: &String| -> std::result::Result<(), String> {
+                // O assunto vazio cancela o commit, então não é um erro aqui.
+                if subject.trim().is_empty() {
+                    return Ok(());
+                }
+                validate_commit_subject(&prefix, subject)
+            }),
+    )?;
+    let Some(subject) = subject.filter(|s| !s.trim().is_empty()) else {
+        return Ok(None);
+    };
+
+    let body = if confirm("Deseja adicionar um corpo ao commit?", false)? {
+        get_long_text("o corpo do commit", "")?.map(|b| b.trim().to_string()).unwrap_or_default()
+    } else {
+        String::new()
+    };
+
+    let breaking_note = if breaking {
+        // Uma mudança incompatível precisa ser descrita: sem a descrição, o
+        // commit é cancelado.
+        let note = read_text(
+            Input::<String>::with_theme(&ColorfulTheme::default())
+                .with_prompt("Descreva a mudança incompatível (vai no rodapé BREAKING CHANGE; vazio para cancelar)")
+                .allow_empty(true),
+        )?;
+        match note {
+            Some(note) if !note.trim().is_empty() => Some(note.trim().to_string()),
+            _ => return Ok(None),
+        }
+    } else {
+        None
+    };
+
+    let mut message = format!("{}{}", prefix, subject.trim());
+    if !body.is_empty() {
+        message.push_str("\n\n");
+        message.push_str(&body);
+    }
+    if let Some(note) = breaking_note {
+        message.push_str("\n\nBREAKING CHANGE: ");
+        message.push_str(&note);
+    }
+    Ok(Some(message))
+}
+
+/// Monta o prefixo do cabeçalho: `tipo`, `(escopo)` se houver, `!` se for uma
+/// mudança incompatível, e o `: ` final.
+fn conventional_prefix(kind: &str, scope: &str, breaking: bool) -> String {
+    let scope = if scope.is_empty() { String::new() } else { format!("({})", scope) };
+    format!("{}{}{}: ", kind, scope, if breaking { "!" } else { "" })
+}
+
+/// Verifica o assunto de um commit guiado.
+///
+/// # Returns
+/// `Ok(())` se o assunto for válido, ou `Err` com uma mensagem explicando o problema.
+fn validate_commit_subject(prefix: &str, subject: &str) -> std::result::Result<(), String> {
+    let subject = subject.trim();
+    if subject.is_empty() {
+        return Err("O assunto não pode ser vazio.".to_string());
+    }
+    let length = prefix.chars().count() + subject.chars().count();
+    if length > MAX_COMMIT_HEADER_LENGTH {
+        return Err(format!(
+            "A primeira linha tem {} caracteres; o máximo é {}.",
+            length, MAX_COMMIT_HEADER_LENGTH
+        ));
+    }
+    if subject.ends_with('.') {
+        return Err("O assunto não deve terminar com ponto final.".to_string());
+    }
+    Ok(())
+}
+
+/// Solicita ao usuário o nome de uma branch, validando-o enquanto ele digita.
+///
+/// A validação rejeita os caracteres e sequências que o Git não aceita em
+/// nomes de referência (espaços, `~`, `^`, `:`, `?`, `*`, `[` e `..`), de modo
+/// que o usuário recebe o feedback no próprio prompt, antes de invocar o Git.
+///
+/// # Returns
+/// - `Ok(Some(String))` com um nome válido.
+/// - `Ok(None)` se o usuário deixar o nome vazio ou interromper a leitura
+///   (Ctrl-C), o que tratamos como cancelamento.
+/// - `Err` se houver um problema com o terminal.
+pub fn get_branch_name() -> Result<Option<String>> {
+    let name = read_text(
+        Input::with_theme(&ColorfulTheme::default())
+            .with_prompt("Digite o nome da branch (deixe vazio para cancelar)")
+            .allow_empty(true)
+            .validate_with(|name: &String| -> std::result::Result<(), String> {
+                // O nome vazio cancela a operação, então não é um erro aqui.
+                if name.is_empty() {
+                    return Ok(());
+                }
+                validate_branch_name(name)
+            }),
+    )?;
+    Ok(name.filter(|name| !name.is_empty()))
+}
+
+/// Verifica se um nome de branch é aceitável para o Git.
+///
+/// # Returns
+/// `Ok(())` se o nome for válido, ou `Err` com uma mensagem explicando o problema.
+fn validate_branch_name(name: &str) -> std::result::Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("O nome da branch não pode ser vazio.".to_string());
+    }
+    if let Some(c) = name.chars().find(|c| c.is_whitespace() || "~^:?*[\\".contains(*c)) {
+        return Err(format!("O nome da branch não pode conter '{}'.", c));
+    }
+    if name.contains("..") {
+        return Err("O nome da branch não pode conter '..'.".to_string());
+    }
+    if name.starts_with('-') || name.starts_with('/') || name.ends_with('/') || name.ends_with(".lock") {
+        return Err("O nome da branch não pode começar com '-' ou '/', nem terminar com '/' ou '.lock'.".to_string());
+    }
+    Ok(())
+}
+
+/// Solicita ao usuário uma linha de texto, pré-preenchida com um valor inicial.
+///
+/// # Arguments
+/// * `prompt` - A pergunta a ser exibida.
+/// * `initial` - O texto inicial, que o usuário pode aceitar ou editar.
+///
+/// # Returns
+/// - `Ok(Some(String))` com o texto digitado, que pode ser vazio (os
+///   chamadores tratam o texto vazio como cancelamento ou valor ausente).
+/// - `Ok(None)` se o usuário interromper a leitura (Ctrl-C).
+/// - `Err` se houver um problema com o terminal.
+pub fn get_text(prompt: &str, initial: &str) -> Result<Option<String>> {
+    read_text(
+        Input::with_theme(&ColorfulTheme::default())
+            .with_prompt(prompt)
+            .with_initial_text(initial)
+            .allow_empty(true),
+    )
+}
+
+/// Solicita um valor secreto (como um token de API) sem exibi-lo na tela.
+///
+/// # Arguments
+/// * `prompt` - A instrução exibida ao usuário.
+///
+/// # Returns
+/// - `Ok(Some(String))` com o valor digitado.
+/// - `Ok(None)` se o usuário não digitar nada, o que tratamos como cancelamento.
+/// - `Err` se houver um problema com o terminal.
+pub fn get_secret(prompt: &str) -> Result<Option<String>> {
+    let secret = Password::with_theme(&ColorfulTheme::default())
+        .with_prompt(format!("{} (deixe vazio para cancelar)", prompt))
+        .allow_empty_password(true)
+        .interact_on(&Term::stdout())?;
+
+    let secret = secret.trim().to_string();
+    Ok(if secret.is_empty() { None } else { Some(secret) })
 }
 
 /// Solicita uma confirmação (sim/não) do usuário.
@@ -55,24 +350,143 @@ pub fn confirm(prompt: &str, default_val: bool) -> Result<bool> {
     Ok(confirmation)
 }
 
+/// Apresenta uma lista de itens que o usuário pode filtrar digitando.
+///
+/// Ideal para listas longas (como branches), em que navegar apenas com as
+/// setas seria lento. O texto digitado é comparado de forma aproximada
+/// ("fuzzy") com cada item.
+///
+/// # Arguments
+/// * `prompt` - A instrução exibida acima da lista.
+/// * `items` - Os itens a serem exibidos.
+/// * `default` - O índice do item inicialmente destacado.
+///
+/// # Returns
+/// - `Ok(Some(usize))` com o índice do item escolhido.
+/// - `Ok(None)` se o usuário cancelar a operação (pressionando Esc).
+/// - `Err` se houver um problema com o terminal.
+pub fn fuzzy_select<T: ToString>(prompt: &str, items: &[T], default: usize) -> Result<Option<usize>> {
+    let selection = FuzzySelect::with_theme(&ColorfulTheme::default())
+        .with_prompt(prompt)
+        .items(items)
+        .default(default)
+        .interact_on_opt(&Term::stdout())?;
+    Ok(selection)
+}
+
+/// Apresenta uma lista de itens e permite ao usuário marcar vários deles.
+///
+/// O usuário navega com as setas, marca/desmarca com Espaço e confirma com Enter.
+///
+/// # Arguments
+/// * `prompt` - A instrução exibida acima da lista.
+/// * `items` - Os itens a serem exibidos.
+///
+/// # Returns
+/// - `Ok(Some(Vec<usize>))` com os índices dos itens marcados.
+/// - `Ok(None)` se o usuário cancelar a operação (pressionando Esc).
+/// - `Err` se houver um problema com o terminal.
+pub fn multi_select<T: ToString>(prompt: &str, items: &[T]) -> Result<Option<Vec<usize>>> {
+    let selection = MultiSelect::with_theme(&ColorfulTheme::default())
+        .with_prompt(prompt)
+        .items(items)
+        .interact_on_opt(&Term::stdout())?;
+    Ok(selection)
+}
+
+/// Permite ao usuário escolher, entre os arquivos alterados, quais irão para o stage.
+///
+/// Cada arquivo é exibido com o tipo da alteração. Os arquivos que já estão no
+/// stage aparecem pré-marcados.
+///
+/// # Arguments
+/// * `files` - Os arquivos alterados, tipicamente de `status::get_status()`.
+///
+/// # Returns
+/// - `Ok(Vec<String>)` com os caminhos marcados. Um vetor vazio significa que o
+///   usuário cancelou (Esc) ou não marcou nada, e deve ser tratado como cancelamento.
+/// - `Err` se houver um problema com o terminal.
+pub fn select_files(files: &[FileStatus]) -> Result<Vec<String>> {
+    let labels: Vec<String> = files
+        .iter()
+        .map(|f| {
+            // Mostramos a alteração pendente mais relevante: a do diretório de
+            // trabalho, se houver, ou então a que já está no stage.
+            let change = f.unstaged_status.as_ref().or(f.staged_status.as_ref());
+            format!("{} {}", change_label(change), f.display_path())
+        })
+        .collect();
+    let defaults: Vec<bool> = files
+        .iter()
+        .map(|f| f.staged_status.is_some() && f.staged_status != Some(ChangeType::Untracked))
+        .collect();
+
+    let selection = MultiSelect::with_theme(&ColorfulTheme::default())
+        .with_prompt("Marque com Espaço os arquivos a incluir no commit (Enter para confirmar)")
+        .items(&labels)
+        .defaults(&defaults)
+        .interact_on_opt(&Term::stdout())?;
+
+    Ok(selection
+        .unwrap_or_default()
+        .into_iter()
+        .map(|i| files[i].path.clone())
+        .collect())
+}
+
+/// Retorna um rótulo curto e de largura fixa para um tipo de alteração.
+fn change_label(change: Option<&ChangeType>) -> &'static str {
+    match change {
+        Some(ChangeType::Added) => "[adicionado]",
+        Some(ChangeType::Modified) => "[modificado]",
+        Some(ChangeType::Deleted) => "[deletado]  ",
+        Some(ChangeType::Renamed) => "[renomeado] ",
+        Some(ChangeType::Copied) => "[copiado]   ",
+        Some(ChangeType::TypeChanged) => "[tipo alt.] ",
+        Some(ChangeType::Unmerged) => "[conflito]  ",
+        Some(ChangeType::Untracked) => "[novo]      ",
+        None => "            ",
+    }
+}
+
+/// Abre o editor de texto padrão do sistema para um texto livre (ex: a
+/// descrição de uma issue ou uma mensagem de commit).
+///
+/// # Arguments
+/// * `what` - O que está sendo editado, exibido na instrução ao usuário.
+/// * `template` - O texto inicial do editor.
+///
+/// # Returns
+/// - `Ok(Some(String))` se o usuário salvar o conteúdo no editor.
+/// - `Ok(None)` se o usuário sair do editor sem salvar.
+/// - `Err` se o editor não puder ser aberto.
+pub fn get_long_text(what: &str, template: &str) -> Result<Option<String>> {
+    println!("{}", console::style(format!("Abrindo seu editor de texto padrão para {}...", what)).dim());
+    println!("{}", console::style("Dica: Salve e feche o arquivo para continuar, ou feche sem salvar para cancelar.").dim());
+    Ok(Editor::new().edit(template)?)
+}
+
 /// Abre o editor de texto padrão do sistema para obter uma entrada multi-linha.
 ///
 /// Esta abordagem é ideal para textos longos, como notas de release, pois
 /// oferece uma experiência de edição muito superior a um prompt de linha única.
 ///
+/// # Arguments
+/// * `template` - O texto pré-preenchido no editor (ex: um changelog gerado
+///   automaticamente). O usuário pode editá-lo livremente antes de salvar.
+///
 /// # Returns
 /// - `Ok(Some(String))` se o usuário salvar o conteúdo no editor.
 /// - `Ok(None)` se o usuário sair do editor sem salvar (ou se o arquivo ficar vazio).
 /// - `Err` se o editor não puder ser aberto.
-pub fn get_release_notes() -> Result<Option<String>> {
+pub fn get_release_notes(template: &str) -> Result<Option<String>> {
     println!("{}", console::style("Abrindo seu editor de texto padrão para as notas da release...").dim());
     println!("{}", console::style("Dica: Salve e feche o arquivo para continuar, ou feche sem salvar para cancelar.").dim());
 
     // `Editor::new()` cria uma instância do prompt do editor.
     let response = Editor::new()
-        // O texto a seguir será pré-preenchido no arquivo temporário que o editor abrir.
-        // Isso serve como um template útil para o usuário.
-        .edit("## Novidades\n\n\n## Correções\n\n\n## Melhorias\n\n")?
+        // O template será pré-preenchido no arquivo temporário que o editor abrir.
+        .edit(template)?
         ;
 
     // `edit()` retorna `Ok(Option<String>)`. `None` significa que o usuário
@@ -0,0 +1,451 @@
+/**
+ * Copyright © Pedro H. Garcia (phkaiser13)
+ * SPDX-License-Identifier: GPL-3.0
+ * This file is licensed under the GNU General Public License v3.0.
+ */
+
+// ==============================================================================
+// Testes de Integração do `git_wrapper`
+//
+// Cada teste cria um repositório Git real em um diretório temporário e
+// exercita os wrappers contra ele, verificando o comportamento de ponta a
+// ponta (execução do Git + análise da saída).
+//
+// Os wrappers executam o Git no diretório atual do processo. Como ele é
+// compartilhado entre as threads de teste, `TestRepo` muda para o repositório
+// temporário mantendo um lock global, o que serializa os testes deste arquivo.
+//
+// Se o Git não estiver instalado, os testes são ignorados com um aviso.
+// ==============================================================================
+
+use gitph::git_wrapper::branch::{self, UncommittedChangesError, UnmergedBranchError};
+use gitph::git_wrapper::commit::{self, CommitOptions};
+use gitph::git_wrapper::status::{self, ChangeType};
+use gitph::git_wrapper::{blame, fetch, hooks, log, pull, remote, stats, tag, ConflictError, VerificationStatus};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{Mutex, MutexGuard};
+use tempfile::TempDir;
+
+/// Serializa os testes, que alteram o diretório atual do processo.
+static CWD_LOCK: Mutex<()> = Mutex::new(());
+
+/// Um repositório Git temporário, que é também o diretório atual enquanto existir.
+struct TestRepo {
+    dir: TempDir,
+    previous_dir: PathBuf,
+    _guard: MutexGuard<'static, ()>,
+}
+
+impl TestRepo {
+    /// Cria um repositório vazio na branch `main`, com uma identidade local.
+    ///
+    /// Retorna `None` (e o teste deve encerrar) se o Git não estiver instalado.
+    fn new() -> Option<Self> {
+        if Command::new("git").arg("--version").output().is_err() {
+            eprintln!("Git não encontrado; teste ignorado.");
+            return None;
+        }
+
+        // Um teste que falhou envenena o lock, mas não invalida os próximos.
+        let guard = CWD_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = tempfile::tempdir().expect("falha ao criar o diretório temporário");
+
+        git(dir.path(), &["init", "-q"]);
+        git(dir.path(), &["symbolic-ref", "HEAD", "refs/heads/main"]);
+        // A configuração global de quem executa os testes (ex: assinatura
+        // obrigatória) não deve interferir.
+        for (key, value) in [
+            ("user.name", "gitph"),
+            ("user.email", "gitph@example.com"),
+            ("commit.gpgsign", "false"),
+            ("tag.gpgsign", "false"),
+        ] {
+            git(dir.path(), &["config", key, value]);
+        }
+
+        let previous_dir = std::env::current_dir().expect("diretório atual inválido");
+        std::env::set_current_dir(dir.path()).expect("falha ao entrar no repositório temporário");
+        Some(TestRepo {
+            dir,
+            previous_dir,
+            _guard: guard,
+        })
+    }
+
+    /// Escreve um arquivo no repositório.
+    fn write(&self, name: &str, content: &str) {
+        fs::write(self.dir.path().join(name), content).expect("falha ao escrever o arquivo");
+    }
+
+    /// Escreve um arquivo e o commita com os wrappers do `gitph`.
+    fn commit_file(&self, name: &str, content: &str, message: &str) {
+        self.write(name, content);
+        commit::add_all().expect("add_all falhou");
+        commit::commit(message, CommitOptions::default()).expect("commit falhou");
+    }
+
+    /// Instala um hook executável com o script informado.
+    #[cfg(unix)]
+    fn install_hook(&self, name: &str, script: &str) {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = self.dir.path().join(".git").join("hooks").join(name);
+        fs::write(&path, script).expect("falha ao escrever o hook");
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).expect("falha ao tornar o hook executável");
+    }
+}
+
+impl Drop for TestRepo {
+    fn drop(&mut self) {
+        // Sai do diretório antes que o `TempDir` o remova.
+        let _ = std::env::set_current_dir(&self.previous_dir);
+    }
+}
+
+/// Executa um comando Git auxiliar (fora dos wrappers) em `dir`.
+fn git(dir: &Path, args: &[&str]) {
+    let output = Command::new("git").args(args).current_dir(dir).output().expect("falha ao executar o git");
+    assert!(
+        output.status.success(),
+        "git {:?} falhou: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn status_reports_staged_modified_and_untracked_files() {
+    let Some(repo) = TestRepo::new() else { return };
+    repo.commit_file("existente.txt", "v1\n", "chore: inicial");
+
+    repo.write("existente.txt", "v2\n");
+    repo.write("novo.txt", "novo\n");
+    repo.write("rascunho com espaço.txt", "rascunho\n");
+    commit::add_paths(&["novo.txt".to_string()]).unwrap();
+
+    let status = status::get_status().unwrap();
+    let find = |path: &str| status.files.iter().find(|f| f.path == path).unwrap();
+
+    assert_eq!(find("existente.txt").unstaged_status, Some(ChangeType::Modified));
+    assert_eq!(find("novo.txt").staged_status, Some(ChangeType::Added));
+    assert_eq!(find("rascunho com espaço.txt").unstaged_status, Some(ChangeType::Untracked));
+    assert!(status.branch_info.starts_with("main"));
+}
+
+#[test]
+fn status_before_the_first_commit() {
+    let Some(repo) = TestRepo::new() else { return };
+    repo.write("README.md", "# teste\n");
+    commit::add_all().unwrap();
+
+    let status = status::get_status().unwrap();
+    assert_eq!(status.files.len(), 1);
+    assert_eq!(status.files[0].staged_status, Some(ChangeType::Added));
+    assert!(log::recent_commits(5).unwrap().is_empty());
+}
+
+#[test]
+fn commit_records_the_message_and_cleans_the_status() {
+    let Some(repo) = TestRepo::new() else { return };
+    repo.commit_file("a.txt", "a\n", "feat: adiciona a");
+
+    assert!(status::get_status().unwrap().files.is_empty());
+    assert_eq!(log::last_commit_message().unwrap(), "feat: adiciona a");
+
+    let commits = log::recent_commits(5).unwrap();
+    assert_eq!(commits.len(), 1);
+    assert_eq!(commits[0].subject, "feat: adiciona a");
+}
+
+#[test]
+fn commit_without_changes_fails() {
+    let Some(repo) = TestRepo::new() else { return };
+    repo.commit_file("a.txt", "a\n", "chore: inicial");
+
+    assert!(commit::commit("vazio", CommitOptions::default()).is_err());
+    let options = CommitOptions {
+        allow_empty: true,
+        ..Default::default()
+    };
+    commit::commit("vazio permitido", options).unwrap();
+    assert_eq!(log::recent_commits(5).unwrap().len(), 2);
+}
+
+#[test]
+#[cfg(unix)]
+fn commits_rejected_by_a_hook_are_reported_as_such() {
+    let Some(repo) = TestRepo::new() else { return };
+    repo.commit_file("a.txt", "a\n", "chore: inicial");
+    repo.install_hook("pre-commit", "#!/bin/sh\necho 'lint falhou' >&2\nexit 1\n");
+
+    repo.write("a.txt", "b\n");
+    commit::add_all().unwrap();
+    let err = commit::commit("feat: b", CommitOptions::default()).unwrap_err();
+    assert!(err.downcast_ref::<commit::HookRejectedError>().is_some());
+}
+
+#[test]
+#[cfg(unix)]
+fn unrelated_failures_are_not_blamed_on_an_installed_hook() {
+    let Some(repo) = TestRepo::new() else { return };
+    repo.commit_file("a.txt", "a\n", "chore: inicial");
+    repo.install_hook("pre-commit", "#!/bin/sh\nexit 0\n");
+
+    // Alterações fora do stage: o commit falha, mas o hook o aprovaria.
+    repo.write("a.txt", "b\n");
+    let err = commit::commit("feat: b", CommitOptions::default()).unwrap_err();
+    assert!(err.downcast_ref::<commit::HookRejectedError>().is_none());
+}
+
+#[test]
+#[cfg(unix)]
+fn commit_msg_hook_counts_characters_instead_of_bytes() {
+    let Some(repo) = TestRepo::new() else { return };
+    repo.commit_file("a.txt", "a\n", "chore: inicial");
+    hooks::install_commit_msg_hook().unwrap();
+
+    // 72 caracteres, mas 136 bytes em UTF-8.
+    let header = format!("feat: {}", "ç".repeat(66));
+    assert_eq!(header.chars().count(), 72);
+    repo.write("a.txt", "b\n");
+    commit::add_all().unwrap();
+    commit::commit(&header, CommitOptions::default()).unwrap();
+
+    let header = format!("feat: {}", "ç".repeat(67));
+    repo.write("a.txt", "c\n");
+    commit::add_all().unwrap();
+    let err = commit::commit(&header, CommitOptions::default()).unwrap_err();
+    assert!(err.downcast_ref::<commit::HookRejectedError>().is_some());
+}
+
+#[test]
+fn branches_can_be_created_listed_and_switched() {
+    let Some(repo) = TestRepo::new() else { return };
+    repo.commit_file("a.txt", "a\n", "chore: inicial");
+
+    branch::create_branch("feature").unwrap();
+    let branches = branch::list_branches().unwrap();
+    let names: Vec<&str> = branches.iter().map(|b| b.name.as_str()).collect();
+    assert_eq!(names, vec!["feature", "main"]);
+    assert!(branches.iter().find(|b| b.name == "main").unwrap().is_current);
+
+    branch::switch_branch("feature").unwrap();
+    assert_eq!(branch::current_branch().unwrap().as_deref(), Some("feature"));
+
+    branch::create_and_switch("outra").unwrap();
+    assert_eq!(branch::current_branch().unwrap().as_deref(), Some("outra"));
+
+    assert!(branch::create_branch("feature").is_err());
+    assert!(branch::switch_branch("inexistente").is_err());
+}
+
+#[test]
+fn switch_blocked_by_local_changes_is_reported() {
+    let Some(repo) = TestRepo::new() else { return };
+    repo.commit_file("a.txt", "main\n", "chore: inicial");
+    branch::create_and_switch("feature").unwrap();
+    repo.commit_file("a.txt", "feature\n", "feat: altera a");
+    branch::switch_branch("main").unwrap();
+
+    repo.write("a.txt", "alteração local\n");
+    let error = branch::switch_branch("feature").unwrap_err();
+    let uncommitted = error
+        .downcast_ref::<UncommittedChangesError>()
+        .expect("deveria ser um UncommittedChangesError");
+    assert_eq!(uncommitted.files, vec!["a.txt"]);
+    assert_eq!(branch::current_branch().unwrap().as_deref(), Some("main"));
+}
+
+#[test]
+fn annotated_tags_are_created_and_listed() {
+    let Some(repo) = TestRepo::new() else { return };
+    repo.commit_file("a.txt", "a\n", "chore: inicial");
+
+    assert_eq!(tag::latest_tag().unwrap(), None);
+    tag::create_annotated_tag("v1.0.0", "Primeira versão", false).unwrap();
+    assert_eq!(tag::latest_tag().unwrap().as_deref(), Some("v1.0.0"));
+    assert_eq!(tag::list_tags().unwrap(), vec!["v1.0.0"]);
+    assert_eq!(tag::verify_tag("v1.0.0").unwrap(), VerificationStatus::Unsigned);
+
+    assert!(tag::create_annotated_tag("v1.0.0", "Duplicada", false).is_err());
+    assert!(tag::create_annotated_tag("  ", "Sem nome", false).is_err());
+}
+
+#[test]
+fn mailmap_canonicalizes_author_names() {
+    let Some(repo) = TestRepo::new() else { return };
+    // Um commit feito com uma identidade antiga do mesmo contribuidor.
+    repo.write("a.txt", "a\n");
+    commit::add_all().unwrap();
+    git(
+        repo.dir.path(),
+        &["-c", "user.name=Apelido", "-c", "user.email=antigo@example.com", "commit", "-q", "-m", "chore: inicial"],
+    );
+    repo.write(".mailmap", "Nome Canônico <canonico@example.com> <antigo@example.com>\n");
+    // Antes do Git 2.29 o `git log`/`git show` não aplicava o `.mailmap` por padrão.
+    git(repo.dir.path(), &["config", "log.mailmap", "false"]);
+
+    let details = log::show_commit("HEAD").unwrap();
+    assert_eq!(details.author, "Nome Canônico <canonico@example.com>");
+
+    let lines = blame::blame("a.txt").unwrap();
+    assert_eq!(lines[0].author, "Nome Canônico");
+
+    let stats = stats::repo_stats(stats::DEFAULT_COMMIT_LIMIT).unwrap();
+    let names: Vec<&str> = stats.authors.iter().map(|a| a.name.as_str()).collect();
+    assert_eq!(names, vec!["Nome Canônico"]);
+}
+
+#[test]
+fn insteadof_rewrites_are_applied_only_when_parsing_the_origin() {
+    let Some(repo) = TestRepo::new() else { return };
+    git(repo.dir.path(), &["config", "url.https://github.com/.insteadOf", "corp:"]);
+    git(repo.dir.path(), &["config", "url.git@github.com:acme-mirror/.insteadOf", "corp:acme/"]);
+    git(repo.dir.path(), &["remote", "add", "origin", "corp:octo/hello.git"]);
+
+    // A URL configurada é preservada; só a análise usa a URL reescrita.
+    assert_eq!(remote::get_origin_url().unwrap(), "corp:octo/hello.git");
+    let origin = remote::origin_remote().unwrap();
+    assert_eq!(
+        (origin.host.as_str(), origin.owner.as_str(), origin.repo.as_str()),
+        ("github.com", "octo", "hello")
+    );
+
+    // O prefixo mais longo vence, e mudar o remoto descarta o cache.
+    remote::set_remote_url("origin", "corp:acme/tools.git").unwrap();
+    assert_eq!(remote::get_origin_url().unwrap(), "corp:acme/tools.git");
+    assert_eq!(
+        remote::origin_github_repo().unwrap(),
+        ("acme-mirror".to_string(), "tools".to_string())
+    );
+}
+
+#[test]
+fn urls_without_a_matching_insteadof_are_unchanged() {
+    let rewrites = vec![("corp:".to_string(), "https://github.com/".to_string())];
+    assert_eq!(
+        remote::apply_url_rewrites("git@github.com:octo/hello.git", &rewrites),
+        "git@github.com:octo/hello.git"
+    );
+    assert_eq!(
+        remote::apply_url_rewrites("corp:octo/hello.git", &rewrites),
+        "https://github.com/octo/hello.git"
+    );
+}
+
+#[test]
+fn commits_lost_by_a_reset_are_recovered_from_the_reflog() {
+    let Some(repo) = TestRepo::new() else { return };
+    repo.commit_file("a.txt", "a\n", "chore: inicial");
+    repo.commit_file("b.txt", "b\n", "feat: commit perdido");
+    git(repo.dir.path(), &["reset", "-q", "--hard", "HEAD~1"]);
+
+    let entries = log::reflog(10).unwrap();
+    assert_eq!(entries[0].selector, "HEAD@{0}");
+    assert_eq!(entries[0].action, "reset");
+    let lost = entries
+        .iter()
+        .find(|e| e.subject == "feat: commit perdido")
+        .expect("o commit perdido deveria estar no reflog");
+    assert_eq!(lost.action, "commit");
+
+    branch::create_branch_from("recuperado", &lost.sha).unwrap();
+    branch::switch_branch("recuperado").unwrap();
+    assert_eq!(log::recent_commits(1).unwrap()[0].subject, "feat: commit perdido");
+}
+
+#[test]
+fn reflog_is_empty_before_the_first_commit() {
+    let Some(_repo) = TestRepo::new() else { return };
+    assert!(log::reflog(10).unwrap().is_empty());
+}
+
+#[test]
+fn pull_reports_conflicts_instead_of_succeeding() {
+    let Some(repo) = TestRepo::new() else { return };
+    repo.commit_file("a.txt", "inicial\n", "chore: inicial");
+
+    // Um remoto local e um segundo clone, que publica alterações nele.
+    let remote_dir = tempfile::tempdir().unwrap();
+    let other_dir = tempfile::tempdir().unwrap();
+    git(remote_dir.path(), &["init", "-q", "--bare"]);
+    let remote_path = remote_dir.path().to_str().unwrap();
+    git(repo.dir.path(), &["remote", "add", "origin", remote_path]);
+    git(repo.dir.path(), &["push", "-q", "-u", "origin", "main"]);
+    git(other_dir.path(), &["clone", "-q", "-b", "main", remote_path, "."]);
+    let publish = |content: &str, message: &str| {
+        fs::write(other_dir.path().join("a.txt"), content).unwrap();
+        git(other_dir.path(), &["-c", "user.name=outro", "-c", "user.email=outro@example.com", "commit", "-q", "-am", message]);
+        git(other_dir.path(), &["push", "-q", "origin", "main"]);
+    };
+
+    publish("remoto\n", "feat: remoto");
+    pull::pull(pull::PullMode::Merge).unwrap();
+    assert_eq!(log::recent_commits(1).unwrap()[0].subject, "feat: remoto");
+
+    publish("remoto de novo\n", "feat: conflitante");
+    repo.commit_file("a.txt", "local\n", "feat: local");
+    let error = pull::pull(pull::PullMode::Merge).unwrap_err();
+    let conflict = error.downcast_ref::<ConflictError>().expect("deveria ser um ConflictError");
+    assert_eq!(conflict.files, vec!["a.txt"]);
+}
+
+#[test]
+fn fetch_with_prune_reports_deleted_remote_branches() {
+    let Some(repo) = TestRepo::new() else { return };
+    repo.commit_file("a.txt", "a\n", "chore: inicial");
+
+    let remote_dir = tempfile::tempdir().unwrap();
+    git(remote_dir.path(), &["init", "-q", "--bare"]);
+    git(repo.dir.path(), &["remote", "add", "origin", remote_dir.path().to_str().unwrap()]);
+    git(repo.dir.path(), &["push", "-q", "origin", "main", "main:antiga"]);
+    fetch::fetch("origin", false).unwrap();
+
+    // A branch é apagada diretamente no remoto, como faria um colaborador.
+    git(remote_dir.path(), &["branch", "-D", "antiga"]);
+    let summary = fetch::fetch("origin", true).unwrap();
+    let refs = fetch::parse_fetch_summary(&summary);
+    assert_eq!(refs.len(), 1);
+    assert_eq!(refs[0].kind, fetch::FetchedRefKind::Pruned);
+    assert_eq!(refs[0].reference, "origin/antiga");
+}
+
+#[test]
+fn unmerged_and_current_branches_are_not_deleted_without_force() {
+    let Some(repo) = TestRepo::new() else { return };
+    repo.commit_file("a.txt", "a\n", "chore: inicial");
+    branch::create_and_switch("feature").unwrap();
+    repo.commit_file("b.txt", "b\n", "feat: não integrado");
+
+    let error = branch::delete_branch("feature", false).unwrap_err();
+    assert!(error.to_string().contains("branch atual"));
+
+    branch::switch_branch("main").unwrap();
+    let error = branch::delete_branch("feature", false).unwrap_err();
+    let unmerged = error
+        .downcast_ref::<UnmergedBranchError>()
+        .expect("deveria ser um UnmergedBranchError");
+    assert_eq!(unmerged.branch, "feature");
+
+    branch::delete_branch("feature", true).unwrap();
+    let names: Vec<String> = branch::list_branches().unwrap().into_iter().map(|b| b.name).collect();
+    assert_eq!(names, vec!["main"]);
+}
+
+#[test]
+fn branches_are_renamed_only_to_unused_names() {
+    let Some(repo) = TestRepo::new() else { return };
+    repo.commit_file("a.txt", "a\n", "chore: inicial");
+    branch::create_branch("fetaure").unwrap();
+
+    assert!(branch::rename_branch("fetaure", "main").unwrap_err().to_string().contains("Já existe"));
+    assert!(branch::rename_branch("fetaure", "  ").is_err());
+    branch::rename_branch("fetaure", "feature").unwrap();
+
+    branch::rename_current_branch("principal").unwrap();
+    assert_eq!(branch::current_branch().unwrap().as_deref(), Some("principal"));
+    let names: Vec<String> = branch::list_branches().unwrap().into_iter().map(|b| b.name).collect();
+    assert_eq!(names, vec!["feature", "principal"]);
+}
\ No newline at end of file